@@ -64,6 +64,7 @@
 //! - `TryFrom<u8>` - Discriminator parsing
 //! - `dispatch()` - Handler dispatch (after enum construction)
 //! - `process()` - Direct dispatch (more efficient)
+//! - `VARIANTS` - `(name, discriminator)` for every instruction
 //!
 //! ## From `instruction`
 //!
@@ -85,6 +86,7 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::parse::Parser;
 use syn::{parse_macro_input, Data, DeriveInput, Fields, Expr, Lit, ItemImpl, ImplItem, ItemStruct, Type};
 
 /// Convert a PascalCase identifier to snake_case.
@@ -103,46 +105,28 @@ fn to_snake_case(s: &str) -> String {
     result
 }
 
-/// Attribute macro for complete Solana program setup.
-///
-/// This is the main entry point for defining a Solzempic program. It generates
-/// everything needed: program ID, framework types, dispatch enum, entrypoint,
-/// and process_instruction function.
-///
-/// # Generated Items
-///
-/// | Item | Type | Description |
-/// |------|------|-------------|
-/// | `ID` | `Pubkey` | Program ID constant |
-/// | `Solzempic` | `struct` | Framework type implementing `Framework` trait |
-/// | `AccountRef<'a, T>` | `type` | Read-only account wrapper alias |
-/// | `AccountRefMut<'a, T>` | `type` | Writable account wrapper alias |
-/// | `ShardRefContext<'a, T>` | `type` | Read-only shard triplet context alias |
-/// | `ShardRefMutContext<'a, T>` | `type` | Writable shard triplet context alias |
-/// | `id()` | `fn` | Returns `&'static Pubkey` |
-/// | `process_instruction` | `fn` | Program entrypoint handler |
-/// | `entrypoint!` | macro | Registers the entrypoint (unless `no-entrypoint` feature) |
-///
-/// # Example
-///
-/// ```ignore
-/// use solzempic::SolzempicEntrypoint;
-///
-/// #[SolzempicEntrypoint("Your11111111111111111111111111111111111111")]
-/// pub enum MyInstruction {
-///     Initialize = 0,
-///     Transfer = 1,
-/// }
-/// ```
-///
-/// This single attribute generates a complete program setup.
-///
-/// # Panics
+/// Collect `///` doc comment lines (`#[doc = "..."]` attributes) for IDL metadata.
 ///
-/// Compile-time panics if:
-/// - Applied to a non-enum type
-/// - No program ID provided in attribute
-/// - Variant lacks explicit discriminant value
+/// Each line has the single leading space added by rustdoc syntax stripped.
+/// Non-literal doc attributes (e.g. `#[doc = include_str!(...)]`) are skipped.
+fn extract_docs(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs.iter().filter_map(|attr| {
+        if !attr.path().is_ident("doc") {
+            return None;
+        }
+        match &attr.meta.require_name_value().ok()?.value {
+            Expr::Lit(expr_lit) => match &expr_lit.lit {
+                Lit::Str(lit_str) => {
+                    let line = lit_str.value();
+                    Some(line.strip_prefix(' ').unwrap_or(&line).to_string())
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }).collect()
+}
+
 /// Parse account specs from #[accounts(...)] attribute on enum variant.
 /// Format: #[accounts(name: constraint, name2: constraint2, ...)]
 /// Constraints: mut (writable), signer, mut_signer (both), program, or empty (readonly)
@@ -182,6 +166,144 @@ fn parse_variant_accounts(attrs: &[syn::Attribute]) -> Vec<(String, bool, bool,
     accounts
 }
 
+/// Byte width of a `#[repr(uN)]` / `repr = uN` discriminator type.
+fn repr_disc_len(ty: &syn::Ident) -> usize {
+    match ty.to_string().as_str() {
+        "u8" => 1,
+        "u16" => 2,
+        "u32" => 4,
+        "u64" => 8,
+        other => panic!("SolzempicEntrypoint repr must be u8, u16, u32, or u64, found `{}`", other),
+    }
+}
+
+/// Attribute macro for complete Solana program setup.
+///
+/// This is the main entry point for defining a Solzempic program. It generates
+/// everything needed: program ID, framework types, dispatch enum, entrypoint,
+/// and process_instruction function.
+///
+/// # Generated Items
+///
+/// | Item | Type | Description |
+/// |------|------|-------------|
+/// | `ID` | `Pubkey` | Program ID constant |
+/// | `Solzempic` | `struct` | Framework type implementing `Framework` trait |
+/// | `AccountRef<'a, T>` | `type` | Read-only account wrapper alias |
+/// | `AccountRefMut<'a, T>` | `type` | Writable account wrapper alias |
+/// | `ShardRefContext<'a, T>` | `type` | Read-only shard triplet context alias |
+/// | `ShardRefMutContext<'a, T>` | `type` | Writable shard triplet context alias |
+/// | `id()` | `fn` | Returns `&'static Pubkey` |
+/// | `VARIANTS` | `const` | `&[(&str, u8)]` of every instruction name and discriminator (wider with `disc_len`) |
+/// | `dispatch_bytes` | `fn` | Dispatches raw instruction data; always present, for tests and CPI wrappers |
+/// | `process_instruction` | `fn` | Program entrypoint handler |
+/// | `entrypoint!` | macro | Registers the entrypoint (unless `no-entrypoint` feature) |
+/// | `ACCOUNT_ORDER` | `const` | `&[InstructionAccounts]` of each instruction's ordered accounts (`client` feature) |
+///
+/// # Example
+///
+/// ```ignore
+/// use solzempic::SolzempicEntrypoint;
+///
+/// #[SolzempicEntrypoint("Your11111111111111111111111111111111111111")]
+/// pub enum MyInstruction {
+///     Initialize = 0,
+///     Transfer = 1,
+/// }
+/// ```
+///
+/// This single attribute generates a complete program setup.
+///
+/// # Fallback Handler
+///
+/// Mark one variant with `#[fallback]` to route any unrecognized discriminator
+/// (and empty instruction data) to that handler instead of returning
+/// `InvalidInstructionData`. The fallback handler receives the full,
+/// untouched `data` slice. Its own discriminator still dispatches normally.
+///
+/// ```ignore
+/// #[SolzempicEntrypoint("Your11111111111111111111111111111111111111")]
+/// pub enum MyInstruction {
+///     Initialize = 0,
+///     #[fallback]
+///     Log = 255,
+/// }
+/// ```
+///
+/// # Wide Discriminators
+///
+/// By default the discriminator is the first byte of instruction data. Pass
+/// `disc_len = 2`, `4`, or `8` to read a little-endian `u16`/`u32`/`u64`
+/// prefix instead. The enum becomes `#[repr(u16/u32/u64)]`, `TryFrom` and
+/// `VARIANTS` use that integer type, and handlers receive the data after the
+/// prefix:
+///
+/// ```ignore
+/// #[SolzempicEntrypoint("Your11111111111111111111111111111111111111", disc_len = 4)]
+/// pub enum MyInstruction {
+///     Initialize = 0x0000_0001,
+///     Transfer = 0x0000_0100,
+/// }
+/// ```
+///
+/// The width can also come from a `#[repr(u16)]` (etc.) already on the enum,
+/// or from a `repr = u16` option; all three forms must agree if combined:
+///
+/// ```ignore
+/// #[SolzempicEntrypoint("Your11111111111111111111111111111111111111")]
+/// #[repr(u16)]
+/// pub enum MyInstruction {
+///     Initialize = 0,
+///     Settle = 300,
+/// }
+/// ```
+///
+/// # Batched Dispatch
+///
+/// Pass the `batch` option to also generate `process_many`, which runs
+/// several sub-instructions packed into one instruction's data. The data is
+/// a `u8` count followed by that many frames, each a little-endian `u16`
+/// length and the sub-instruction bytes (discriminator included). Every
+/// sub-instruction sees the whole account slice. The handler that drives the
+/// batch takes the frames as trailing data from `build_with_rest`:
+///
+/// ```ignore
+/// #[SolzempicEntrypoint("Your11111111111111111111111111111111111111", batch)]
+/// pub enum MyInstruction {
+///     Deposit = 0,
+///     Swap = 1,
+///     Batch = 2,
+/// }
+///
+/// #[params]
+/// pub struct BatchParams;
+///
+/// pub struct Batch<'a> {
+///     accounts: &'a [AccountView],
+///     frames: &'a [u8],
+/// }
+///
+/// #[instruction(BatchParams)]
+/// impl<'a> Batch<'a> {
+///     fn build_with_rest(accounts: &'a [AccountView], _params: &BatchParams, rest: &'a [u8]) -> Result<Self, ProgramError> {
+///         Ok(Self { accounts, frames: rest })
+///     }
+///
+///     fn execute(&self, program_id: &Address, _params: &BatchParams) -> ProgramResult {
+///         MyInstruction::process_many(program_id, self.accounts, self.frames)
+///     }
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Compile-time panics if:
+/// - Applied to a non-enum type
+/// - No program ID provided in attribute
+/// - Variant lacks explicit discriminant value
+/// - More than one variant is marked `#[fallback]`
+/// - `disc_len` is not 1, 2, 4, or 8, or `repr` is not `u8`/`u16`/`u32`/`u64`
+/// - `disc_len`, `repr = ...`, and `#[repr(...)]` disagree
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -190,23 +312,67 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
     let vis = &input.vis;
     let attrs = &input.attrs;
 
-    // Parse the program ID from attribute - either a string literal or an identifier
-    let program_id_tokens: proc_macro2::TokenStream = if attr.is_empty() {
+    // Parse the attribute: a program ID (string literal or identifier), then
+    // optional `key = value` and flag options
+    if attr.is_empty() {
         panic!("SolzempicEntrypoint requires a program ID, e.g. #[SolzempicEntrypoint(\"Your111...\")]");
-    } else {
-        let attr_str = attr.to_string();
-        let trimmed = attr_str.trim();
-        if trimmed.starts_with('"') && trimmed.ends_with('"') {
-            // String literal: convert to pinocchio_pubkey::pubkey!() call
-            let pubkey_str = &trimmed[1..trimmed.len()-1];
-            let pubkey_str_lit = syn::LitStr::new(pubkey_str, proc_macro2::Span::call_site());
+    }
+    let attr_args = syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated
+        .parse(attr)
+        .expect("SolzempicEntrypoint attribute must be a program ID followed by options");
+    let mut attr_args = attr_args.into_iter();
+    let program_id_tokens: proc_macro2::TokenStream = match attr_args.next() {
+        // String literal: convert to pinocchio_pubkey::pubkey!() call
+        Some(Expr::Lit(syn::ExprLit { lit: Lit::Str(pubkey_str_lit), .. })) => {
             quote! { ::pinocchio_pubkey::pubkey!(#pubkey_str_lit) }
-        } else {
-            // Identifier: use directly
-            let ident: syn::Ident = syn::parse(attr.clone())
-                .expect("SolzempicEntrypoint attribute must be a string literal or identifier");
+        }
+        // Identifier: use directly
+        Some(Expr::Path(path)) if path.path.get_ident().is_some() => {
+            let ident = path.path.get_ident().unwrap();
             quote! { #ident }
         }
+        _ => panic!("SolzempicEntrypoint attribute must be a string literal or identifier"),
+    };
+
+    // Discriminator width in bytes (default 1), from `disc_len = N`,
+    // `repr = uN`, or an existing `#[repr(uN)]` on the enum
+    let mut disc_lens: Vec<usize> = Vec::new();
+    // A bare `batch` option opts in to `process_many`
+    let mut batch_enabled = false;
+    for arg in attr_args {
+        match arg {
+            Expr::Assign(assign) if matches!(&*assign.left, Expr::Path(p) if p.path.is_ident("disc_len")) => {
+                disc_lens.push(match &*assign.right {
+                    Expr::Lit(syn::ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse::<usize>()
+                        .expect("disc_len must be an integer"),
+                    _ => panic!("disc_len must be an integer literal"),
+                });
+            }
+            Expr::Assign(assign) if matches!(&*assign.left, Expr::Path(p) if p.path.is_ident("repr")) => {
+                disc_lens.push(match &*assign.right {
+                    Expr::Path(p) if p.path.get_ident().is_some() => repr_disc_len(p.path.get_ident().unwrap()),
+                    _ => panic!("repr must be one of u8, u16, u32, u64"),
+                });
+            }
+            Expr::Path(p) if p.path.is_ident("batch") => batch_enabled = true,
+            _ => panic!("unknown SolzempicEntrypoint option, expected `disc_len = N`, `repr = uN`, or `batch`"),
+        }
+    }
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let ty: syn::Ident = attr.parse_args()
+            .expect("SolzempicEntrypoint enums only support #[repr(u8/u16/u32/u64)]");
+        disc_lens.push(repr_disc_len(&ty));
+    }
+    if disc_lens.windows(2).any(|pair| pair[0] != pair[1]) {
+        panic!("SolzempicEntrypoint discriminator width is set more than once with different values");
+    }
+    let disc_len = disc_lens.first().copied().unwrap_or(1);
+    let disc_ty = match disc_len {
+        1 => quote! { u8 },
+        2 => quote! { u16 },
+        4 => quote! { u32 },
+        8 => quote! { u64 },
+        _ => panic!("disc_len must be 1, 2, 4, or 8"),
     };
 
     let variants = match &input.data {
@@ -224,9 +390,22 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
         (variant_name, disc_expr, accounts)
     }).collect();
 
+    // Find the optional #[fallback] variant for unmatched discriminators
+    let fallback_variants: Vec<_> = variants.iter()
+        .filter(|variant| variant.attrs.iter().any(|attr| attr.path().is_ident("fallback")))
+        .map(|variant| &variant.ident)
+        .collect();
+    if fallback_variants.len() > 1 {
+        panic!("SolzempicEntrypoint allows at most one #[fallback] variant");
+    }
+    let fallback_variant = fallback_variants.first();
+
     // Filter out any ShankInstruction derive from input attrs to avoid conflicts
     let filtered_attrs: Vec<_> = attrs.iter().filter(|attr| {
-        if attr.path().is_ident("derive") {
+        if attr.path().is_ident("repr") {
+            // `repr` is re-emitted from the discriminator width below
+            false
+        } else if attr.path().is_ident("derive") {
             // Check if the derive contains ShankInstruction
             let content = attr.meta.require_list().ok();
             if let Some(content) = content {
@@ -248,29 +427,116 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
     // Generate dispatch match arms (for backward compat)
     let dispatch_arms = variant_info.iter().map(|(name, _, _)| {
         quote! {
-            #enum_name::#name => <#name<'_> as ::solzempic::Instruction<'_>>::process(program_id, accounts, data),
+            #enum_name::#name => <#name<'_> as ::solzempic::ContextInstruction<'_>>::process_with_context(program_id, accounts, data),
         }
     });
 
     // Generate process match arms (direct discriminator to handler)
     let process_arms = variant_info.iter().map(|(name, disc, _)| {
         quote! {
-            #disc => <#name<'_> as ::solzempic::Instruction<'_>>::process(program_id, accounts, &data[1..]),
+            #disc => <#name<'_> as ::solzempic::ContextInstruction<'_>>::process_with_context(program_id, accounts, &data[#disc_len..]),
         }
     });
 
+    // Unmatched discriminators (and empty data) go to the fallback handler with the
+    // full data slice, or error if no fallback is declared
+    let unmatched_arm = match fallback_variant {
+        Some(name) => quote! {
+            <#name<'_> as ::solzempic::ContextInstruction<'_>>::process_with_context(program_id, accounts, data)
+        },
+        None => quote! {
+            Err(::pinocchio::error::ProgramError::InvalidInstructionData)
+        },
+    };
+
+    // Read the discriminator prefix as a little-endian integer
+    let read_discriminator = if disc_len == 1 {
+        quote! {
+            let Some(&discriminator) = data.first() else {
+                return #unmatched_arm;
+            };
+        }
+    } else {
+        quote! {
+            let Some(prefix) = data.first_chunk::<#disc_len>() else {
+                return #unmatched_arm;
+            };
+            let discriminator = #disc_ty::from_le_bytes(*prefix);
+        }
+    };
+
+    // Batched dispatch: a count byte, then `count` frames of `len: u16 (LE)` + `len` bytes
+    let process_many_fn = if batch_enabled {
+        quote! {
+            /// Process a batch of framed sub-instructions in order.
+            ///
+            /// Data layout: `count: u8`, then `count` frames of `len: u16`
+            /// (little-endian) followed by `len` bytes of instruction data.
+            /// Each frame is dispatched through [`process`](Self::process) with
+            /// the full account slice. Fails on the first error, on truncated
+            /// frames, or on trailing bytes.
+            #[inline]
+            pub fn process_many(
+                program_id: &::solana_address::Address,
+                accounts: &[::pinocchio::AccountView],
+                data: &[u8],
+            ) -> ::pinocchio::ProgramResult {
+                let Some((&count, mut rest)) = data.split_first() else {
+                    return Err(::pinocchio::error::ProgramError::InvalidInstructionData);
+                };
+                for _ in 0..count {
+                    let [lo, hi, tail @ ..] = rest else {
+                        return Err(::pinocchio::error::ProgramError::InvalidInstructionData);
+                    };
+                    let len = u16::from_le_bytes([*lo, *hi]) as usize;
+                    if tail.len() < len {
+                        return Err(::pinocchio::error::ProgramError::InvalidInstructionData);
+                    }
+                    let (frame, next) = tail.split_at(len);
+                    Self::process(program_id, accounts, frame)?;
+                    rest = next;
+                }
+                if !rest.is_empty() {
+                    return Err(::pinocchio::error::ProgramError::InvalidInstructionData);
+                }
+                Ok(())
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // Generate IDL metadata entries
     let idl_entries = variant_info.iter().map(|(name, disc, _)| {
         quote! {
             ::solzempic::InstructionMeta {
                 name: #name::IDL_NAME,
-                discriminator: #disc,
+                discriminator: &(#disc as #disc_ty).to_le_bytes(),
                 accounts: &#name::SHANK_ACCOUNTS,
                 params: #name::IDL_PARAMS,
+                docs: #name::IDL_DOCS,
+            }
+        }
+    });
+
+    // Generate account ordering entries for client-side builders
+    let account_order_entries = variant_info.iter().map(|(name, disc, _)| {
+        let name_str = name.to_string();
+        quote! {
+            ::solzempic::InstructionAccounts {
+                name: #name_str,
+                discriminator: &(#disc as #disc_ty).to_le_bytes(),
+                accounts: &#name::SHANK_ACCOUNTS,
             }
         }
     });
 
+    // Generate (name, discriminator) pairs for the VARIANTS const
+    let variant_entries = variant_info.iter().map(|(name, disc, _)| {
+        let name_str = name.to_string();
+        quote! { (#name_str, #disc) }
+    });
+
     // Generate variant definitions for the enum
     let variant_defs = variant_info.iter().map(|(name, disc, _accounts)| {
         quote! {
@@ -281,7 +547,7 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
     // Generate enum definition (no ShankInstruction - we generate IDL metadata ourselves)
     let enum_definition = quote! {
         #(#filtered_attrs)*
-        #[repr(u8)]
+        #[repr(#disc_ty)]
         #vis enum #enum_name {
             #(#variant_defs),*
         }
@@ -299,20 +565,16 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
         // Generate account metadata array
         let account_metas: Vec<proc_macro2::TokenStream> = accounts.iter().enumerate().map(|(idx, (acc_name, is_signer, is_writable, _is_program))| {
             quote! {
-                ::solzempic::ShankAccountMeta {
-                    index: #idx,
-                    name: #acc_name,
-                    is_signer: #is_signer,
-                    is_writable: #is_writable,
-                    is_program: false,
-                }
+                ::solzempic::ShankAccountMeta::new(#idx, #acc_name)
+                    .signer(#is_signer)
+                    .writable(#is_writable)
             }
         }).collect();
 
         quote! {
             /// IDL metadata for #name instruction
             pub mod #mod_name {
-                pub const DISCRIMINATOR: u8 = #disc as u8;
+                pub const DISCRIMINATOR: #disc_ty = #disc as #disc_ty;
                 pub const NAME: &str = #name_str;
                 pub const ACCOUNTS: [::solzempic::ShankAccountMeta; #num_accounts] = [
                     #(#account_metas),*
@@ -352,11 +614,11 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
 
         #enum_definition
 
-        impl ::core::convert::TryFrom<u8> for #enum_name {
+        impl ::core::convert::TryFrom<#disc_ty> for #enum_name {
             type Error = ::pinocchio::error::ProgramError;
 
             #[inline]
-            fn try_from(value: u8) -> Result<Self, Self::Error> {
+            fn try_from(value: #disc_ty) -> Result<Self, Self::Error> {
                 match value {
                     #(#try_from_arms)*
                     _ => Err(::pinocchio::error::ProgramError::InvalidInstructionData),
@@ -365,6 +627,11 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
         }
 
         impl #enum_name {
+            /// Every instruction as `(name, discriminator)`, in declaration order.
+            pub const VARIANTS: &'static [(&'static str, #disc_ty)] = &[
+                #(#variant_entries),*
+            ];
+
             /// Dispatch to handler (use after TryFrom conversion)
             #[inline]
             pub fn dispatch(
@@ -385,13 +652,27 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
                 accounts: &[::pinocchio::AccountView],
                 data: &[u8],
             ) -> ::pinocchio::ProgramResult {
-                let discriminator = *data.first()
-                    .ok_or(::pinocchio::error::ProgramError::InvalidInstructionData)?;
+                #read_discriminator
                 match discriminator {
                     #(#process_arms)*
-                    _ => Err(::pinocchio::error::ProgramError::InvalidInstructionData),
+                    _ => #unmatched_arm,
                 }
             }
+
+            #process_many_fn
+        }
+
+        /// Dispatch raw instruction data to its handler.
+        ///
+        /// Generated regardless of the `no-entrypoint` feature, so tests and
+        /// CPI wrappers can call the dispatcher without the runtime entrypoint.
+        #[inline]
+        pub fn dispatch_bytes(
+            program_id: &::solana_address::Address,
+            accounts: &[::pinocchio::AccountView],
+            instruction_data: &[u8],
+        ) -> ::pinocchio::ProgramResult {
+            #enum_name::process(program_id, accounts, instruction_data)
         }
 
         /// Program entrypoint
@@ -401,7 +682,7 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
             accounts: &[::pinocchio::AccountView],
             instruction_data: &[u8],
         ) -> ::pinocchio::ProgramResult {
-            #enum_name::process(program_id, accounts, instruction_data)
+            dispatch_bytes(program_id, accounts, instruction_data)
         }
 
         #[cfg(not(feature = "no-entrypoint"))]
@@ -414,6 +695,12 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
             #(#idl_entries),*
         ];
 
+        /// Account ordering for every instruction, for off-chain transaction builders.
+        #[cfg(feature = "client")]
+        pub const ACCOUNT_ORDER: &[::solzempic::InstructionAccounts] = &[
+            #(#account_order_entries),*
+        ];
+
         /// Shank-compatible instruction metadata module.
         /// Generated by SolzempicEntrypoint macro to provide IDL metadata
         /// when using expression discriminants (which ShankInstruction doesn't support).
@@ -442,14 +729,27 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
 ///
 /// This separation enables clear responsibility boundaries and easier testing.
 ///
-/// # Required Methods
+/// # Required and Optional Methods
+///
+/// Only a build phase is required - `build`, or `build_with_rest` for a
+/// variable-length tail, or neither when the attribute passes `auto_build`
+/// (the struct's generated `auto_build()` becomes `build`). Everything else
+/// is optional:
 ///
-/// All three methods must be implemented:
+/// | Method | Required | If omitted |
+/// |--------|----------|------------|
+/// | `build` / `build_with_rest` | Yes, unless `auto_build` | - |
+/// | `validate` | No | `Ok(())` |
+/// | `execute` | No | `Ok(())` |
+/// | `precheck` | No | `Ok(())` |
+/// | `check_constraints` | No | The struct's `#[has_one]`/`#[address]` checks |
 ///
 /// ```ignore
-/// fn build(accounts: &'a [AccountInfo], params: &Params) -> Result<Self, ProgramError>
-/// fn validate(&self, program_id: &Pubkey, params: &Params) -> ProgramResult
-/// fn execute(&self, program_id: &Pubkey, params: &Params) -> ProgramResult
+/// fn build(accounts: &'a [AccountView], params: &Params) -> Result<Self, ProgramError>
+/// fn build_with_rest(accounts: &'a [AccountView], params: &Params, rest: &'a [u8]) -> Result<Self, ProgramError>
+/// fn precheck(params: &Params) -> ProgramResult
+/// fn validate(&self, program_id: &Address, params: &Params) -> ProgramResult
+/// fn execute(&mut self, program_id: &Address, params: &Params) -> ProgramResult
 /// ```
 ///
 /// # Generated Code
@@ -457,9 +757,98 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
 /// From a single impl block, generates:
 ///
 /// 1. `impl InstructionParams for MyInstruction<'_>` - Associates params type
-/// 2. `impl<'a> Instruction<'a> for MyInstruction<'a>` - Full instruction trait
+/// 2. `impl<'a> Instruction<'a> for MyInstruction<'a>` - Full instruction trait,
+///    with the omitted methods filled in
+///
+/// The `Instruction::process` default method runs the phases in order:
+/// `precheck`, `build`, `check_constraints`, `validate`, `execute`.
+///
+/// # Borsh Params
+///
+/// By default, params are parsed with a zero-copy cast and must be `Copy`.
+/// For instructions with variable-length payloads (e.g., admin instructions
+/// taking a `Vec<u8>` or `String`), opt into borsh decoding instead:
+///
+/// ```ignore
+/// #[derive(BorshDeserialize, BorshParams)]
+/// pub struct SetMetadataParams {
+///     pub uri: String,
+/// }
+///
+/// #[instruction(SetMetadataParams, borsh)]
+/// impl<'a> SetMetadata<'a> { ... }
+/// ```
+///
+/// This requires the `borsh` feature on `solzempic`. `BorshParams` implements
+/// `ParamsDecode`, which `InstructionParams::Params` must implement (it is
+/// automatic for `Copy` params). Borsh params have no
+/// `#[params]` field metadata, so `IDL_PARAMS` is empty for these instructions.
+///
+/// # `has_one` Constraints (struct form)
+///
+/// On an instruction struct, `#[has_one(field = account)]` on an account field
+/// checks that `field` in that account's data equals the address of the
+/// `account` field of the same struct. The checks are generated into a
+/// `check_has_one()` method, which `process` runs after `build` and before
+/// `validate` (through `Instruction::check_constraints`), so `validate` only
+/// holds business rules:
+///
+/// ```ignore
+/// #[instruction]
+/// pub struct UpdateMarket<'a> {
+///     // market.authority == admin.address() && market.fee_vault == vault.address()
+///     #[has_one(authority = admin)]
+///     #[has_one(fee_vault = vault, error = MarketError::WrongVault)]
+///     pub market: AccountRefMut<'a, Market>,
+///     pub admin: Signer<'a>,
+///     pub vault: Vault<'a>,
+/// }
+/// ```
+///
+/// A failed check returns [`ProgramError::InvalidAccountOwner`], or the
+/// `error = ...` expression converted with `Into<ProgramError>`.
+///
+/// # `address` Constraints (struct form)
+///
+/// `#[address(EXPECTED)]` pins a field to a known address. `EXPECTED` is any
+/// expression of type `Address` resolvable at the struct definition (usually a
+/// constant path). The checks are generated into `check_addresses()`, which
+/// returns [`ProgramError::InvalidArgument`] on mismatch and, like
+/// `check_has_one()`, runs automatically between `build` and `validate`
+/// (address checks first):
+///
+/// ```ignore
+/// #[instruction]
+/// pub struct SetFee<'a> {
+///     #[address(CONFIG_ID)]
+///     pub config: AccountRefMut<'a, Config>,
+///     #[address(admin::ID)]
+///     pub admin: Signer<'a>,
+/// }
+/// ```
+///
+/// # `seeds` Constraints (struct form)
+///
+/// `#[seeds(...)]` declares a field as a PDA of this program. Each seed is a
+/// byte string literal or the name of another account field, whose address is
+/// used. The template is recorded in `SHANK_ACCOUNTS` (and the IDL `pda`
+/// entry), and `check_seeds(program_id)` verifies the addresses with the
+/// canonical bump, returning [`ProgramError::InvalidSeeds`] on mismatch:
+///
+/// ```ignore
+/// #[instruction]
+/// pub struct CreateMarket<'a> {
+///     #[seeds(b"market", base_mint)]
+///     pub market: AccountRefMut<'a, Market>,
+///     pub base_mint: Mint<'a>,
+/// }
+///
+/// fn validate(&self, program_id: &Address, _params: &Params) -> ProgramResult {
+///     self.check_seeds(program_id)
+/// }
+/// ```
 ///
-/// The `Instruction::process` default method calls all three phases in order.
+/// `check_seeds` runs `find_program_address` once per declared PDA.
 ///
 /// # Example
 ///
@@ -509,37 +898,262 @@ pub fn SolzempicEntrypoint(attr: TokenStream, item: TokenStream) -> TokenStream
 /// }
 /// ```
 ///
-/// # Panics
+/// # Custom Errors
 ///
-/// Compile-time panics if:
-/// - No params type provided in attribute (for impl blocks)
-/// - Applied to non-struct/non-impl
-#[proc_macro_attribute]
-pub fn instruction(attr: TokenStream, item: TokenStream) -> TokenStream {
-    // Try to parse as struct first, then as impl block
-    let item_clone = item.clone();
-
-    if let Ok(input) = syn::parse::<ItemStruct>(item_clone) {
-        // It's a struct - generate Shank account metadata
-        return instruction_struct_impl(attr, input);
-    }
-
-    // Otherwise treat as impl block
-    let params_type: syn::Path = syn::parse(attr)
-        .expect("instruction macro on impl requires params type, e.g. #[instruction(MyParams)]");
-    let input = parse_macro_input!(item as ItemImpl);
-
-    // Extract the struct name from the impl
-    let struct_type = &input.self_ty;
-
-    // Extract struct name without lifetime for InstructionParams impl
-    let struct_name = match struct_type.as_ref() {
-        syn::Type::Path(type_path) => &type_path.path.segments.last().unwrap().ident,
-        _ => panic!("instruction macro requires a struct type"),
-    };
-
-    // Extract the methods
-    let methods: Vec<_> = input.items.iter().filter_map(|item| {
+/// Add `error = MyError` to let `build`, `validate`, and `execute` return
+/// `Result<_, MyError>` (or `ProgramError`). The methods are kept as inherent
+/// methods and the generated `Instruction` impl converts their errors with
+/// `Into<ProgramError>`. `solzempic::define_errors!` generates a suitable enum:
+///
+/// ```ignore
+/// solzempic::define_errors! {
+///     pub enum MarketError {
+///         InsufficientLiquidity = 6000,
+///     }
+/// }
+///
+/// #[instruction(SwapParams, error = MarketError)]
+/// impl<'a> Swap<'a> {
+///     fn build(accounts: &'a [AccountView], _params: &SwapParams) -> Result<Self, ProgramError> { ... }
+///
+///     fn validate(&self, _program_id: &Address, params: &SwapParams) -> Result<(), MarketError> {
+///         if self.pool.get().liquidity < params.amount {
+///             return Err(MarketError::InsufficientLiquidity);
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+///
+/// # Account Arrays (struct form)
+///
+/// A fixed-size array field such as `[AccountRefMut<'a, Vault>; 8]` is listed
+/// as 8 consecutive accounts named `{field}_0` .. `{field}_7`, each with the
+/// element type's signer/writable flags. The length must be an integer
+/// literal:
+///
+/// ```ignore
+/// #[instruction]
+/// pub struct ClaimRewards<'a> {
+///     pub user: Signer<'a>,
+///     pub reward_vaults: [Writable<'a>; 4],  // reward_vaults_0 .. reward_vaults_3
+/// }
+/// ```
+///
+/// # Index Gaps (struct form)
+///
+/// `#[skip(N)]` on a field leaves N account positions before it undescribed,
+/// for accounts the instruction handles outside the struct. Later indices
+/// shift by N, `NUM_ACCOUNTS` counts only the described accounts, and
+/// `MIN_ACCOUNTS` includes the skipped ones:
+///
+/// ```ignore
+/// #[instruction]
+/// pub struct Settle<'a> {
+///     pub user: Signer<'a>,            // index 0
+///     #[skip(2)]
+///     pub market: AccountRefMut<'a, Market>,  // index 3
+/// }
+/// ```
+///
+/// # Conditional Accounts (struct form)
+///
+/// Trailing fields marked `#[present_if(cond)]` are only passed when `cond`,
+/// an expression over `params`, holds. They are flagged `optional` in the
+/// account metadata, and the struct gets `MIN_ACCOUNTS` plus an
+/// `expected_account_count(&params)` helper, which uses the params type of
+/// the struct's `InstructionParams` impl:
+///
+/// ```ignore
+/// #[instruction]
+/// pub struct Swap<'a> {
+///     pub user: Signer<'a>,
+///     pub pool: AccountRefMut<'a, Pool>,
+///     #[present_if(params.has_referrer != 0)]
+///     pub referrer: Option<Writable<'a>>,
+/// }
+///
+/// // Swap::expected_account_count(&params) is 2 or 3
+/// ```
+///
+/// Absent accounts are simply left out, so every `#[present_if]` field must
+/// come after all required ones; `build` checks the same condition before
+/// reading them. An `Option<T>` field is described with `T`'s flags, so
+/// `Option<Writable<'a>>` is a natural type for a conditional account.
+/// `#[has_one]`, `#[address]` and `#[seeds]` checks that involve an
+/// `Option<T>` field are skipped while it is `None`.
+///
+/// # Auto Build
+///
+/// `#[instruction(auto_build)]` on the struct generates `auto_build(accounts,
+/// params)`, loading each field from its account with the constructor for
+/// its type (`load` for account wrappers, `ValidatedAccount::wrap` for
+/// signers, programs and sysvars). Adding `auto_build` to the impl attribute
+/// makes it the default `build`. Fields the macro can't load - a `Vault` that
+/// needs its authority, a shard context, an array - are marked `#[manual]`
+/// and built by `build_<field>(accounts, params)`:
+///
+/// ```ignore
+/// #[instruction(auto_build)]
+/// pub struct Deposit<'a> {
+///     pub user: Signer<'a>,
+///     pub market: AccountRefMut<'a, Market>,
+///     #[manual]
+///     pub vault: Vault<'a>,
+/// }
+///
+/// #[instruction(DepositParams, auto_build)]
+/// impl<'a> Deposit<'a> {
+///     fn build_vault(accounts: &'a [AccountView], _params: &DepositParams) -> Result<Vault<'a>, ProgramError> {
+///         Vault::wrap(next_account(accounts, 2)?, &MARKET_AUTHORITY)
+///     }
+/// }
+/// ```
+///
+/// # Default Phases
+///
+/// Only `build` is required in the impl block. If `validate` or `execute` is
+/// omitted, the macro fills it in with a body that returns `Ok(())`. Other
+/// items (consts, helper methods) are kept on the struct in an inherent impl:
+///
+/// ```ignore
+/// #[instruction(NoParams)]
+/// impl<'a> CheckAdmin<'a> {
+///     fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+///         Ok(Self { admin: Signer::wrap(&accounts[0])? })
+///     }
+///
+///     // validate and execute default to Ok(())
+/// }
+/// ```
+///
+/// # Prechecks
+///
+/// An optional `precheck(params)` runs before `build`, so checks that only
+/// need the params reject a transaction before any account is loaded:
+///
+/// ```ignore
+/// #[instruction(WithdrawParams)]
+/// impl<'a> Withdraw<'a> {
+///     fn precheck(params: &WithdrawParams) -> ProgramResult {
+///         if params.amount == 0 {
+///             return Err(ProgramError::InvalidArgument);
+///         }
+///         Ok(())
+///     }
+///
+///     fn build(accounts: &'a [AccountView], params: &WithdrawParams) -> Result<Self, ProgramError> {
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// # Trailing Data
+///
+/// For instructions with a variable-length payload after their fixed params,
+/// define `build_with_rest` instead of `build`. It receives the instruction
+/// data after `Params`, borrowed for `'a`, and `build` is generated to call it
+/// with an empty tail:
+///
+/// ```ignore
+/// #[instruction(PayoutParams)]
+/// impl<'a> Payout<'a> {
+///     fn build_with_rest(accounts: &'a [AccountView], params: &PayoutParams, rest: &'a [u8]) -> Result<Self, ProgramError> {
+///         let amounts = rest.get(..params.count as usize * 8).ok_or(ProgramError::InvalidInstructionData)?;
+///         Ok(Self { vault: Writable::wrap(&accounts[0])?, amounts })
+///     }
+/// }
+/// ```
+///
+/// # Shared Context
+///
+/// Declaring `type Ctx = ...;` in the impl block threads a value derived in
+/// `build` through the later phases, so expensive lookups (a PDA bump, a
+/// parsed header) happen once. `build` returns `(Self, Ctx)` and `validate`/
+/// `execute` take `ctx: &Ctx` after `&self`; the macro implements
+/// [`ContextInstruction`](https://docs.rs/solzempic/latest/solzempic/trait.ContextInstruction.html)
+/// instead of `Instruction`, and the entrypoint dispatches it through
+/// `process_with_context`. Consts and helper methods are kept on the struct as
+/// in plain mode:
+///
+/// ```ignore
+/// #[instruction(DepositParams)]
+/// impl<'a> Deposit<'a> {
+///     type Ctx = u8;
+///
+///     fn build(accounts: &'a [AccountView], params: &DepositParams) -> Result<(Self, u8), ProgramError> {
+///         let ix = Self { vault: AccountRefMut::load(&accounts[0])? };
+///         let bump = ix.vault.assert_pda(&[b"vault"])?;
+///         Ok((ix, bump))
+///     }
+///
+///     fn execute(&mut self, bump: &u8, _program_id: &Address, params: &DepositParams) -> ProgramResult {
+///         // sign with [b"vault", &[*bump]]
+///         Ok(())
+///     }
+/// }
+/// ```
+///
+/// # Compile Errors
+///
+/// The impl block must define `build` or `build_with_rest`, unless it passes
+/// `auto_build`. When a phase is
+/// missing, a method whose name is within two edits of it (`buid`,
+/// `valdiate`, `exectue`) is rejected with a "did you mean" error on that
+/// method, since the macro would otherwise treat it as a helper and default
+/// the phase it meant to implement.
+///
+/// # Panics
+///
+/// Compile-time panics if:
+/// - No params type provided in attribute (for impl blocks)
+/// - An option other than `borsh`, `auto_build` or `error = ...` follows the params type
+/// - An `auto_build` struct has a field it can't load that isn't `#[manual]`
+/// - Applied to non-struct/non-impl
+#[proc_macro_attribute]
+pub fn instruction(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Try to parse as struct first, then as impl block
+    let item_clone = item.clone();
+
+    if let Ok(input) = syn::parse::<ItemStruct>(item_clone) {
+        // It's a struct - generate Shank account metadata
+        return instruction_struct_impl(attr, input);
+    }
+
+    // Otherwise treat as impl block: #[instruction(MyParams)], plus optional
+    // `borsh` and `error = MyError`
+    let attr_metas = syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated
+        .parse(attr)
+        .expect("instruction macro on impl requires params type, e.g. #[instruction(MyParams)]");
+    let mut attr_metas = attr_metas.into_iter();
+    let params_type = match attr_metas.next() {
+        Some(syn::Meta::Path(path)) => path,
+        _ => panic!("instruction macro on impl requires params type, e.g. #[instruction(MyParams)]"),
+    };
+    let mut use_borsh = false;
+    let mut auto_build = false;
+    let mut error_type: Option<Expr> = None;
+    for meta in attr_metas {
+        match meta {
+            syn::Meta::Path(flag) if flag.is_ident("borsh") => use_borsh = true,
+            syn::Meta::Path(flag) if flag.is_ident("auto_build") => auto_build = true,
+            syn::Meta::NameValue(nv) if nv.path.is_ident("error") => error_type = Some(nv.value),
+            other => panic!("unknown instruction option `{}`, expected `borsh`, `auto_build` or `error = ...`", quote!(#other)),
+        }
+    }
+    let input = parse_macro_input!(item as ItemImpl);
+
+    // Extract the struct name from the impl
+    let struct_type = &input.self_ty;
+
+    // Extract struct name without lifetime for InstructionParams impl
+    let struct_name = match struct_type.as_ref() {
+        syn::Type::Path(type_path) => &type_path.path.segments.last().unwrap().ident,
+        _ => panic!("instruction macro requires a struct type"),
+    };
+
+    // Extract the methods
+    let methods: Vec<_> = input.items.iter().filter_map(|item| {
         if let ImplItem::Fn(method) = item {
             Some(method)
         } else {
@@ -547,15 +1161,306 @@ pub fn instruction(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     }).collect();
 
-    let struct_name_str = struct_name.to_string();
+    // `type Ctx = ...;` switches to context mode (see `ContextInstruction`)
+    let ctx_type = input.items.iter().find_map(|item| match item {
+        ImplItem::Type(ty) if ty.ident == "Ctx" => Some(&ty.ty),
+        _ => None,
+    });
+
+    // `build` (or `build_with_rest`) is required; missing `validate`/`execute`
+    // default to `Ok(())`
+    let has_method = |name: &str| methods.iter().any(|method| method.sig.ident == name);
+    if let Err(err) = check_phase_methods(&input, struct_name, &methods, auto_build) {
+        return err.to_compile_error().into();
+    }
+    if let Some(ctx_type) = ctx_type {
+        if auto_build {
+            panic!("`auto_build` can't be combined with `type Ctx`; `build` must return the context");
+        }
+        return instruction_context_impl(
+            &input,
+            struct_name,
+            &params_type,
+            use_borsh,
+            error_type.as_ref(),
+            ctx_type,
+            &methods,
+        );
+    }
+    let default_build = if has_method("build") {
+        quote! {}
+    } else if auto_build && !has_method("build_with_rest") {
+        // `auto_build` is generated by `#[instruction(auto_build)]` on the struct
+        quote! {
+            #[inline]
+            fn build(accounts: &'a [::pinocchio::AccountView], params: &#params_type) -> ::core::result::Result<Self, ::pinocchio::error::ProgramError> {
+                Self::auto_build(accounts, params)
+            }
+        }
+    } else {
+        quote! {
+            #[inline]
+            fn build(accounts: &'a [::pinocchio::AccountView], params: &#params_type) -> ::core::result::Result<Self, ::pinocchio::error::ProgramError> {
+                <Self as ::solzempic::Instruction<'a>>::build_with_rest(accounts, params, &[])
+            }
+        }
+    };
+    let default_validate = if has_method("validate") {
+        quote! {}
+    } else {
+        quote! {
+            #[inline]
+            fn validate(&self, _program_id: &::solana_address::Address, _params: &#params_type) -> ::pinocchio::ProgramResult {
+                Ok(())
+            }
+        }
+    };
+    let default_execute = if has_method("execute") {
+        quote! {}
+    } else {
+        quote! {
+            #[inline]
+            fn execute(&mut self, _program_id: &::solana_address::Address, _params: &#params_type) -> ::pinocchio::ProgramResult {
+                Ok(())
+            }
+        }
+    };
+
+    // Consts and helpers always go in an inherent impl. With `error = MyError`
+    // the phase methods join them and the trait impl forwards to them,
+    // converting errors into `ProgramError`
+    let items = match inherent_items(&input, error_type.is_none()) {
+        Ok(items) => items,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+    let (user_impl, trait_methods) = match &error_type {
+        None => {
+            let phase_methods = methods.iter().filter(|method| is_phase(method));
+            let user_impl = quote! {
+                impl #impl_generics #struct_type #where_clause {
+                    #(#items)*
+                }
+            };
+            (user_impl, quote! { #(#phase_methods)* })
+        }
+        Some(error_type) => {
+            let forwards = methods.iter().filter_map(|method| {
+                let forward = match method.sig.ident.to_string().as_str() {
+                    "precheck" => quote! {
+                        #[inline]
+                        fn precheck(params: &#params_type) -> ::pinocchio::ProgramResult {
+                            Self::precheck(params).map_err(::core::convert::Into::into)
+                        }
+                    },
+                    "build" => quote! {
+                        #[inline]
+                        fn build(accounts: &'a [::pinocchio::AccountView], params: &#params_type) -> ::core::result::Result<Self, ::pinocchio::error::ProgramError> {
+                            Self::build(accounts, params).map_err(::core::convert::Into::into)
+                        }
+                    },
+                    "build_with_rest" => quote! {
+                        #[inline]
+                        fn build_with_rest(accounts: &'a [::pinocchio::AccountView], params: &#params_type, rest: &'a [u8]) -> ::core::result::Result<Self, ::pinocchio::error::ProgramError> {
+                            Self::build_with_rest(accounts, params, rest).map_err(::core::convert::Into::into)
+                        }
+                    },
+                    "check_constraints" => quote! {
+                        #[inline]
+                        fn check_constraints(&self) -> ::pinocchio::ProgramResult {
+                            Self::check_constraints(self).map_err(::core::convert::Into::into)
+                        }
+                    },
+                    "validate" => quote! {
+                        #[inline]
+                        fn validate(&self, program_id: &::solana_address::Address, params: &#params_type) -> ::pinocchio::ProgramResult {
+                            Self::validate(self, program_id, params).map_err(::core::convert::Into::into)
+                        }
+                    },
+                    "execute" => quote! {
+                        #[inline]
+                        fn execute(&mut self, program_id: &::solana_address::Address, params: &#params_type) -> ::pinocchio::ProgramResult {
+                            Self::execute(self, program_id, params).map_err(::core::convert::Into::into)
+                        }
+                    },
+                    _ => return None,
+                };
+                Some(forward)
+            });
+            let user_impl = quote! {
+                const _: fn(#error_type) -> ::pinocchio::error::ProgramError = ::core::convert::Into::into;
+
+                impl #impl_generics #struct_type #where_clause {
+                    #(#items)*
+                }
+            };
+            (user_impl, quote! { #(#forwards)* })
+        }
+    };
+
+    let params_impl = instruction_params_impl(struct_name, &params_type, use_borsh);
+    let constraints_forward = constraints_forward(has_method("check_constraints"));
 
     let expanded = quote! {
+        #params_impl
+
+        #user_impl
+
+        impl<'a> ::solzempic::Instruction<'a> for #struct_name<'a> {
+            #trait_methods
+            #default_build
+            #constraints_forward
+            #default_validate
+            #default_execute
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Methods of an `#[instruction]` impl block that implement a trait phase.
+const PHASES: [&str; 6] = ["precheck", "build", "build_with_rest", "check_constraints", "validate", "execute"];
+
+/// Whether `method` implements one of the [`PHASES`].
+fn is_phase(method: &syn::ImplItemFn) -> bool {
+    PHASES.iter().any(|phase| method.sig.ident == phase)
+}
+
+/// Items of an `#[instruction]` impl block that belong in the inherent impl:
+/// everything but `type Ctx`, minus the phase methods if `skip_phases` (they
+/// then go in the trait impl). Inherent impls can't hold associated types,
+/// so any other `type` is an error.
+fn inherent_items(input: &ItemImpl, skip_phases: bool) -> syn::Result<Vec<&ImplItem>> {
+    let mut items = Vec::new();
+    for item in &input.items {
+        match item {
+            ImplItem::Type(ty) if ty.ident == "Ctx" => {}
+            ImplItem::Type(ty) => {
+                return Err(syn::Error::new_spanned(&ty.ident, "only `type Ctx` is allowed in an `#[instruction]` impl block"));
+            }
+            ImplItem::Fn(method) if skip_phases && is_phase(method) => {}
+            other => items.push(other),
+        }
+    }
+    Ok(items)
+}
+
+/// Check that an `#[instruction]` impl block defines `build` (or
+/// `build_with_rest`), and that no method looks like a misspelled phase.
+///
+/// A typo like `valdiate` would otherwise become a helper method while the
+/// defaulted `validate` silently accepts everything. Only methods with the
+/// missing phase's signature are flagged, so helpers with similar names
+/// (`recheck`, `invalidate`, `executed`) still compile.
+fn check_phase_methods(
+    input: &ItemImpl,
+    struct_name: &syn::Ident,
+    methods: &[&syn::ImplItemFn],
+    auto_build: bool,
+) -> syn::Result<()> {
+    let has_method = |name: &str| methods.iter().any(|method| method.sig.ident == name);
+    let has_build = auto_build || has_method("build") || has_method("build_with_rest");
+
+    // Only phases that are missing can have been misspelled, so a helper
+    // like `validator` next to a real `validate` is fine
+    let missing: Vec<&str> = [("build", has_build), ("build_with_rest", has_build), ("validate", has_method("validate")), ("execute", has_method("execute")), ("precheck", has_method("precheck"))]
+        .into_iter()
+        .filter(|&(_, present)| !present)
+        .map(|(phase, _)| phase)
+        .collect();
+    for method in methods {
+        let name = method.sig.ident.to_string();
+        let misspelled = missing.iter().find(|phase| {
+            !name.contains(*phase) && edit_distance(&name, phase) <= 2 && has_phase_signature(&method.sig, phase)
+        });
+        if let Some(phase) = misspelled {
+            return Err(syn::Error::new_spanned(
+                &method.sig.ident,
+                format!("`{}` is not an instruction phase; did you mean `{}`?", name, phase),
+            ));
+        }
+    }
+
+    if !has_build {
+        return Err(syn::Error::new_spanned(
+            &input.self_ty,
+            format!("instruction impl for `{}` must define `build` or `build_with_rest`, or pass `auto_build`", struct_name),
+        ));
+    }
+    Ok(())
+}
+
+/// Whether `sig` is shaped like `phase`: `validate` and `execute` take
+/// `&self`/`&mut self` plus the program ID, params and, in context mode, the
+/// context; the other phases are associated functions.
+fn has_phase_signature(sig: &syn::Signature, phase: &str) -> bool {
+    let receiver = sig.receiver().map(|receiver| receiver.reference.is_some());
+    let args = sig.inputs.iter().filter(|arg| matches!(arg, syn::FnArg::Typed(_))).count();
+    match phase {
+        "validate" | "execute" => receiver == Some(true) && (args == 2 || args == 3),
+        "build" => receiver.is_none() && args == 2,
+        "build_with_rest" => receiver.is_none() && args == 3,
+        "precheck" => receiver.is_none() && args == 1,
+        _ => false,
+    }
+}
+
+/// Levenshtein distance between two identifiers.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == cb { prev } else { 1 + prev.min(cur).min(row[j]) };
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
+
+/// `InstructionParams` impl plus the `IDL_NAME`/`IDL_PARAMS` constants shared
+/// by both impl-block modes of `#[instruction]`.
+fn instruction_params_impl(struct_name: &syn::Ident, params_type: &syn::Path, use_borsh: bool) -> proc_macro2::TokenStream {
+    let struct_name_str = struct_name.to_string();
+
+    // Borsh mode decodes variable-length params; default is the zero-copy cast
+    let (decode_body, decode_rest_body) = if use_borsh {
+        (
+            quote! { ::solzempic::parse_params_borsh::<#params_type>(data) },
+            quote! { ::solzempic::parse_params_borsh_with_rest::<#params_type>(data) },
+        )
+    } else {
+        (
+            quote! { ::solzempic::parse_params::<#params_type>(data) },
+            quote! { ::solzempic::parse_params_with_rest::<#params_type>(data) },
+        )
+    };
+
+    // Borsh params don't go through #[params], so they have no field metadata
+    let idl_params = if use_borsh {
+        quote! { &[] }
+    } else {
+        quote! { <#params_type as ::solzempic::ParamsMeta>::FIELDS }
+    };
+
+    quote! {
         impl ::solzempic::InstructionParams for #struct_name<'_> {
             type Params = #params_type;
-        }
 
-        impl<'a> ::solzempic::Instruction<'a> for #struct_name<'a> {
-            #(#methods)*
+            const NAME: &'static str = #struct_name_str;
+
+            #[inline]
+            fn decode_params(data: &[u8]) -> ::core::result::Result<Self::Params, ::pinocchio::error::ProgramError> {
+                #decode_body
+            }
+
+            #[inline]
+            fn decode_params_with_rest(data: &[u8]) -> ::core::result::Result<(Self::Params, &[u8]), ::pinocchio::error::ProgramError> {
+                #decode_rest_body
+            }
         }
 
         impl #struct_name<'_> {
@@ -563,7 +1468,121 @@ pub fn instruction(attr: TokenStream, item: TokenStream) -> TokenStream {
             pub const IDL_NAME: &'static str = #struct_name_str;
 
             /// Get params field metadata for IDL generation.
-            pub const IDL_PARAMS: &'static [::solzempic::ParamField] = <#params_type as ::solzempic::ParamsMeta>::FIELDS;
+            pub const IDL_PARAMS: &'static [::solzempic::ParamField] = #idl_params;
+        }
+    }
+}
+
+/// `check_constraints` for the generated `Instruction`/`ContextInstruction`
+/// impl: runs the struct's `InstructionConstraints` if the struct form of
+/// `#[instruction]` generated one, and is `Ok(())` otherwise.
+///
+/// This macro can't see the struct, so it probes for the impl with autoref
+/// method resolution (see `solzempic::ConstraintProbe`). A user-defined
+/// `check_constraints` takes precedence.
+fn constraints_forward(user_defined: bool) -> proc_macro2::TokenStream {
+    if user_defined {
+        return quote! {};
+    }
+    quote! {
+        #[inline]
+        fn check_constraints(&self) -> ::pinocchio::ProgramResult {
+            #[allow(unused_imports)]
+            use ::solzempic::{ProbeConstraints as _, ProbeNoConstraints as _};
+            #[allow(clippy::needless_borrow)]
+            let result = (&::solzempic::ConstraintProbe(self)).check();
+            result
+        }
+    }
+}
+
+/// Impl-block `#[instruction]` for context instructions (`type Ctx = ...;`).
+///
+/// Every item but `type Ctx` stays in an inherent impl, so the user's
+/// `build`/`build_with_rest`, `validate`, and `execute` (which take or return
+/// the context) sit next to their consts and helpers; `ContextInstruction`
+/// forwards to them. No `Instruction` impl is generated: dispatch goes
+/// through `ContextInstruction::process_with_context`.
+fn instruction_context_impl(
+    input: &ItemImpl,
+    struct_name: &syn::Ident,
+    params_type: &syn::Path,
+    use_borsh: bool,
+    error_type: Option<&Expr>,
+    ctx_type: &Type,
+    methods: &[&syn::ImplItemFn],
+) -> TokenStream {
+    let has_method = |name: &str| methods.iter().any(|method| method.sig.ident == name);
+    let struct_type = &input.self_ty;
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+
+    let build_call = if has_method("build_with_rest") {
+        quote! { Self::build_with_rest(accounts, params, rest).map_err(::core::convert::Into::into) }
+    } else {
+        quote! { let _ = rest; Self::build(accounts, params).map_err(::core::convert::Into::into) }
+    };
+    let validate_body = if has_method("validate") {
+        quote! { Self::validate(self, ctx, program_id, params).map_err(::core::convert::Into::into) }
+    } else {
+        quote! { let _ = (ctx, program_id, params); Ok(()) }
+    };
+    let execute_body = if has_method("execute") {
+        quote! { Self::execute(self, ctx, program_id, params).map_err(::core::convert::Into::into) }
+    } else {
+        quote! { let _ = (ctx, program_id, params); Ok(()) }
+    };
+    let precheck_forward = has_method("precheck").then(|| quote! {
+        #[inline]
+        fn precheck(params: &#params_type) -> ::pinocchio::ProgramResult {
+            Self::precheck(params).map_err(::core::convert::Into::into)
+        }
+    });
+    let error_check = error_type.map(|error_type| quote! {
+        const _: fn(#error_type) -> ::pinocchio::error::ProgramError = ::core::convert::Into::into;
+    });
+
+    let params_impl = instruction_params_impl(struct_name, params_type, use_borsh);
+    let constraints_forward = constraints_forward(has_method("check_constraints"));
+
+    let items = match inherent_items(input, false) {
+        Ok(items) => items,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let expanded = quote! {
+        #params_impl
+
+        #error_check
+
+        impl #impl_generics #struct_type #where_clause {
+            #(#items)*
+        }
+
+        impl<'a> ::solzempic::ContextInstruction<'a> for #struct_name<'a> {
+            type Ctx = #ctx_type;
+
+            #precheck_forward
+
+            #constraints_forward
+
+            #[inline]
+            fn build_with_context(
+                accounts: &'a [::pinocchio::AccountView],
+                params: &#params_type,
+                rest: &'a [u8],
+            ) -> ::core::result::Result<(Self, #ctx_type), ::pinocchio::error::ProgramError> {
+                #build_call
+            }
+
+            #[inline]
+            fn validate_with_context(&self, ctx: &#ctx_type, program_id: &::solana_address::Address, params: &#params_type) -> ::pinocchio::ProgramResult {
+                #validate_body
+            }
+
+            #[inline]
+            fn execute_with_context(&mut self, ctx: &#ctx_type, program_id: &::solana_address::Address, params: &#params_type) -> ::pinocchio::ProgramResult {
+                #execute_body
+            }
         }
     };
 
@@ -577,14 +1596,19 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
     let attrs = &input.attrs;
     let generics = &input.generics;
 
-    // Parse optional starting index from attribute (defaults to 0)
-    let start_index: usize = if attr.is_empty() {
-        0
-    } else {
-        syn::parse::<syn::LitInt>(attr)
-            .map(|lit| lit.base10_parse::<usize>().unwrap_or(0))
-            .unwrap_or(0)
-    };
+    // Parse optional starting index (defaults to 0) and `auto_build`
+    let mut start_index = 0usize;
+    let mut auto_build = false;
+    let attr_args = syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated
+        .parse(attr)
+        .unwrap_or_default();
+    for arg in attr_args {
+        match arg {
+            Expr::Lit(syn::ExprLit { lit: Lit::Int(lit), .. }) => start_index = lit.base10_parse::<usize>().unwrap_or(0),
+            Expr::Path(path) if path.path.is_ident("auto_build") => auto_build = true,
+            other => panic!("unknown instruction option `{}`, expected a start index or `auto_build`", quote!(#other)),
+        }
+    }
 
     let fields = match &input.fields {
         Fields::Named(fields_named) => &fields_named.named,
@@ -594,7 +1618,12 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
     // Analyze each field and determine account constraints
     let mut account_metas: Vec<proc_macro2::TokenStream> = Vec::new();
     let mut shank_attr_strings: Vec<String> = Vec::new();
+    let mut seed_checks: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut optional_counts: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut min_accounts = 0usize;
+    let mut has_skip = false;
     let mut current_idx = start_index;
+    let mut field_loads: Vec<proc_macro2::TokenStream> = Vec::new();
 
     for field in fields.iter() {
         let field_name = field.ident.as_ref().expect("Named field required");
@@ -603,22 +1632,105 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
 
         let (is_signer, is_writable, is_program, expand_count) = analyze_field_type(field_ty);
 
-        if expand_count > 1 {
+        // #[skip(N)]: N accounts not in the struct come before this field
+        let skip = field.attrs.iter().find(|attr| attr.path().is_ident("skip")).map_or(0, |attr| {
+            attr.parse_args::<syn::LitInt>()
+                .and_then(|lit| lit.base10_parse::<usize>())
+                .expect("skip expects a number of accounts, e.g. #[skip(2)]")
+        });
+        current_idx += skip;
+        has_skip |= skip > 0;
+
+        if auto_build {
+            field_loads.push(auto_build_field(field, current_idx));
+        }
+
+        // #[present_if(cond)] accounts must trail every required one
+        let present_if = field.attrs.iter().find(|attr| attr.path().is_ident("present_if")).map(|attr| {
+            attr.parse_args::<Expr>()
+                .expect("present_if expects a condition on `params`, e.g. #[present_if(params.has_referrer != 0)]")
+        });
+        let is_optional = present_if.is_some();
+        let passed_count = skip + expand_count;
+        match present_if {
+            Some(condition) => optional_counts.push(quote! {
+                if #condition {
+                    count += #passed_count;
+                }
+            }),
+            None if !optional_counts.is_empty() => panic!(
+                "`{}` follows a #[present_if] account; conditional accounts must come last",
+                field_name_str
+            ),
+            None => min_accounts += passed_count,
+        }
+        let optional_str = if is_optional { ", optional" } else { "" };
+
+        let seeds = parse_seeds(field);
+        if !seeds.is_empty() {
+            if expand_count != 1 || matches!(field_ty, Type::Array(_)) {
+                panic!("#[seeds] is only supported on single-account fields, not `{}`", field_name_str);
+            }
+            let seed_exprs = seeds.iter().map(|seed| match seed {
+                SeedArg::Const(bytes) => quote! { &#bytes[..] },
+                SeedArg::Account(account) => {
+                    let account = account_binding(account);
+                    quote! { #account.address().as_ref() }
+                }
+            });
+            let mut accounts = vec![field_name];
+            accounts.extend(seeds.iter().filter_map(|seed| match seed {
+                SeedArg::Account(account) => Some(account),
+                SeedArg::Const(_) => None,
+            }));
+            let account = account_binding(field_name);
+            seed_checks.push(with_accounts(fields, &accounts, quote! {
+                let (expected, _) = ::solana_address::Address::find_program_address(&[#(#seed_exprs),*], program_id);
+                if !::solana_address::address_eq(#account.address(), &expected) {
+                    return Err(::pinocchio::error::ProgramError::InvalidSeeds);
+                }
+            }));
+        }
+        let seed_metas: Vec<_> = seeds.iter().map(|seed| match seed {
+            SeedArg::Const(bytes) => quote! { ::solzempic::SeedMeta::Const(#bytes) },
+            SeedArg::Account(account) => {
+                let account_str = account.to_string();
+                quote! { ::solzempic::SeedMeta::Account(#account_str) }
+            }
+        }).collect();
+
+        if let Type::Array(_) = field_ty {
+            // Account arrays: {field_name}_0 .. {field_name}_{N-1}, each with the element's flags
+            let mut constraints = Vec::new();
+            if is_writable { constraints.push("writable"); }
+            if is_signer { constraints.push("signer"); }
+            let constraints_str: String = constraints.iter().map(|c| format!(", {}", c)).collect();
+
+            for i in 0..expand_count {
+                let idx = current_idx + i;
+                let nested_name = format!("{}_{}", field_name_str, i);
+                account_metas.push(quote! {
+                    ::solzempic::ShankAccountMeta::new(#idx, #nested_name)
+                        .signer(#is_signer)
+                        .writable(#is_writable)
+                        .program(#is_program)
+                        .optional(#is_optional)
+                });
+                shank_attr_strings.push(format!("#[account({}{}{}, name=\"{}\")]", idx, constraints_str, optional_str, nested_name));
+            }
+            current_idx += expand_count;
+        } else if expand_count > 1 {
             // Generate nested shard names: {field_name}_low_shard, {field_name}_current_shard, {field_name}_high_shard
             let shard_suffixes = ["low_shard", "current_shard", "high_shard"];
             for (i, suffix) in shard_suffixes.iter().enumerate() {
                 let idx = current_idx + i;
                 let nested_name = format!("{}_{}", field_name_str, suffix);
                 account_metas.push(quote! {
-                    ::solzempic::ShankAccountMeta {
-                        index: #idx,
-                        name: #nested_name,
-                        is_signer: false,
-                        is_writable: true,
-                        is_program: false,
-                    }
+                    ::solzempic::ShankAccountMeta::new(#idx, #nested_name)
+                        .writable(true)
+                        .optional(#is_optional)
                 });
-                shank_attr_strings.push(format!("#[account({}, writable, name=\"{}\")]", idx, nested_name));
+                shank_attr_strings.push(format!("#[account({}, writable{}, name=\"{}\")]", idx, optional_str, nested_name));
             }
             current_idx += expand_count;
         } else {
@@ -632,16 +1744,15 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
                 format!(", {}", constraints.join(", "))
             };
 
-            shank_attr_strings.push(format!("#[account({}{}, name=\"{}\")]", current_idx, constraints_str, field_name_str));
+            shank_attr_strings.push(format!("#[account({}{}{}, name=\"{}\")]", current_idx, constraints_str, optional_str, field_name_str));
 
             account_metas.push(quote! {
-                ::solzempic::ShankAccountMeta {
-                    index: #current_idx,
-                    name: #field_name_str,
-                    is_signer: #is_signer,
-                    is_writable: #is_writable,
-                    is_program: #is_program,
-                }
+                ::solzempic::ShankAccountMeta::new(#current_idx, #field_name_str)
+                    .signer(#is_signer)
+                    .writable(#is_writable)
+                    .program(#is_program)
+                    .optional(#is_optional)
+                    .seeds(&[#(#seed_metas),*])
             });
             current_idx += 1;
         }
@@ -649,24 +1760,152 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
 
     let num_accounts = account_metas.len();
     let shank_output = shank_attr_strings.join("\n    ");
+    let docs = extract_docs(attrs);
+
+    // Collect #[has_one(field = account, error = ...)] checks
+    let mut has_one_checks: Vec<proc_macro2::TokenStream> = Vec::new();
+    for field in fields.iter() {
+        let field_name = field.ident.as_ref().expect("Named field required");
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("has_one")) {
+            has_one_checks.extend(parse_has_one(fields, field_name, attr));
+        }
+    }
+
+    // Collect #[address(EXPECTED)] checks
+    let address_checks: Vec<proc_macro2::TokenStream> = fields.iter().flat_map(|field| {
+        let field_name = field.ident.as_ref().expect("Named field required");
+        field.attrs.iter().filter(|attr| attr.path().is_ident("address")).map(move |attr| {
+            let expected: Expr = attr.parse_args()
+                .expect("address expects an expression, e.g. #[address(CONFIG_ID)]");
+            let account = account_binding(field_name);
+            with_accounts(fields, &[field_name], quote! {
+                if !::solana_address::address_eq(#account.address(), &#expected) {
+                    return Err(::pinocchio::error::ProgramError::InvalidArgument);
+                }
+            })
+        })
+    }).collect();
+    let address_impl = if address_checks.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            /// Check the `#[address]` constraints declared on this struct's fields.
+            #[inline]
+            pub fn check_addresses(&self) -> ::core::result::Result<(), ::pinocchio::error::ProgramError> {
+                #(#address_checks)*
+                Ok(())
+            }
+        }
+    };
+
+    let seeds_impl = if seed_checks.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            /// Check that each `#[seeds]` field is the PDA of `program_id` for its seeds.
+            #[inline]
+            pub fn check_seeds(&self, program_id: &::solana_address::Address) -> ::core::result::Result<(), ::pinocchio::error::ProgramError> {
+                #(#seed_checks)*
+                Ok(())
+            }
+        }
+    };
+
+    let has_one_impl = if has_one_checks.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            /// Check the `#[has_one]` constraints declared on this struct's fields.
+            #[inline]
+            pub fn check_has_one(&self) -> ::core::result::Result<(), ::pinocchio::error::ProgramError> {
+                #(#has_one_checks)*
+                Ok(())
+            }
+        }
+    };
+
+    // `process` runs these between `build` and `validate` via `check_constraints`
+    let constraints_impl = if has_one_checks.is_empty() && address_checks.is_empty() {
+        quote! {}
+    } else {
+        let address_call = (!address_checks.is_empty()).then(|| quote! { self.check_addresses()?; });
+        let has_one_call = (!has_one_checks.is_empty()).then(|| quote! { self.check_has_one()?; });
+        quote! {
+            impl ::solzempic::InstructionConstraints for #struct_name<'_> {
+                #[inline]
+                fn check(&self) -> ::pinocchio::ProgramResult {
+                    #address_call
+                    #has_one_call
+                    Ok(())
+                }
+            }
+        }
+    };
+
+    let optional_impl = if optional_counts.is_empty() && !has_skip {
+        quote! {}
+    } else {
+        quote! {
+            /// Accounts passed when no `#[present_if]` condition holds.
+            pub const MIN_ACCOUNTS: usize = #min_accounts;
+
+            /// Number of accounts a client passes for `params`: the required
+            /// accounts plus each `#[present_if]` group whose condition holds.
+            #[inline]
+            pub fn expected_account_count(params: &<Self as ::solzempic::InstructionParams>::Params) -> usize {
+                let mut count = Self::MIN_ACCOUNTS;
+                #(#optional_counts)*
+                count
+            }
+        }
+    };
 
     let field_defs = fields.iter().map(|f| {
         let field_name = &f.ident;
         let field_ty = &f.ty;
         let field_vis = &f.vis;
-        let field_attrs = &f.attrs;
+        // Constraint attributes are consumed here, not forwarded
+        let field_attrs = f.attrs.iter()
+            .filter(|attr| !["has_one", "address", "seeds", "present_if", "manual", "skip"].iter().any(|name| attr.path().is_ident(name)));
         quote! {
             #(#field_attrs)*
             #field_vis #field_name: #field_ty
         }
     });
 
+    let auto_build_impl = if auto_build {
+        let lifetime = generics.lifetimes().next()
+            .map(|param| &param.lifetime)
+            .unwrap_or_else(|| panic!("auto_build needs a lifetime on `{}` for the loaded accounts", struct_name));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                /// Load each account field from `accounts` by its type, in
+                /// declaration order; `#[manual]` fields call `build_<field>`.
+                #[inline]
+                pub fn auto_build(
+                    accounts: &#lifetime [::pinocchio::AccountView],
+                    params: &<Self as ::solzempic::InstructionParams>::Params,
+                ) -> ::core::result::Result<Self, ::pinocchio::error::ProgramError> {
+                    let _ = params;
+                    Ok(Self {
+                        #(#field_loads),*
+                    })
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
         #(#attrs)*
         #vis struct #struct_name #generics {
             #(#field_defs),*
         }
 
+        #auto_build_impl
+
         impl #struct_name<'_> {
             pub const NUM_ACCOUNTS: usize = #num_accounts;
 
@@ -677,40 +1916,238 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
             pub fn shank_accounts() -> &'static str {
                 #shank_output
             }
+
+            /// Accounts in the order `build` expects them, for off-chain builders.
+            pub fn account_order() -> &'static [::solzempic::ShankAccountMeta] {
+                &Self::SHANK_ACCOUNTS
+            }
+
+            /// Doc comment lines for IDL generation.
+            pub const IDL_DOCS: &'static [&'static str] = &[#(#docs),*];
+
+            #has_one_impl
+
+            #address_impl
+
+            #seeds_impl
+
+            #optional_impl
         }
+
+        #constraints_impl
     };
 
     TokenStream::from(expanded)
 }
 
+/// Field initializer for `auto_build`: load the account at `index` with the
+/// constructor matching the field's type.
+///
+/// `#[manual]` fields call `Self::build_<field>(accounts, params)` instead,
+/// and `#[present_if]` fields load the inner type only when the condition holds.
+fn auto_build_field(field: &syn::Field, index: usize) -> proc_macro2::TokenStream {
+    let field_name = field.ident.as_ref().expect("Named field required");
+    if field.attrs.iter().any(|attr| attr.path().is_ident("manual")) {
+        let builder = syn::Ident::new(&format!("build_{}", field_name), field_name.span());
+        return quote! { #field_name: Self::#builder(accounts, params)? };
+    }
+
+    let present_if = field.attrs.iter().find(|attr| attr.path().is_ident("present_if"));
+    let load = match present_if {
+        Some(attr) => {
+            let condition: Expr = attr.parse_args().expect("present_if expects a condition on `params`");
+            let inner = option_inner(&field.ty).unwrap_or_else(|| {
+                panic!("auto_build: #[present_if] field `{}` must be an Option<T>", field_name)
+            });
+            let load = account_loader(field_name, inner, index);
+            quote! { if #condition { Some(#load) } else { None } }
+        }
+        None => account_loader(field_name, &field.ty, index),
+    };
+    quote! { #field_name: #load }
+}
+
+/// Expression loading the account at `index` as `ty`, or a panic naming the
+/// field when its type has no single-account constructor.
+fn account_loader(field_name: &syn::Ident, ty: &Type, index: usize) -> proc_macro2::TokenStream {
+    let account = quote! { ::solzempic::next_account(accounts, #index)? };
+    let type_name = match ty {
+        Type::Reference(reference) if !matches!(*reference.elem, Type::Slice(_)) => return account,
+        Type::Path(type_path) => type_path.path.segments.last().map(|segment| segment.ident.to_string()),
+        _ => None,
+    };
+    match type_name.as_deref() {
+        Some("AccountRef" | "AccountRefMut" | "TokenAccountRef" | "TokenAccountRefMut" | "ForeignRef" | "ForeignRefMut") => {
+            quote! { <#ty>::load(#account)? }
+        }
+        Some("Lut" | "SolVault") => quote! { <#ty>::wrap(#account)? },
+        Some("Option" | "Vault" | "VaultMut" | "ProgramSigner" | "ShardRefContext" | "ShardRefMutContext") | None => panic!(
+            "auto_build can't load `{}: {}`; mark it #[manual] and define `build_{}(accounts, params)`",
+            field_name,
+            quote!(#ty),
+            field_name
+        ),
+        Some(_) => quote! { <#ty as ::solzempic::ValidatedAccount<'_>>::wrap(#account)? },
+    }
+}
+
+/// `T` in `Option<T>`, if `ty` is an `Option`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else { return None };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+            syn::GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// One seed in a `#[seeds(...)]` template.
+enum SeedArg {
+    /// Byte string literal, e.g. `b"market"`.
+    Const(syn::LitByteStr),
+    /// Another account field, whose address is the seed.
+    Account(syn::Ident),
+}
+
+/// Parse the `#[seeds(b"prefix", other_field, ...)]` attribute on a field, if any.
+fn parse_seeds(field: &syn::Field) -> Vec<SeedArg> {
+    let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("seeds")) else {
+        return Vec::new();
+    };
+    let args = attr
+        .parse_args_with(syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated)
+        .expect("seeds expects a list, e.g. #[seeds(b\"market\", mint)]");
+    args.into_iter().map(|arg| match arg {
+        Expr::Lit(syn::ExprLit { lit: Lit::ByteStr(bytes), .. }) => SeedArg::Const(bytes),
+        Expr::Path(path) if path.path.get_ident().is_some() => {
+            SeedArg::Account(path.path.get_ident().cloned().expect("checked above"))
+        }
+        other => panic!(
+            "unsupported seed `{}`: seeds must be byte string literals or account field names",
+            quote!(#other)
+        ),
+    }).collect()
+}
+
+/// Parse one `#[has_one(data_field = account_field, ..., error = expr)]` attribute
+/// on `field_name` into address comparisons.
+fn parse_has_one(
+    fields: &syn::punctuated::Punctuated<syn::Field, syn::Token![,]>,
+    field_name: &syn::Ident,
+    attr: &syn::Attribute,
+) -> Vec<proc_macro2::TokenStream> {
+    let pairs = attr
+        .parse_args_with(syn::punctuated::Punctuated::<syn::MetaNameValue, syn::Token![,]>::parse_terminated)
+        .expect("has_one expects `field = account` pairs, e.g. #[has_one(authority = signer)]");
+
+    let mut error: Option<Expr> = None;
+    let mut targets: Vec<(syn::Ident, syn::Ident)> = Vec::new();
+    for pair in pairs {
+        let key = pair.path.get_ident()
+            .expect("has_one keys must be identifiers")
+            .clone();
+        if key == "error" {
+            error = Some(pair.value);
+            continue;
+        }
+        let account = match &pair.value {
+            Expr::Path(expr_path) => expr_path.path.get_ident()
+                .expect("has_one values must name a field of the instruction struct")
+                .clone(),
+            _ => panic!("has_one values must name a field of the instruction struct"),
+        };
+        targets.push((key, account));
+    }
+    if targets.is_empty() {
+        panic!("has_one on `{}` needs at least one `field = account` pair", field_name);
+    }
+
+    let error = match error {
+        Some(expr) => quote! { ::core::convert::Into::into(#expr) },
+        None => quote! { ::pinocchio::error::ProgramError::InvalidAccountOwner },
+    };
+
+    targets.into_iter().map(|(data_field, account)| {
+        let holder = account_binding(field_name);
+        let target = account_binding(&account);
+        with_accounts(fields, &[field_name, &account], quote! {
+            if !::solana_address::address_eq(&#holder.get().#data_field, #target.address()) {
+                return Err(#error);
+            }
+        })
+    }).collect()
+}
+
+/// Local name a constraint check uses for the struct field `account`.
+///
+/// Mixed-site hygiene keeps it from shadowing or being shadowed by names in
+/// the check itself (`program_id`, user expressions).
+fn account_binding(account: &syn::Ident) -> syn::Ident {
+    syn::Ident::new(&account.to_string(), proc_macro2::Span::mixed_site())
+}
+
+/// Wrap a constraint `check` so each of `accounts` is bound by
+/// [`account_binding`]. An `Option<_>` field (a `#[present_if]` account) is
+/// bound with `if let Some(..)`, so the check is skipped while it's absent.
+fn with_accounts(
+    fields: &syn::punctuated::Punctuated<syn::Field, syn::Token![,]>,
+    accounts: &[&syn::Ident],
+    check: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut bound: Vec<&syn::Ident> = Vec::new();
+    for account in accounts {
+        if !bound.contains(account) {
+            bound.push(account);
+        }
+    }
+    bound.into_iter().rev().fold(check, |body, account| {
+        let binding = account_binding(account);
+        let is_optional = fields.iter().any(|field| field.ident.as_ref() == Some(account) && option_inner(&field.ty).is_some());
+        if is_optional {
+            quote! { if let Some(#binding) = &self.#account { #body } }
+        } else {
+            quote! { { let #binding = &self.#account; #body } }
+        }
+    })
+}
+
 /// Derive macro for account structs with automatic discriminator handling.
 ///
-/// This macro transforms a simple struct definition into a zero-copy-safe
-/// account type with all necessary traits and discriminator validation.
+/// Turns a `#[repr(C)]` struct into a zero-copy-safe account type with all
+/// necessary traits and discriminator validation. A derive only adds impls,
+/// so the struct declares its own `discriminator` field and `#[repr(C)]`;
+/// use the `#[account]` attribute macro to have them written for you.
 ///
 /// # What It Generates
 ///
-/// From your struct definition, the macro produces:
-///
 /// | Generated | Purpose |
 /// |-----------|---------|
-/// | `#[repr(C)]` | Stable, predictable memory layout |
-/// | `Clone`, `Copy` | Value semantics |
-/// | `Pod`, `Zeroable` | Safe zero-copy casting via bytemuck |
-/// | `discriminator` field | 8-byte type identifier (prepended) |
-/// | `Loadable` impl | Zero-copy loading with validation |
+/// | `Clone`, `Copy` impls | Value semantics (don't derive them as well) |
+/// | `Pod`, `Zeroable` impls | Zero-copy casting via bytemuck |
+/// | Layout assertions | Every field is `Pod` and the struct has no implicit padding |
+/// | `LEN` const | Total size in bytes, discriminator included |
+/// | `DISCRIMINATOR_VALUE` / `DISCRIMINATOR_BYTES` | The discriminator as a byte and as stored |
+/// | `Loadable` impl | Zero-copy loading with validation; its `DISCRIMINATOR_BYTES`, all of which are checked on load, are the ones above |
 ///
 /// # Account Layout
 ///
-/// The discriminator is prepended to your fields:
+/// The struct must be `#[repr(C)]` and have a `discriminator: [u8; 8]`
+/// field, normally first. Its first byte holds the discriminator and the
+/// rest are zero:
 ///
 /// ```text
-/// Original:                    Generated:
-/// struct Counter {             struct Counter {
-///     owner: Pubkey,      →        discriminator: [u8; 8],  // Added
-///     count: u64,                  owner: Pubkey,
-/// }                                count: u64,
-///                              }
+/// #[repr(C)]
+/// struct Counter {
+///     discriminator: [u8; 8],  // [1, 0, 0, 0, 0, 0, 0, 0]
+///     owner: Pubkey,
+///     count: u64,
+/// }
 /// ```
 ///
 /// # Discriminator Values
@@ -725,24 +2162,28 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
 ///
 /// # Required Attribute
 ///
-/// The `#[account(discriminator = N)]` attribute is required:
+/// The `#[account(discriminator = N)]` attribute (or
+/// `#[account(discriminator_fn = f)]`, which uses `f("MyAccount")` and requires
+/// `f` to be a `const fn(&str) -> u8`) is required:
 ///
 /// ```ignore
+/// #[repr(C)]
 /// #[derive(Account)]
 /// #[account(discriminator = 1)]  // Required!
-/// pub struct MyAccount { ... }
+/// pub struct MyAccount { pub discriminator: [u8; 8], ... }
 /// ```
 ///
 /// # Field Requirements
 ///
-/// All fields must be `Pod`-safe (no padding, alignment 1 or power-of-2):
+/// All fields must be `Pod`, with no implicit padding between or after
+/// them. Both are checked at compile time:
 ///
 /// | Safe Types | Unsafe Types |
 /// |------------|--------------|
-/// | `u8`, `u16`, `u32`, `u64`, `u128` | `bool` (use `u8`) |
+/// | `u8`, `u16`, `u32`, `u64`, `u128` | `bool` (use `PodBool`) |
 /// | `i8`, `i16`, `i32`, `i64`, `i128` | `enum` (use `#[repr(u8)]`) |
 /// | `[u8; N]`, `Pubkey` | `String`, `Vec<T>` |
-/// | Other `Pod` structs | References, Box, Rc |
+/// | `PodBool`, other `Pod` structs | References, Box, Rc |
 ///
 /// # Example
 ///
@@ -751,9 +2192,11 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
 /// use pinocchio::pubkey::Pubkey;
 ///
 /// /// A simple counter account.
+/// #[repr(C)]
 /// #[derive(Account)]
 /// #[account(discriminator = 1)]
 /// pub struct Counter {
+///     pub discriminator: [u8; 8],
 ///     /// The authority who can increment.
 ///     pub authority: Pubkey,
 ///     /// Current count value.
@@ -761,9 +2204,11 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
 /// }
 ///
 /// /// User profile with multiple fields.
+/// #[repr(C)]
 /// #[derive(Account)]
 /// #[account(discriminator = 2)]
 /// pub struct UserProfile {
+///     pub discriminator: [u8; 8],
 ///     pub owner: Pubkey,
 ///     pub created_at: i64,
 ///     pub points: u64,
@@ -772,6 +2217,76 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
 /// }
 /// ```
 ///
+/// The discriminator may also be a variant of the enum generated by
+/// `define_account_types!`, which keeps a single source of truth for the
+/// values:
+///
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(Account)]
+/// #[account(discriminator = AccountType::Market)]
+/// pub struct Market {
+///     pub discriminator: [u8; 8],
+///     pub admin: Pubkey,
+/// }
+/// ```
+///
+/// # Size Assertion
+///
+/// The generated `LEN` constant is the full size including the 8-byte
+/// discriminator. Add `size = N` to fail compilation if the layout doesn't
+/// come out to exactly `N` bytes:
+///
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(Account)]
+/// #[account(discriminator = 1, size = 48)]
+/// pub struct Counter {
+///     pub discriminator: [u8; 8],
+///     pub authority: Pubkey,
+///     pub count: u64,
+/// }
+/// ```
+///
+/// # Existing Discriminator Field
+///
+/// Layouts migrated from another framework may already store their type tag
+/// somewhere other than the front. Add `field = "name"` to use that field
+/// instead of `discriminator`; `Loadable::DISCRIMINATOR_OFFSET` is set to its
+/// offset, so loading and `init` read and write the tag in place:
+///
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(Account)]
+/// #[account(discriminator = 3, field = "tag")]
+/// pub struct LegacyPool {
+///     pub authority: Pubkey,
+///     pub tag: u8,
+///     pub _padding: [u8; 7],
+///     pub liquidity: u64,
+/// }
+/// ```
+///
+/// The named field must exist; its first byte is compared against the
+/// discriminator, and `DISCRIMINATOR_BYTES` is that one byte.
+///
+/// # Tuple Structs
+///
+/// Small wrappers can be tuple structs. Field `0` is the `[u8; 8]`
+/// discriminator, so the data starts at `1`:
+///
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(Account)]
+/// #[account(discriminator = 4)]
+/// pub struct Nonce(pub [u8; 8], pub u64);
+///
+/// nonce.get_mut().1 += 1;
+/// ```
+///
+/// `field = ...` is not available on tuple structs, and Shank can't
+/// describe them since it needs named fields.
+///
 /// # Usage with AccountRef
 ///
 /// ```ignore
@@ -787,66 +2302,241 @@ fn instruction_struct_impl(attr: TokenStream, input: ItemStruct) -> TokenStream
 /// # Panics
 ///
 /// Compile-time panics if:
-/// - `#[account(discriminator = N)]` attribute is missing
-/// - Applied to non-struct (enum, union)
-/// - Struct has unnamed fields (tuple struct)
+/// - `#[account(discriminator = ...)]` attribute is missing
+/// - `field = ...` is not a string literal
+///
+/// Emits a compile error naming the struct if it is applied to an enum,
+/// union or unit struct, if it lacks `#[repr(C)]`, if it has no
+/// `discriminator: [u8; 8]` field (or the `field = ...` one doesn't exist),
+/// or if `field = ...` is used on a tuple struct.
 #[proc_macro_derive(Account, attributes(account))]
 pub fn derive_account(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
-    let vis = &input.vis;
 
     // Extract the discriminator value from #[account(discriminator = N)] attribute
-    let discriminator = extract_discriminator(&input.attrs)
+    let discriminator = extract_discriminator(&input.attrs, name)
         .expect("Account derive requires #[account(discriminator = N)] attribute");
 
+    // Optional #[account(size = N)] layout assertion
+    let size_assertion = extract_account_arg(&input.attrs, "size").map(|size| {
+        quote! {
+            const _: () = assert!(::core::mem::size_of::<#name>() == #size);
+        }
+    });
+
     // Get the struct fields
-    let fields = match &input.data {
+    let (fields, is_tuple) = match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
-            Fields::Named(fields_named) => &fields_named.named,
-            _ => panic!("Account derive only supports structs with named fields"),
+            Fields::Named(fields_named) => (&fields_named.named, false),
+            Fields::Unnamed(fields_unnamed) => (&fields_unnamed.unnamed, true),
+            Fields::Unit => {
+                return syn::Error::new_spanned(
+                    name,
+                    format!("Account derive on `{}`: unit structs have no data to load; add at least one field", name),
+                )
+                .to_compile_error()
+                .into();
+            }
         },
-        _ => panic!("Account derive only supports structs"),
+        _ => {
+            return syn::Error::new_spanned(
+                name,
+                format!("Account derive on `{}`: only structs can be accounts", name),
+            )
+            .to_compile_error()
+            .into();
+        }
     };
 
-    // Generate the new struct with discriminator field prepended
-    let field_defs = fields.iter().map(|f| {
-        let field_name = &f.ident;
-        let field_ty = &f.ty;
-        let field_vis = &f.vis;
-        let attrs = &f.attrs;
-        quote! {
-            #(#attrs)*
-            #field_vis #field_name: #field_ty
+    if !has_repr_c(&input.attrs) {
+        return syn::Error::new_spanned(
+            name,
+            format!("Account derive on `{}`: add #[repr(C)] so the layout is stable for zero-copy loading", name),
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if is_tuple {
+        if extract_account_arg(&input.attrs, "field").is_some() {
+            return syn::Error::new_spanned(
+                name,
+                format!("#[account(field = ...)] on tuple struct `{}`: the discriminator is always field `0`", name),
+            )
+            .to_compile_error()
+            .into();
+        }
+        let field_tys: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+        return derive_tuple_account(name, &field_tys, &discriminator, size_assertion);
+    }
+
+    // The discriminator is the `discriminator` field unless #[account(field = "name")]
+    // picks an existing one, e.g. the type tag of a legacy layout
+    let custom_field = extract_account_arg(&input.attrs, "field").map(|expr| match expr {
+        Expr::Lit(syn::ExprLit { lit: Lit::Str(lit), .. }) => lit.value(),
+        _ => panic!("#[account(field = ...)] expects a string literal field name"),
+    });
+    let field_name = custom_field.clone().unwrap_or_else(|| "discriminator".to_string());
+    let Some(discriminator_field) = fields.iter()
+        .filter_map(|f| f.ident.as_ref())
+        .find(|ident| *ident == field_name.as_str())
+    else {
+        let msg = match &custom_field {
+            Some(field) => format!("#[account(field = \"{}\")] does not name a field of `{}`", field, name),
+            None => format!(
+                "Account derive on `{}`: declare `pub discriminator: [u8; 8]` as the first field, or name the tag field with #[account(field = \"...\")]",
+                name
+            ),
+        };
+        return syn::Error::new_spanned(name, msg).to_compile_error().into();
+    };
+
+    // A prepended-style `discriminator` field is the full 8-byte array; a custom
+    // field is compared on its first byte only
+    let (bytes_len, field_type_check) = if custom_field.is_some() {
+        (quote! { 1 }, quote! {})
+    } else {
+        (
+            quote! { 8 },
+            quote! {
+                const _: fn(&#name) -> &[u8; 8] = |account| &account.discriminator;
+            },
+        )
+    };
+    let discriminator_offset = quote! { ::core::mem::offset_of!(#name, #discriminator_field) };
+
+    let field_tys: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let pod_impls = account_derive_pod_impls(name, &field_tys);
+    let expanded = quote! {
+        #pod_impls
+        #field_type_check
+        #size_assertion
+
+        impl #name {
+            /// Size of the account in bytes, including the discriminator.
+            pub const LEN: usize = ::core::mem::size_of::<Self>();
+
+            /// The discriminator value for this account type.
+            pub const DISCRIMINATOR_VALUE: u8 = #discriminator as u8;
+
+            /// The discriminator as stored in the account.
+            pub const DISCRIMINATOR_BYTES: [u8; #bytes_len] = {
+                let mut bytes = [0u8; #bytes_len];
+                bytes[0] = Self::DISCRIMINATOR_VALUE;
+                bytes
+            };
+
+            /// Check if data has the correct discriminator.
+            #[inline]
+            pub fn check_discriminator(data: &[u8]) -> bool {
+                data.get(#discriminator_offset) == Some(&Self::DISCRIMINATOR_VALUE)
+            }
+        }
+
+        impl ::solzempic::Loadable for #name {
+            const DISCRIMINATOR: u8 = #name::DISCRIMINATOR_VALUE;
+            const DISCRIMINATOR_BYTES: &'static [u8] = &#name::DISCRIMINATOR_BYTES;
+            const DISCRIMINATOR_OFFSET: usize = #discriminator_offset;
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Whether the attributes include `#[repr(C)]` (possibly alongside others,
+/// e.g. `#[repr(C, align(8))]`).
+fn has_repr_c(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("repr")).any(|attr| {
+        attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+            .is_ok_and(|metas| metas.iter().any(|meta| meta.path().is_ident("C")))
+    })
+}
+
+/// `Clone`, `Copy`, `Pod` and `Zeroable` for a `#[derive(Account)]` type.
+///
+/// A derive can't add `#[derive]`s to the struct it is on, so the impls are
+/// written out. `Pod`/`Zeroable` are unsafe impls made sound by the
+/// `#[repr(C)]` check and [`pod_layout_checks`].
+fn account_derive_pod_impls(name: &syn::Ident, field_tys: &[&Type]) -> proc_macro2::TokenStream {
+    let layout_checks = pod_layout_checks(name, field_tys);
+    quote! {
+        #layout_checks
+
+        impl ::core::clone::Clone for #name {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
         }
-    });
 
+        impl ::core::marker::Copy for #name {}
+
+        // Safety: #[repr(C)], every field is Pod and there is no padding
+        unsafe impl ::bytemuck::Zeroable for #name {}
+
+        unsafe impl ::bytemuck::Pod for #name {}
+    }
+}
+
+/// Compile-time checks behind the unsafe `Pod` impls of the account macros,
+/// standing in for what `#[derive(Pod)]` verifies: every field type is `Pod`,
+/// and the fields add up to the struct's size, so there is no implicit padding.
+fn pod_layout_checks(name: &syn::Ident, field_tys: &[&Type]) -> proc_macro2::TokenStream {
+    let padding_msg = format!(
+        "account `{}` has implicit padding; add explicit `_padding` fields",
+        name
+    );
+    quote! {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#name>() == 0 #(+ ::core::mem::size_of::<#field_tys>())*,
+            #padding_msg
+        );
+
+        const _: fn() = || {
+            fn field_is_pod<T: ::bytemuck::Pod>() {}
+            #(field_is_pod::<#field_tys>();)*
+        };
+    }
+}
+
+/// `#[derive(Account)]` for tuple structs: field `0` is the discriminator.
+///
+/// Shank can only describe named fields, so there is nothing to add for it.
+fn derive_tuple_account(
+    name: &syn::Ident,
+    field_tys: &[&Type],
+    discriminator: &Expr,
+    size_assertion: Option<proc_macro2::TokenStream>,
+) -> TokenStream {
+    let pod_impls = account_derive_pod_impls(name, field_tys);
     let expanded = quote! {
-        #[repr(C)]
-        #[derive(Clone, Copy, ::bytemuck::Pod, ::bytemuck::Zeroable)]
-        #[derive(::solzempic::shank::ShankAccount)]
-        #vis struct #name {
-            /// Account discriminator (8 bytes)
-            pub discriminator: [u8; 8],
-            #(#field_defs),*
-        }
+        #pod_impls
+
+        const _: fn(&#name) -> &[u8; 8] = |account| &account.0;
+
+        #size_assertion
 
         impl #name {
+            /// Size of the account in bytes, including the discriminator.
+            pub const LEN: usize = ::core::mem::size_of::<Self>();
+
             /// The discriminator value for this account type.
-            pub const DISCRIMINATOR_VALUE: u8 = #discriminator;
+            pub const DISCRIMINATOR_VALUE: u8 = #discriminator as u8;
 
-            /// The discriminator as an 8-byte array.
-            pub const DISCRIMINATOR_BYTES: [u8; 8] = [#discriminator, 0, 0, 0, 0, 0, 0, 0];
+            /// The discriminator as stored in the account.
+            pub const DISCRIMINATOR_BYTES: [u8; 8] = [Self::DISCRIMINATOR_VALUE, 0, 0, 0, 0, 0, 0, 0];
 
             /// Check if data has the correct discriminator.
             #[inline]
             pub fn check_discriminator(data: &[u8]) -> bool {
-                !data.is_empty() && data[0] == #discriminator
+                data.first() == Some(&Self::DISCRIMINATOR_VALUE)
             }
         }
 
         impl ::solzempic::Loadable for #name {
-            const DISCRIMINATOR: u8 = #discriminator;
+            const DISCRIMINATOR: u8 = #name::DISCRIMINATOR_VALUE;
+            const DISCRIMINATOR_BYTES: &'static [u8] = &#name::DISCRIMINATOR_BYTES;
         }
     };
 
@@ -860,6 +2550,14 @@ fn analyze_field_type(ty: &Type) -> (bool, bool, bool, usize) {
         Type::Path(type_path) => {
             if let Some(segment) = type_path.path.segments.last() {
                 let type_name = segment.ident.to_string();
+                // Option<T> (e.g. a #[present_if] account) has T's flags
+                if type_name == "Option" {
+                    if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                        if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                            return analyze_field_type(inner);
+                        }
+                    }
+                }
                 match type_name.as_str() {
                     // Signer types
                     "Signer" => (true, false, false, 1),
@@ -870,6 +2568,8 @@ fn analyze_field_type(ty: &Type) -> (bool, bool, bool, usize) {
                     "AccountRefMut" => (false, true, false, 1),
                     "TokenAccountRefMut" => (false, true, false, 1),
                     "Writable" => (false, true, false, 1),
+                    "ForeignRefMut" => (false, true, false, 1),
+                    "VaultMut" => (false, true, false, 1),
 
                     // Readonly account types
                     "AccountRef" => (false, false, false, 1),
@@ -879,6 +2579,8 @@ fn analyze_field_type(ty: &Type) -> (bool, bool, bool, usize) {
                     "SolVault" => (false, false, false, 1),
                     "ValidatedAccount" => (false, false, false, 1),
                     "ReadOnly" => (false, false, false, 1),
+                    "ForeignRef" => (false, false, false, 1),
+                    "ProgramSigner" => (false, false, false, 1),  // signs CPIs only
 
                     // Writable specialized types
                     "Lut" => (false, true, false, 1),  // LUTs are typically created/modified
@@ -904,18 +2606,55 @@ fn analyze_field_type(ty: &Type) -> (bool, bool, bool, usize) {
             // &'a AccountView - default to readonly (use Writable<'a> for writable)
             (false, false, false, 1)
         }
+        Type::Array(array) => {
+            // [T; N] expands to N consecutive accounts with T's flags
+            let (is_signer, is_writable, is_program, elem_count) = analyze_field_type(&array.elem);
+            if elem_count != 1 {
+                panic!("account arrays must hold single-account types, not shard contexts");
+            }
+            let len = match &array.len {
+                Expr::Lit(syn::ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse::<usize>()
+                    .expect("account array length must be an integer"),
+                _ => panic!("account array length must be an integer literal so the accounts can be listed"),
+            };
+            (is_signer, is_writable, is_program, len)
+        }
         _ => (false, false, false, 1),
     }
 }
 
 /// Attribute macro for account structs.
 ///
-/// Adds `#[repr(C)]`, `#[derive(Clone, Copy)]`, unsafe Pod/Zeroable impls, and optionally
-/// `#[derive(ShankAccount)]` (when `shank` feature is enabled).
-///
-/// If a discriminator is provided, also generates `impl Loadable`.
-///
-/// Uses unsafe impl for Pod/Zeroable to support structs with manually-verified padding.
+/// Adds `#[repr(C)]`, `#[derive(Clone, Copy)]`, unsafe Pod/Zeroable impls, and
+/// `#[derive(ShankAccount)]` unless `no_shank` is given.
+///
+/// If a discriminator is provided, also generates `impl Loadable`, whose
+/// `DISCRIMINATOR_BYTES` is that single byte.
+/// `discriminator_fn = f` computes it instead as `f("StructName")`, where `f`
+/// is a `const fn(&str) -> u8`, so every account type can share one naming
+/// or hashing scheme. Only `u8` is accepted, since `Loadable::DISCRIMINATOR`
+/// is one byte; reduce a wider hash to a byte inside `f`.
+///
+/// Pass `no_shank` for structs whose fields Shank can't describe (e.g. nested
+/// Pod structs); the `AccountIdlMeta` metadata is still generated.
+///
+/// Mark an alignment-1 byte-array field with `#[le]` to generate a getter of
+/// the same name and a `set_` setter that convert it to and from the unsigned
+/// integer of its width. `#[le(i64)]` picks the type explicitly. The IDL
+/// describes the field as that integer, since the byte layout matches.
+///
+/// The Pod/Zeroable impls are checked at compile time the way
+/// `#[derive(Pod)]` would: every field type must be `Pod`, and the fields must
+/// add up to the struct's size, so implicit padding needs an explicit
+/// `_padding` field.
+///
+/// Generic structs (e.g. `Ring<const N: usize>`) get generic impls, with type
+/// parameters and field types required to be `Pod`. Their padding check runs
+/// per instance, when `Loadable::LEN` is evaluated, so it needs a
+/// discriminator. They skip the `ShankAccount` derive and
+/// the `idl` inventory registration, since neither has a single layout to
+/// describe; submit `&<Ring<64> as AccountIdlMeta>::META` for the instances
+/// the program uses.
 ///
 /// # Example
 ///
@@ -933,6 +2672,30 @@ fn analyze_field_type(ty: &Type) -> (bool, bool, bool, usize) {
 ///     pub discriminator: [u8; 8],
 ///     pub admin: Pubkey,
 /// }
+///
+/// // Little-endian accessors (`fees()`/`set_fees()`, `pnl()`/`set_pnl()`):
+/// #[account(discriminator = AccountType::Vault)]
+/// pub struct Vault {
+///     pub discriminator: [u8; 8],
+///     #[le]
+///     fees: [u8; 8],
+///     #[le(i64)]
+///     pnl: [u8; 8],
+/// }
+///
+/// // Discriminator computed from the name, here `account_disc("Position")`:
+/// #[account(discriminator_fn = account_disc)]
+/// pub struct Position {
+///     pub discriminator: [u8; 8],
+///     pub size: u64,
+/// }
+///
+/// // Without the ShankAccount derive:
+/// #[account(discriminator = AccountType::Book, no_shank)]
+/// pub struct Book {
+///     pub discriminator: [u8; 8],
+///     pub levels: [Level; 64],
+/// }
 /// ```
 #[proc_macro_attribute]
 pub fn account(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -941,19 +2704,74 @@ pub fn account(attr: TokenStream, item: TokenStream) -> TokenStream {
     let vis = &input.vis;
     let attrs = &input.attrs;
     let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let is_generic = !generics.params.is_empty();
+    let field_tys: Vec<&Type> = input.fields.iter().map(|f| &f.ty).collect();
+
+    // Type parameters and field types must themselves be Pod for the struct
+    // to be, which the trait impls below also rely on
+    let (pod_bounds, zeroable_bounds) = if is_generic {
+        let type_params: Vec<_> = generics.type_params().map(|param| &param.ident).collect();
+        let predicates: Vec<_> = generics.where_clause.iter().flat_map(|clause| &clause.predicates).collect();
+        (
+            quote! { where #(#predicates,)* #(#type_params: ::bytemuck::Pod,)* #(#field_tys: ::bytemuck::Pod,)* },
+            quote! { where #(#predicates,)* #(#type_params: ::bytemuck::Zeroable,)* #(#field_tys: ::bytemuck::Zeroable,)* },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
 
-    // Parse discriminator from attribute if provided
-    let discriminator_expr: Option<syn::Expr> = if attr.is_empty() {
-        None
+    // A generic struct's size is only known per instance, so its padding check
+    // runs when `Loadable::LEN` is evaluated for that instance
+    let (layout_checks, loadable_len) = if is_generic {
+        let padding_msg = format!(
+            "account `{}` has implicit padding; add explicit `_padding` fields",
+            name
+        );
+        (
+            quote! {},
+            quote! {
+                const LEN: usize = {
+                    ::core::assert!(
+                        ::core::mem::size_of::<Self>() == 0 #(+ ::core::mem::size_of::<#field_tys>())*,
+                        #padding_msg
+                    );
+                    ::core::mem::size_of::<Self>()
+                };
+            },
+        )
     } else {
-        let attr_str = attr.to_string();
-        // Parse "discriminator = <expr>"
-        if let Some(eq_pos) = attr_str.find('=') {
-            let expr_str = attr_str[eq_pos + 1..].trim();
-            syn::parse_str(expr_str).ok()
-        } else {
-            None
+        (pod_layout_checks(name, &field_tys), quote! {})
+    };
+
+    // Parse optional `discriminator = <expr>` and `no_shank`
+    let attr_metas = syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated
+        .parse(attr)
+        .expect("account macro expects `discriminator = ...` (or `discriminator_fn = ...`) and/or `no_shank`");
+    let mut discriminator_expr: Option<syn::Expr> = None;
+    let mut no_shank = false;
+    for meta in attr_metas {
+        let disc = match meta {
+            syn::Meta::NameValue(nv) if nv.path.is_ident("discriminator") => nv.value,
+            syn::Meta::NameValue(nv) if nv.path.is_ident("discriminator_fn") => discriminator_from_fn(&nv.value, name),
+            syn::Meta::Path(flag) if flag.is_ident("no_shank") => {
+                no_shank = true;
+                continue;
+            }
+            other => panic!(
+                "unknown account option `{}`, expected `discriminator = ...`, `discriminator_fn = ...` or `no_shank`",
+                quote!(#other)
+            ),
+        };
+        if discriminator_expr.replace(disc).is_some() {
+            panic!("account `{}` sets its discriminator more than once", name);
         }
+    }
+    // Shank describes concrete types only
+    let shank_derive = if no_shank || is_generic {
+        quote! {}
+    } else {
+        quote! { #[derive(::solzempic::shank::ShankAccount)] }
     };
 
     let fields = match &input.fields {
@@ -961,17 +2779,56 @@ pub fn account(attr: TokenStream, item: TokenStream) -> TokenStream {
         _ => panic!("account macro only supports structs with named fields"),
     };
 
+    // `#[le]` fields are consumed here; the attribute isn't emitted
+    let le_types: Vec<Option<Type>> = fields.iter().map(parse_le).collect();
+
     let field_defs = fields.iter().map(|f| {
         let field_name = &f.ident;
         let field_ty = &f.ty;
         let field_vis = &f.vis;
-        let field_attrs = &f.attrs;
+        let field_attrs = f.attrs.iter().filter(|attr| !attr.path().is_ident("le"));
         quote! {
             #(#field_attrs)*
             #field_vis #field_name: #field_ty
         }
     });
 
+    let le_accessors: Vec<_> = fields.iter().zip(&le_types).filter_map(|(f, int_ty)| {
+        let int_ty = int_ty.as_ref()?;
+        let field_name = f.ident.as_ref().expect("named field");
+        let setter = syn::Ident::new(&format!("set_{}", field_name), field_name.span());
+        let docs: Vec<_> = f.attrs.iter().filter(|attr| attr.path().is_ident("doc")).collect();
+        let getter_doc = if docs.is_empty() {
+            let doc = format!("Read `{}`, stored little-endian.", field_name);
+            quote! { #[doc = #doc] }
+        } else {
+            quote! { #(#docs)* }
+        };
+        let setter_doc = format!("Set `{}`, stored little-endian.", field_name);
+        Some(quote! {
+            #getter_doc
+            #[inline]
+            #vis fn #field_name(&self) -> #int_ty {
+                <#int_ty>::from_le_bytes(self.#field_name)
+            }
+
+            #[doc = #setter_doc]
+            #[inline]
+            #vis fn #setter(&mut self, value: #int_ty) {
+                self.#field_name = value.to_le_bytes();
+            }
+        })
+    }).collect();
+    let le_impl = if le_accessors.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #(#le_accessors)*
+            }
+        }
+    };
+
     // Check if struct has a discriminator field
     let has_discriminator_field = fields.iter().any(|f| {
         f.ident.as_ref().map(|i| i == "discriminator").unwrap_or(false)
@@ -981,9 +2838,9 @@ pub fn account(attr: TokenStream, item: TokenStream) -> TokenStream {
     let loadable_impl = discriminator_expr.clone().map(|disc| {
         let account_impl = if has_discriminator_field {
             quote! {
-                impl ::solzempic::traits::Account for #name {
+                impl #impl_generics ::solzempic::traits::Account for #name #ty_generics #pod_bounds {
                     const DISCRIMINATOR: u8 = #disc as u8;
-                    const LEN: usize = ::core::mem::size_of::<Self>();
+                    const LEN: usize = <Self as ::solzempic::Loadable>::LEN;
 
                     #[inline]
                     fn discriminator(&self) -> &[u8; 8] {
@@ -996,24 +2853,28 @@ pub fn account(attr: TokenStream, item: TokenStream) -> TokenStream {
         };
 
         quote! {
-            impl ::solzempic::Loadable for #name {
+            impl #impl_generics ::solzempic::Loadable for #name #ty_generics #pod_bounds {
                 const DISCRIMINATOR: u8 = #disc as u8;
+                #loadable_len
             }
 
-            impl ::solzempic::Initializable for #name {}
+            impl #impl_generics ::solzempic::Initializable for #name #ty_generics #pod_bounds {}
 
             #account_impl
         }
     });
 
     // Generate field metadata for IDL
-    let field_metas: Vec<_> = fields.iter().map(|f| {
+    let field_metas: Vec<_> = fields.iter().zip(&le_types).map(|(f, int_ty)| {
         let field_name = f.ident.as_ref().expect("named field").to_string();
-        let field_type = type_to_string(&f.ty);
+        // `#[le]` byte arrays have the integer's wire layout
+        let field_type = type_to_string(int_ty.as_ref().unwrap_or(&f.ty));
+        let field_docs = extract_docs(&f.attrs);
         quote! {
             ::solzempic::FieldMeta {
                 name: #field_name,
                 type_name: #field_type,
+                docs: &[#(#field_docs),*],
             }
         }
     }).collect();
@@ -1022,40 +2883,55 @@ pub fn account(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     // Generate AccountIdlMeta impl if discriminator is provided
     let idl_meta_impl = discriminator_expr.as_ref().map(|disc| {
+        // A generic type has no single layout to register; callers submit
+        // `&<Ring<64> as AccountIdlMeta>::META` for the instances they use
+        let registration = if is_generic {
+            quote! {}
+        } else {
+            quote! {
+                // Auto-register with inventory when idl feature is enabled
+                #[cfg(feature = "idl")]
+                ::solzempic::inventory::submit! {
+                    &<#name as ::solzempic::AccountIdlMeta>::META
+                }
+            }
+        };
         quote! {
-            impl ::solzempic::AccountIdlMeta for #name {
+            impl #impl_generics ::solzempic::AccountIdlMeta for #name #ty_generics #pod_bounds {
                 const NAME: &'static str = #name_str;
                 const DISCRIMINATOR: u8 = #disc as u8;
                 const FIELDS: &'static [::solzempic::FieldMeta] = &[
                     #(#field_metas),*
                 ];
                 const META: ::solzempic::AccountTypeMeta = ::solzempic::AccountTypeMeta {
-                    name: Self::NAME,
-                    discriminator: Self::DISCRIMINATOR,
-                    fields: Self::FIELDS,
+                    name: <Self as ::solzempic::AccountIdlMeta>::NAME,
+                    discriminator: <Self as ::solzempic::AccountIdlMeta>::DISCRIMINATOR,
+                    fields: <Self as ::solzempic::AccountIdlMeta>::FIELDS,
+                    size: ::core::mem::size_of::<Self>(),
                 };
             }
 
-            // Auto-register with inventory when idl feature is enabled
-            #[cfg(feature = "idl")]
-            ::solzempic::inventory::submit! {
-                &<#name as ::solzempic::AccountIdlMeta>::META
-            }
+            #registration
         }
     });
 
+
     let expanded = quote! {
         #[repr(C)]
         #[derive(Clone, Copy)]
-        #[derive(::solzempic::shank::ShankAccount)]
+        #shank_derive
         #(#attrs)*
-        #vis struct #name #generics {
+        #vis struct #name #generics #where_clause {
             #(#field_defs),*
         }
 
-        // Safety: Struct is #[repr(C)] - caller ensures no uninitialized padding
-        unsafe impl ::bytemuck::Pod for #name {}
-        unsafe impl ::bytemuck::Zeroable for #name {}
+        #layout_checks
+
+        // Safety: #[repr(C)], every field is Pod and there is no padding
+        unsafe impl #impl_generics ::bytemuck::Pod for #name #ty_generics #pod_bounds {}
+        unsafe impl #impl_generics ::bytemuck::Zeroable for #name #ty_generics #zeroable_bounds {}
+
+        #le_impl
 
         #loadable_impl
 
@@ -1065,6 +2941,36 @@ pub fn account(attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Integer type behind an `#[le]` / `#[le(T)]` field attribute.
+///
+/// Without an explicit type, `[u8; N]` maps to the unsigned integer of
+/// that width.
+fn parse_le(field: &syn::Field) -> Option<Type> {
+    let attr = field.attrs.iter().find(|attr| attr.path().is_ident("le"))?;
+    if let syn::Meta::List(_) = &attr.meta {
+        return Some(attr.parse_args::<Type>().expect("#[le(T)] expects an integer type, e.g. #[le(i64)]"));
+    }
+    let len = match &field.ty {
+        Type::Array(array) => match &array.len {
+            Expr::Lit(syn::ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse::<usize>().ok(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let int_ty = match len {
+        Some(1) => quote! { u8 },
+        Some(2) => quote! { u16 },
+        Some(4) => quote! { u32 },
+        Some(8) => quote! { u64 },
+        Some(16) => quote! { u128 },
+        _ => panic!(
+            "#[le] field `{}` must be [u8; 1|2|4|8|16], or name its type with #[le(T)]",
+            field.ident.as_ref().expect("named field")
+        ),
+    };
+    Some(syn::parse2(int_ty).expect("integer type"))
+}
+
 /// Extract discriminator value from `#[account(discriminator = N)]` attribute.
 ///
 /// Parses the attribute list looking for the `account` attribute with a
@@ -1083,7 +2989,22 @@ pub fn account(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// #[account(discriminator = 256)]   // ✗ Overflow (not u8)
 /// #[account(discriminator = "1")]   // ✗ String, not integer
 /// ```
-fn extract_discriminator(attrs: &[syn::Attribute]) -> Option<u8> {
+fn extract_discriminator(attrs: &[syn::Attribute], name: &syn::Ident) -> Option<Expr> {
+    extract_account_arg(attrs, "discriminator")
+        .or_else(|| extract_account_arg(attrs, "discriminator_fn").map(|func| discriminator_from_fn(&func, name)))
+}
+
+/// `discriminator_fn = f`: call the `const fn(&str) -> u8` with the type name.
+///
+/// The result is bound to a `u8` so a function returning anything else (e.g.
+/// an 8-byte hash) fails with a type mismatch rather than an invalid cast.
+fn discriminator_from_fn(func: &Expr, name: &syn::Ident) -> Expr {
+    let name_str = name.to_string();
+    syn::parse_quote! { { let discriminator: u8 = #func(#name_str); discriminator } }
+}
+
+/// Extract the value of `key = <expr>` from an `#[account(...)]` attribute.
+fn extract_account_arg(attrs: &[syn::Attribute], key: &str) -> Option<Expr> {
     for attr in attrs {
         if attr.path().is_ident("account") {
             let nested = attr.parse_args_with(
@@ -1092,12 +3013,8 @@ fn extract_discriminator(attrs: &[syn::Attribute]) -> Option<u8> {
 
             for meta in nested {
                 if let syn::Meta::NameValue(nv) = meta {
-                    if nv.path.is_ident("discriminator") {
-                        if let Expr::Lit(expr_lit) = &nv.value {
-                            if let Lit::Int(lit_int) = &expr_lit.lit {
-                                return lit_int.base10_parse::<u8>().ok();
-                            }
-                        }
+                    if nv.path.is_ident(key) {
+                        return Some(nv.value);
                     }
                 }
             }
@@ -1109,13 +3026,15 @@ fn extract_discriminator(attrs: &[syn::Attribute]) -> Option<u8> {
 /// Attribute macro for instruction parameter structs.
 ///
 /// Generates `impl InstructionParams` with field metadata for IDL generation.
-/// Also adds `#[repr(C)]`, `#[derive(Clone, Copy)]`, and Pod/Zeroable impls.
+/// Also adds `#[repr(C)]`, `#[derive(Clone, Copy)]`, Pod/Zeroable impls, and
+/// `TryFrom<&[u8]>` (length-checked, same as `solzempic::parse_params`).
 ///
 /// # Example
 ///
 /// ```ignore
 /// #[params]
 /// pub struct CancelClmmPositionParams {
+///     /// Order to cancel.
 ///     pub order_id: u64,
 ///     pub side: u8,
 ///     pub _padding: [u8; 7],
@@ -1124,12 +3043,22 @@ fn extract_discriminator(attrs: &[syn::Attribute]) -> Option<u8> {
 /// // Generates:
 /// // impl InstructionParams for CancelClmmPositionParams {
 /// //     const FIELDS: &'static [ParamField] = &[
-/// //         ParamField { name: "order_id", type_name: "u64" },
-/// //         ParamField { name: "side", type_name: "u8" },
-/// //         ParamField { name: "_padding", type_name: "[u8; 7]" },
+/// //         ParamField { name: "order_id", type_name: "u64", docs: &["Order to cancel."] },
+/// //         ParamField { name: "side", type_name: "u8", docs: &[] },
+/// //         ParamField { name: "_padding", type_name: "[u8; 7]", docs: &[] },
 /// //     ];
 /// // }
 /// ```
+///
+/// # Padding
+///
+/// Params are read zero-copy from instruction data, so the struct must have
+/// no implicit padding. The macro asserts at compile time that its size equals
+/// the sum of its field sizes; reorder fields or add explicit `_padding`
+/// arrays to fix a failing assertion.
+///
+/// Use `#[derive(Params)]` to generate the same impls without rewriting the
+/// struct.
 #[proc_macro_attribute]
 pub fn params(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as ItemStruct);
@@ -1137,6 +3066,8 @@ pub fn params(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let vis = &input.vis;
     let attrs = &input.attrs;
 
+    let impls = params_impls(name, &input.fields);
+
     // Handle unit structs (no fields)
     if matches!(&input.fields, syn::Fields::Unit) {
         let expanded = quote! {
@@ -1145,13 +3076,7 @@ pub fn params(_attr: TokenStream, item: TokenStream) -> TokenStream {
             #(#attrs)*
             #vis struct #name;
 
-            // Safety: Unit struct is always valid
-            unsafe impl ::bytemuck::Pod for #name {}
-            unsafe impl ::bytemuck::Zeroable for #name {}
-
-            impl ::solzempic::ParamsMeta for #name {
-                const FIELDS: &'static [::solzempic::ParamField] = &[];
-            }
+            #impls
         };
         return TokenStream::from(expanded);
     }
@@ -1173,28 +3098,207 @@ pub fn params(_attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     });
 
+    let expanded = quote! {
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        #(#attrs)*
+        #vis struct #name {
+            #(#field_defs),*
+        }
+
+        #impls
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Derive macro for instruction parameter structs.
+///
+/// Generates the same impls as `#[params]` - Pod/Zeroable, `ParamsMeta`,
+/// `TryFrom<&[u8]>`, and the padding assertion - without rewriting the
+/// struct, so it composes with your own attributes and derives. The struct
+/// must be `#[repr(C)]` and derive `Clone` and `Copy` itself.
+///
+/// # Example
+///
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Debug, PartialEq, Params)]
+/// pub struct SwapParams {
+///     pub amount_in: u64,
+///     pub min_amount_out: u64,
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Compile-time panics if:
+/// - The struct is not `#[repr(C)]`
+/// - Applied to an enum, union, or tuple struct
+#[proc_macro_derive(Params)]
+pub fn derive_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data_struct) if !matches!(data_struct.fields, Fields::Unnamed(_)) => &data_struct.fields,
+        _ => panic!("Params derive only supports structs with named fields or unit structs"),
+    };
+
+    // Pod is only sound for a fixed field order
+    let is_repr_c = input.attrs.iter().filter(|attr| attr.path().is_ident("repr")).any(|attr| {
+        attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+            .map(|metas| metas.iter().any(|meta| meta.path().is_ident("C")))
+            .unwrap_or(false)
+    });
+    if !is_repr_c {
+        panic!("`{}` must be #[repr(C)] to derive Params", name);
+    }
+
+    TokenStream::from(params_impls(name, fields))
+}
+
+/// Derive macro implementing `solzempic::ParamsDecode` with borsh.
+///
+/// For params with variable-length fields, which can't be `Copy` and so
+/// don't get the zero-copy `ParamsDecode` impl. The struct must also derive
+/// `BorshDeserialize`, and `solzempic` needs its `borsh` feature.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(BorshDeserialize, BorshParams)]
+/// pub struct SetMetadataParams {
+///     pub uri: String,
+/// }
+/// ```
+#[proc_macro_derive(BorshParams)]
+pub fn derive_borsh_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics ::solzempic::ParamsDecode for #name #ty_generics #where_clause {
+            #[inline]
+            fn decode(data: &[u8]) -> ::core::result::Result<Self, ::pinocchio::error::ProgramError> {
+                ::solzempic::parse_params_borsh(data)
+            }
+
+            #[inline]
+            fn decode_with_rest(data: &[u8]) -> ::core::result::Result<(Self, &[u8]), ::pinocchio::error::ProgramError> {
+                ::solzempic::parse_params_borsh_with_rest(data)
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Derive macro implementing `solzempic::Framework`.
+///
+/// Only the trait impl is generated - no `ID` constant and no type aliases -
+/// so several frameworks can live side by side in a workspace without the
+/// `Solzempic` name colliding.
+///
+/// # Example
+///
+/// ```ignore
+/// use solzempic::{Framework, AccountRefMut};
+///
+/// #[derive(Framework)]
+/// #[framework(id = crate::ID)]
+/// pub struct Exchange;
+///
+/// pub type ExchangeRefMut<'a, T> = AccountRefMut<'a, T, Exchange>;
+/// ```
+///
+/// # Panics
+///
+/// Compile-time panics if `#[framework(id = ...)]` is missing.
+#[proc_macro_derive(Framework, attributes(framework))]
+pub fn derive_framework(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let program_id = input.attrs.iter()
+        .filter(|attr| attr.path().is_ident("framework"))
+        .filter_map(|attr| attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::MetaNameValue, syn::Token![,]>::parse_terminated
+        ).ok())
+        .flatten()
+        .find(|nv| nv.path.is_ident("id"))
+        .map(|nv| nv.value)
+        .unwrap_or_else(|| panic!("Framework derive on `{}` requires #[framework(id = PROGRAM_ID)]", name));
+
+    let expanded = quote! {
+        impl #impl_generics ::solzempic::Framework for #name #ty_generics #where_clause {
+            const PROGRAM_ID: ::solana_address::Address = #program_id;
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Impls shared by `#[params]` and `#[derive(Params)]`: Pod/Zeroable behind a
+/// padding assertion, `ParamsMeta`, and `TryFrom<&[u8]>`.
+fn params_impls(name: &syn::Ident, fields: &Fields) -> proc_macro2::TokenStream {
+    // Zero-copy, length-checked parsing via `parse_params`
+    let try_from_impl = quote! {
+        impl ::core::convert::TryFrom<&[u8]> for #name {
+            type Error = ::pinocchio::error::ProgramError;
+
+            #[inline]
+            fn try_from(data: &[u8]) -> ::core::result::Result<Self, Self::Error> {
+                ::solzempic::parse_params::<Self>(data)
+            }
+        }
+    };
+
+    if matches!(fields, Fields::Unit) {
+        return quote! {
+            // Safety: Unit struct is always valid
+            unsafe impl ::bytemuck::Pod for #name {}
+            unsafe impl ::bytemuck::Zeroable for #name {}
+
+            impl ::solzempic::ParamsMeta for #name {
+                const FIELDS: &'static [::solzempic::ParamField] = &[];
+            }
+
+            #try_from_impl
+        };
+    }
+
     // Generate ParamField metadata
     let param_fields: Vec<_> = fields.iter().map(|f| {
         let field_name = f.ident.as_ref().expect("Named field required");
         let field_name_str = field_name.to_string();
         let type_str = type_to_string(&f.ty);
+        let field_docs = extract_docs(&f.attrs);
         quote! {
             ::solzempic::ParamField {
                 name: #field_name_str,
                 type_name: #type_str,
+                docs: &[#(#field_docs),*],
             }
         }
     }).collect();
 
-    let expanded = quote! {
-        #[repr(C)]
-        #[derive(Clone, Copy)]
-        #(#attrs)*
-        #vis struct #name {
-            #(#field_defs),*
-        }
+    // Implicit padding would make the `Pod` impl below unsound
+    let field_tys = fields.iter().map(|f| &f.ty);
+    let padding_msg = format!(
+        "params struct `{}` has implicit padding; add explicit `_padding` fields",
+        name
+    );
 
-        // Safety: Struct is #[repr(C)] with primitive fields
+    quote! {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#name>() == 0 #(+ ::core::mem::size_of::<#field_tys>())*,
+            #padding_msg
+        );
+
+        // Safety: Struct is #[repr(C)] and the assertion above rules out padding
         unsafe impl ::bytemuck::Pod for #name {}
         unsafe impl ::bytemuck::Zeroable for #name {}
 
@@ -1203,9 +3307,9 @@ pub fn params(_attr: TokenStream, item: TokenStream) -> TokenStream {
                 #(#param_fields),*
             ];
         }
-    };
 
-    TokenStream::from(expanded)
+        #try_from_impl
+    }
 }
 
 /// Convert a type to its string representation for IDL.
@@ -1232,4 +3336,152 @@ fn type_to_string(ty: &Type) -> String {
         }
         _ => quote!(#ty).to_string(),
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `check_phase_methods` on an impl block, as `#[instruction]` does.
+    fn check(input: ItemImpl, auto_build: bool) -> syn::Result<()> {
+        let methods: Vec<_> = input.items.iter().filter_map(|item| match item {
+            ImplItem::Fn(method) => Some(method),
+            _ => None,
+        }).collect();
+        let struct_name = syn::Ident::new("Deposit", proc_macro2::Span::call_site());
+        check_phase_methods(&input, &struct_name, &methods, auto_build)
+    }
+
+    #[test]
+    fn test_discriminator_fn_result_is_u8() {
+        let func: Expr = syn::parse_quote!(account_disc);
+        let name = syn::Ident::new("Position", proc_macro2::Span::call_site());
+        let disc = discriminator_from_fn(&func, &name);
+        let expected = quote! { { let discriminator: u8 = account_disc("Position"); discriminator } };
+        assert_eq!(quote!(#disc).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("validate", "validate"), 0);
+        assert_eq!(edit_distance("valdiate", "validate"), 2);
+        assert_eq!(edit_distance("buid", "build"), 1);
+        assert_eq!(edit_distance("exectue", "execute"), 2);
+        assert_eq!(edit_distance("", "build"), 5);
+        assert_eq!(edit_distance("build", ""), 5);
+        assert_eq!(edit_distance("helper", "execute"), 6);
+    }
+
+    #[test]
+    fn test_misspelled_phase_is_rejected() {
+        let input: ItemImpl = syn::parse_quote! {
+            impl<'a> Deposit<'a> {
+                fn build(accounts: &'a [AccountView], params: &P) -> Result<Self, ProgramError> { todo!() }
+                fn valdiate(&self, program_id: &Address, params: &P) -> ProgramResult { Ok(()) }
+            }
+        };
+        let err = check(input, false).unwrap_err();
+        assert_eq!(err.to_string(), "`valdiate` is not an instruction phase; did you mean `validate`?");
+    }
+
+    #[test]
+    fn test_helpers_near_present_phases_are_allowed() {
+        let input: ItemImpl = syn::parse_quote! {
+            impl<'a> Deposit<'a> {
+                fn build(accounts: &'a [AccountView], params: &P) -> Result<Self, ProgramError> { todo!() }
+                fn validate(&self, program_id: &Address, params: &P) -> ProgramResult { Ok(()) }
+                fn validator(&self) -> bool { true }
+                fn compute_fee(&self) -> u64 { 0 }
+            }
+        };
+        assert!(check(input, false).is_ok());
+    }
+
+    #[test]
+    fn test_helpers_near_missing_phases_are_allowed() {
+        // `validate`, `execute` and `precheck` are all defaulted here
+        let input: ItemImpl = syn::parse_quote! {
+            impl<'a> Deposit<'a> {
+                fn build(accounts: &'a [AccountView], params: &P) -> Result<Self, ProgramError> { todo!() }
+                fn recheck(&self) -> bool { true }
+                fn prechecks(params: &P) -> ProgramResult { Ok(()) }
+                fn invalidate(&mut self) {}
+                fn executed(&self) -> bool { false }
+                fn exec(&self, program_id: &Address, params: &P) -> ProgramResult { Ok(()) }
+            }
+        };
+        assert!(check(input, false).is_ok());
+    }
+
+    #[test]
+    fn test_misspelled_phase_needs_phase_signature() {
+        let input: ItemImpl = syn::parse_quote! {
+            impl<'a> Deposit<'a> {
+                fn build(accounts: &'a [AccountView], params: &P) -> Result<Self, ProgramError> { todo!() }
+                fn exectue(&mut self, program_id: &Address, params: &P) -> ProgramResult { Ok(()) }
+            }
+        };
+        let err = check(input, false).unwrap_err();
+        assert_eq!(err.to_string(), "`exectue` is not an instruction phase; did you mean `execute`?");
+
+        let input: ItemImpl = syn::parse_quote! {
+            impl<'a> Deposit<'a> {
+                fn biuld(accounts: &'a [AccountView], params: &P) -> Result<Self, ProgramError> { todo!() }
+            }
+        };
+        let err = check(input, false).unwrap_err();
+        assert_eq!(err.to_string(), "`biuld` is not an instruction phase; did you mean `build`?");
+
+        let input: ItemImpl = syn::parse_quote! {
+            impl<'a> Deposit<'a> {
+                fn build(accounts: &'a [AccountView], params: &P) -> Result<Self, ProgramError> { todo!() }
+                fn precheks(params: &P) -> ProgramResult { Ok(()) }
+            }
+        };
+        let err = check(input, false).unwrap_err();
+        assert_eq!(err.to_string(), "`precheks` is not an instruction phase; did you mean `precheck`?");
+    }
+
+    #[test]
+    fn test_missing_build_is_rejected_unless_auto_build() {
+        let input: ItemImpl = syn::parse_quote! {
+            impl<'a> Deposit<'a> {
+                fn execute(&mut self, program_id: &Address, params: &P) -> ProgramResult { Ok(()) }
+            }
+        };
+        let err = check(input.clone(), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "instruction impl for `Deposit` must define `build` or `build_with_rest`, or pass `auto_build`"
+        );
+        assert!(check(input, true).is_ok());
+    }
+
+    #[test]
+    fn test_helper_items_stay_inherent() {
+        let input: ItemImpl = syn::parse_quote! {
+            impl<'a> Deposit<'a> {
+                const FEE_BPS: u64 = 30;
+                fn build(accounts: &'a [AccountView], params: &P) -> Result<Self, ProgramError> { todo!() }
+                fn fee(amount: u64) -> u64 { amount * Self::FEE_BPS / 10_000 }
+                fn execute(&mut self, program_id: &Address, params: &P) -> ProgramResult { Ok(()) }
+            }
+        };
+        let names = |items: Vec<&ImplItem>| -> Vec<String> {
+            items.iter().map(|item| match item {
+                ImplItem::Const(c) => c.ident.to_string(),
+                ImplItem::Fn(method) => method.sig.ident.to_string(),
+                _ => unreachable!(),
+            }).collect()
+        };
+        assert_eq!(names(inherent_items(&input, true).unwrap()), ["FEE_BPS", "fee"]);
+        assert_eq!(names(inherent_items(&input, false).unwrap()), ["FEE_BPS", "build", "fee", "execute"]);
+
+        let input: ItemImpl = syn::parse_quote! {
+            impl<'a> Deposit<'a> {
+                type Output = u64;
+            }
+        };
+        let Err(err) = inherent_items(&input, true) else { panic!("associated type was accepted") };
+        assert_eq!(err.to_string(), "only `type Ctx` is allowed in an `#[instruction]` impl block");
+    }
+}
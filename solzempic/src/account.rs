@@ -3,8 +3,13 @@
 //! This module provides low-level utilities for working with Solana accounts:
 //!
 //! - [`rent_exempt_minimum`]: Calculate rent-exempt balance for account sizes
+//! - [`try_rent_exempt_minimum`]: Same, rejecting sizes above [`MAX_ACCOUNT_SIZE`]
 //! - [`transfer_lamports`]: Transfer SOL between accounts via System program
+//! - [`transfer_lamports_signed`]: Transfer SOL out of a system-owned PDA
+//! - [`transfer_lamports_owned`]: Move SOL out of a program-owned account
 //! - [`create_pda_account`]: Create PDA accounts with proper signing
+//! - [`invoke_as_pda`]: Make any CPI signed by a PDA of the calling program
+//! - [`next_account`]: Index into the accounts slice without panicking
 //!
 //! # Performance
 //!
@@ -33,6 +38,7 @@ use pinocchio::{
     cpi::{invoke_signed, Seed, Signer},
     error::ProgramError,
     instruction::{InstructionAccount, InstructionView},
+    ProgramResult,
 };
 use solana_address::Address;
 
@@ -47,6 +53,12 @@ use crate::SYSTEM_PROGRAM_ID;
 /// accounts (see [`ShardRefContext`](crate::ShardRefContext)).
 pub const MAX_ACCOUNT_SIZE: usize = 10 * 1024 * 1024;
 
+/// Maximum number of seeds (including the bump) in a PDA derivation.
+///
+/// This is the Solana runtime limit for `create_program_address` and
+/// `invoke_signed` signer seeds.
+pub const MAX_SEEDS: usize = 16;
+
 /// Approximate lamports required per byte for rent exemption.
 ///
 /// This value is based on the current Solana rent rate (~0.00000696 SOL per byte).
@@ -89,11 +101,37 @@ pub const LAMPORTS_PER_BYTE: u64 = 6960;
 /// This approximation is within 1% of the exact value for typical account
 /// sizes. For critical applications where exact lamport counts matter,
 /// read the Rent sysvar at runtime.
+///
+/// # Overflow
+///
+/// The result saturates at `u64::MAX` instead of wrapping, so a bogus
+/// `data_len` asks for too many lamports rather than too few. Use
+/// [`try_rent_exempt_minimum`] to reject sizes above [`MAX_ACCOUNT_SIZE`].
 #[inline]
 pub const fn rent_exempt_minimum(data_len: usize) -> u64 {
     // Base rent (128 bytes for account metadata) + data
-    let total_bytes = 128 + data_len;
-    (total_bytes as u64) * LAMPORTS_PER_BYTE
+    let total_bytes = (data_len as u64).saturating_add(128);
+    total_bytes.saturating_mul(LAMPORTS_PER_BYTE)
+}
+
+/// Checked [`rent_exempt_minimum`] for sizes that come from user input.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::InvalidArgument`] if `data_len` exceeds
+/// [`MAX_ACCOUNT_SIZE`], the same limit [`create_pda_account`] enforces.
+///
+/// # Example
+///
+/// ```ignore
+/// let rent = try_rent_exempt_minimum(params.space as usize)?;
+/// ```
+#[inline]
+pub const fn try_rent_exempt_minimum(data_len: usize) -> Result<u64, ProgramError> {
+    if data_len > MAX_ACCOUNT_SIZE {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(rent_exempt_minimum(data_len))
 }
 
 /// Transfer lamports (SOL) between accounts using the System program.
@@ -176,6 +214,175 @@ pub fn transfer_lamports<'a>(
     pinocchio::cpi::invoke(&instruction, account_infos)
 }
 
+/// Transfer SOL out of a system-owned PDA via the System program.
+///
+/// Same as [`transfer_lamports`], but `from` is a PDA of the calling program
+/// and signs through `seeds` instead of being a transaction signer. The
+/// System program account must still be present in the transaction.
+///
+/// # Arguments
+///
+/// * `from` - The system-owned PDA to debit (must be writable)
+/// * `to` - The account to credit (must be writable)
+/// * `amount` - Amount in lamports (zero is a no-op)
+/// * `seeds` - The PDA seeds of `from` **including the bump seed** (max [`MAX_SEEDS`])
+///
+/// # Errors
+///
+/// * [`ProgramError::InvalidAccountData`] - `from` or `to` is not writable
+/// * [`ProgramError::InvalidSeeds`] - More than [`MAX_SEEDS`] seeds
+/// * System program errors - Insufficient funds, seeds don't match `from`, etc.
+#[inline]
+pub fn transfer_lamports_signed(
+    from: &AccountView,
+    to: &AccountView,
+    amount: u64,
+    seeds: &[&[u8]],
+) -> Result<(), ProgramError> {
+    if amount == 0 {
+        return Ok(());
+    }
+    if !from.is_writable() || !to.is_writable() {
+        return Err(crate::errors::account_not_writable());
+    }
+
+    let mut instruction_data = [0u8; 12];
+    instruction_data[0..4].copy_from_slice(&2u32.to_le_bytes()); // Transfer discriminator
+    instruction_data[4..12].copy_from_slice(&amount.to_le_bytes());
+
+    let account_metas = [
+        InstructionAccount {
+            address: from.address(),
+            is_writable: true,
+            is_signer: true,
+        },
+        InstructionAccount {
+            address: to.address(),
+            is_writable: true,
+            is_signer: false,
+        },
+    ];
+
+    let instruction = InstructionView {
+        program_id: &SYSTEM_PROGRAM_ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_as_pda(&instruction, &[from, to], seeds)
+}
+
+/// Invoke `instruction`, signing as the PDA derived from `seeds`.
+///
+/// Converts the seeds into the `Seed`/`Signer` structures `invoke_signed`
+/// expects, on the stack. This is the primitive behind every signed CPI in
+/// this crate; use it for program calls that have no dedicated helper.
+///
+/// # Arguments
+///
+/// * `instruction` - The instruction to invoke
+/// * `accounts` - The accounts referenced by `instruction`
+/// * `seeds` - The PDA seeds **including the bump seed** (max [`MAX_SEEDS`]).
+///   Empty seeds invoke without a PDA signer.
+///
+/// # Errors
+///
+/// * [`ProgramError::InvalidSeeds`] - More than [`MAX_SEEDS`] seeds
+/// * Any error returned by the invoked program
+///
+/// # Example
+///
+/// ```ignore
+/// let bump = [market.bump];
+/// let seeds: &[&[u8]] = &[b"market", base_mint.as_ref(), &bump];
+/// invoke_as_pda(&instruction, &[vault, destination, market.info()], seeds)?;
+/// ```
+#[inline]
+pub fn invoke_as_pda<const ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    accounts: &[&AccountView; ACCOUNTS],
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    if seeds.len() > MAX_SEEDS {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if seeds.is_empty() {
+        return invoke_signed(instruction, accounts, &[]);
+    }
+    let seed_refs: [Seed; MAX_SEEDS] =
+        core::array::from_fn(|i| Seed::from(seeds.get(i).copied().unwrap_or(&[])));
+    let signer = Signer::from(&seed_refs[..seeds.len()]);
+    invoke_signed(instruction, accounts, &[signer])
+}
+
+/// Move SOL out of an account owned by the calling program.
+///
+/// The runtime lets a program debit accounts it owns directly, so no CPI is
+/// needed. Ownership is enforced by the runtime when the instruction ends;
+/// for typed program accounts prefer
+/// [`AccountRefMut::sub_lamports`](crate::AccountRefMut::sub_lamports).
+/// Passing the same account as `from` and `to` leaves its balance unchanged.
+///
+/// # Errors
+///
+/// * [`ProgramError::InvalidAccountData`] - `from` or `to` is not writable
+/// * [`ProgramError::InsufficientFunds`] - `amount` exceeds `from`'s balance
+/// * [`ProgramError::ArithmeticOverflow`] - `to`'s balance would overflow
+///   (`from` is left untouched)
+#[inline]
+pub fn transfer_lamports_owned(
+    from: &AccountView,
+    to: &AccountView,
+    amount: u64,
+) -> Result<(), ProgramError> {
+    if amount == 0 {
+        return Ok(());
+    }
+    if !from.is_writable() || !to.is_writable() {
+        return Err(crate::errors::account_not_writable());
+    }
+    // Debit before reading `to` so a transfer to the same account nets to zero
+    // instead of crediting `amount` on top of the stale balance.
+    let from_lamports = from.lamports()
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    from.set_lamports(from_lamports);
+    match to.lamports().checked_add(amount) {
+        Some(to_lamports) => {
+            to.set_lamports(to_lamports);
+            Ok(())
+        }
+        None => {
+            from.set_lamports(from_lamports + amount);
+            Err(ProgramError::ArithmeticOverflow)
+        }
+    }
+}
+
+/// Get the account at index `i`, or an error if too few accounts were passed.
+///
+/// Use this in `build` instead of `accounts[i]`, which panics and aborts the
+/// program with no useful error when a client passes too few accounts.
+///
+/// # Errors
+///
+/// * [`ProgramError::NotEnoughAccountKeys`] - `i` is out of bounds
+///
+/// # Example
+///
+/// ```ignore
+/// fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+///     Ok(Self {
+///         authority: Signer::wrap(next_account(accounts, 0)?)?,
+///         vault: AccountRefMut::load(next_account(accounts, 1)?)?,
+///     })
+/// }
+/// ```
+#[inline]
+pub fn next_account(accounts: &[AccountView], i: usize) -> Result<&AccountView, ProgramError> {
+    accounts.get(i).ok_or_else(crate::errors::not_enough_account_keys)
+}
+
 /// Create a Program Derived Address (PDA) account with the specified size.
 ///
 /// This function performs a CPI to the System program's CreateAccount instruction,
@@ -188,11 +395,13 @@ pub fn transfer_lamports<'a>(
 /// * `new_account` - The PDA account to create (address must match seeds + program_id)
 /// * `program_id` - The program that will own the created account
 /// * `space` - The size of the account data in bytes
-/// * `seeds` - The PDA seeds **including the bump seed** (max 6 seeds)
+/// * `seeds` - The PDA seeds **including the bump seed** (max [`MAX_SEEDS`])
 ///
 /// # Returns
 ///
 /// * `Ok(())` - Account created successfully
+/// * `Err(ProgramError::InvalidArgument)` - `space` exceeds [`MAX_ACCOUNT_SIZE`]
+/// * `Err(ProgramError::InvalidSeeds)` - More than [`MAX_SEEDS`] seeds
 /// * `Err(ProgramError)` - Creation failed (insufficient funds, wrong address, etc.)
 ///
 /// # Example
@@ -213,15 +422,16 @@ pub fn transfer_lamports<'a>(
 ///
 /// # Seed Limits
 ///
-/// This function supports up to **6 seeds**. This accommodates most PDA
-/// derivation patterns:
+/// This function supports up to [`MAX_SEEDS`] (16) seeds including the bump,
+/// the same limit the runtime enforces. Passing more returns
+/// [`ProgramError::InvalidSeeds`] instead of signing with a truncated list:
 ///
 /// ```ignore
 /// // Common patterns (3-4 seeds):
 /// &[b"market", token_a.as_ref(), token_b.as_ref(), &[bump]]
 ///
-/// // Complex patterns (5-6 seeds):
-/// &[b"order", market.as_ref(), user.as_ref(), &order_id.to_le_bytes(), &[bump]]
+/// // Complex patterns (7+ seeds):
+/// &[b"order", market.as_ref(), user.as_ref(), &[side], &price.to_le_bytes(), &nonce.to_le_bytes(), &[bump]]
 /// ```
 ///
 /// # Important
@@ -246,6 +456,11 @@ pub fn create_pda_account<'a>(
     space: usize,
     seeds: &[&[u8]],
 ) -> Result<(), ProgramError> {
+    // Reject oversized requests up front rather than surfacing an opaque System error
+    if space > MAX_ACCOUNT_SIZE {
+        return Err(crate::errors::account_too_large());
+    }
+
     let lamports = rent_exempt_minimum(space);
 
     // CreateAccount instruction
@@ -276,19 +491,141 @@ pub fn create_pda_account<'a>(
     };
 
     // Only pass the 2 accounts referenced by the instruction (matches pinocchio-system)
-    let account_infos = &[payer, new_account];
-
-    // Convert &[&[u8]] to [Seed] for invoke_signed
-    // Support up to 6 seeds (e.g., ["market", token_a, token_b, seed, bump, extra])
-    let seed_refs: [Seed; 6] = [
-        Seed::from(seeds.first().copied().unwrap_or(&[])),
-        Seed::from(seeds.get(1).copied().unwrap_or(&[])),
-        Seed::from(seeds.get(2).copied().unwrap_or(&[])),
-        Seed::from(seeds.get(3).copied().unwrap_or(&[])),
-        Seed::from(seeds.get(4).copied().unwrap_or(&[])),
-        Seed::from(seeds.get(5).copied().unwrap_or(&[])),
-    ];
-    let seed_count = seeds.len().min(6);
-    let signer = Signer::from(&seed_refs[..seed_count]);
-    invoke_signed(&instruction, account_infos, &[signer])
+    invoke_as_pda(&instruction, &[payer, new_account], seeds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::AccountBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_transfer_lamports_owned() {
+        let from = AccountBuilder::new().writable(true).lamports(1_000).build();
+        let to = AccountBuilder::new().writable(true).lamports(10).build();
+
+        transfer_lamports_owned(from.info(), to.info(), 400).unwrap();
+        assert_eq!(from.info().lamports(), 600);
+        assert_eq!(to.info().lamports(), 410);
+
+        assert_eq!(
+            transfer_lamports_owned(from.info(), to.info(), 601),
+            Err(ProgramError::InsufficientFunds)
+        );
+
+        let read_only = AccountBuilder::new().lamports(10).build();
+        assert_eq!(
+            transfer_lamports_owned(from.info(), read_only.info(), 1),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(transfer_lamports_owned(from.info(), read_only.info(), 0), Ok(()));
+    }
+
+    #[test]
+    fn test_transfer_lamports_owned_same_account() {
+        let account = AccountBuilder::new().writable(true).lamports(1_000).build();
+
+        transfer_lamports_owned(account.info(), account.info(), 400).unwrap();
+        assert_eq!(account.info().lamports(), 1_000);
+
+        assert_eq!(
+            transfer_lamports_owned(account.info(), account.info(), 1_001),
+            Err(ProgramError::InsufficientFunds)
+        );
+        assert_eq!(account.info().lamports(), 1_000);
+    }
+
+    #[test]
+    fn test_transfer_lamports_owned_overflow_restores_from() {
+        let from = AccountBuilder::new().writable(true).lamports(1_000).build();
+        let to = AccountBuilder::new().writable(true).lamports(u64::MAX).build();
+
+        assert_eq!(
+            transfer_lamports_owned(from.info(), to.info(), 1),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+        assert_eq!(from.info().lamports(), 1_000);
+        assert_eq!(to.info().lamports(), u64::MAX);
+    }
+
+    #[test]
+    fn test_transfer_lamports_signed_rejects_before_cpi() {
+        let from = AccountBuilder::new().writable(true).lamports(1_000).build();
+        let to = AccountBuilder::new().writable(true).build();
+        let read_only = AccountBuilder::new().build();
+
+        assert_eq!(transfer_lamports_signed(from.info(), to.info(), 0, &[b"vault"]), Ok(()));
+        assert_eq!(
+            transfer_lamports_signed(from.info(), read_only.info(), 1, &[b"vault"]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            transfer_lamports_signed(read_only.info(), to.info(), 1, &[b"vault"]),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let seeds: [&[u8]; MAX_SEEDS + 1] = [b"s"; MAX_SEEDS + 1];
+        assert_eq!(
+            transfer_lamports_signed(from.info(), to.info(), 1, &seeds),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(from.info().lamports(), 1_000);
+    }
+
+    #[test]
+    fn test_rent_exempt_minimum_bounds() {
+        assert_eq!(rent_exempt_minimum(0), 128 * LAMPORTS_PER_BYTE);
+        assert_eq!(rent_exempt_minimum(usize::MAX), u64::MAX);
+
+        assert_eq!(try_rent_exempt_minimum(256), Ok(rent_exempt_minimum(256)));
+        assert_eq!(try_rent_exempt_minimum(MAX_ACCOUNT_SIZE), Ok(rent_exempt_minimum(MAX_ACCOUNT_SIZE)));
+        assert_eq!(try_rent_exempt_minimum(MAX_ACCOUNT_SIZE + 1), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_next_account() {
+        let account = AccountBuilder::new().lamports(7).build();
+        let accounts = [*account.info()];
+
+        assert_eq!(next_account(&accounts, 0).unwrap().lamports(), 7);
+        assert_eq!(next_account(&accounts, 1).err(), Some(ProgramError::NotEnoughAccountKeys));
+        assert_eq!(next_account(&[], 0).err(), Some(ProgramError::NotEnoughAccountKeys));
+    }
+
+    #[test]
+    fn test_create_pda_account_rejects_oversized_space() {
+        let payer = AccountBuilder::new().signer(true).writable(true).build();
+        let new_account = AccountBuilder::new()
+            .address(Address::new_from_array([1u8; 32]))
+            .writable(true)
+            .build();
+        let program_id = Address::new_from_array([2u8; 32]);
+
+        let result = create_pda_account(
+            payer.info(),
+            new_account.info(),
+            &program_id,
+            MAX_ACCOUNT_SIZE + 1,
+            &[b"seed", &[255]],
+        );
+        assert_eq!(result, Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_create_pda_account_rejects_too_many_seeds() {
+        let payer = AccountBuilder::new().signer(true).writable(true).build();
+        let new_account = AccountBuilder::new()
+            .address(Address::new_from_array([1u8; 32]))
+            .writable(true)
+            .build();
+        let program_id = Address::new_from_array([2u8; 32]);
+        let seeds: [&[u8]; MAX_SEEDS + 1] = [b"seed"; MAX_SEEDS + 1];
+
+        let result = create_pda_account(payer.info(), new_account.info(), &program_id, 64, &seeds);
+        assert_eq!(result, Err(ProgramError::InvalidSeeds));
+
+        let instruction = InstructionView { program_id: &program_id, accounts: &[], data: &[] };
+        assert_eq!(invoke_as_pda(&instruction, &[payer.info()], &seeds), Err(ProgramError::InvalidSeeds));
+    }
 }
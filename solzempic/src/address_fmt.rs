@@ -0,0 +1,108 @@
+//! Base58 address formatting without allocation.
+//!
+//! `Address` has no `Display` impl in `no_std` builds, so failed checks can
+//! only log an error code. [`format_address`] renders an address into a
+//! fixed-size stack buffer that can be logged as a `&str` or with `{}`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use solzempic::format_address;
+//!
+//! if !address_eq(account.owner(), &crate::ID) {
+//!     msg!("unexpected owner for {}", format_address(account.address()));
+//!     return Err(ProgramError::IllegalOwner);
+//! }
+//! ```
+
+use core::fmt;
+
+use solana_address::Address;
+
+/// Maximum length of a base58-encoded 32-byte address.
+pub const MAX_BASE58_LEN: usize = 44;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// A base58-encoded address held on the stack.
+///
+/// Returned by [`format_address`]; implements `Display` and `Debug`.
+#[derive(Clone, Copy)]
+pub struct AddressStr {
+    buf: [u8; MAX_BASE58_LEN],
+    len: u8,
+}
+
+impl AddressStr {
+    /// Get the encoded address.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // Only alphabet bytes (ASCII) are written
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+    }
+}
+
+impl fmt::Display for AddressStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for AddressStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Encode `address` as base58, the form explorers and wallets display.
+///
+/// # Performance
+///
+/// The conversion is quadratic in the address length (roughly 1,000
+/// multiply/divide steps), so call it on the error path only.
+pub fn format_address(address: &Address) -> AddressStr {
+    let bytes: &[u8] = address.as_ref();
+
+    // Base58 digits, least significant first
+    let mut digits = [0u8; MAX_BASE58_LEN];
+    let mut digits_len = 0;
+    for &byte in bytes {
+        let mut carry = byte as u32;
+        for digit in &mut digits[..digits_len] {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits[digits_len] = (carry % 58) as u8;
+            digits_len += 1;
+            carry /= 58;
+        }
+    }
+
+    // Each leading zero byte encodes as a leading '1'
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    let mut buf = [0u8; MAX_BASE58_LEN];
+    buf[..zeros].fill(ALPHABET[0]);
+    for (out, &digit) in buf[zeros..].iter_mut().zip(digits[..digits_len].iter().rev()) {
+        *out = ALPHABET[digit as usize];
+    }
+
+    AddressStr { buf, len: (zeros + digits_len) as u8 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID};
+
+    #[test]
+    fn test_format_address() {
+        assert_eq!(format_address(&SYSTEM_PROGRAM_ID).as_str(), "11111111111111111111111111111111");
+        assert_eq!(
+            format_address(&TOKEN_PROGRAM_ID).as_str(),
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        );
+        assert_eq!(format_address(&Address::new_from_array([0xff; 32])).as_str().len(), MAX_BASE58_LEN);
+    }
+}
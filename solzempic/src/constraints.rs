@@ -0,0 +1,52 @@
+//! Account constraints declared on `#[instruction]` structs.
+//!
+//! `#[has_one(...)]` and `#[address(...)]` on the fields of an `#[instruction]`
+//! struct generate `check_has_one()` and `check_addresses()`, plus an
+//! [`InstructionConstraints`] impl that runs both.
+//! The impl-block form of `#[instruction]` wires that impl into
+//! [`Instruction::check_constraints`](crate::Instruction::check_constraints),
+//! which `process` calls after `build` and before `validate`, so declared
+//! constraints are enforced without calling them by hand.
+
+use pinocchio::ProgramResult;
+
+/// Checks declared by attributes on an `#[instruction]` struct's fields.
+///
+/// Generated by the struct form of `#[instruction]` when any field carries
+/// `#[has_one]` or `#[address]`.
+pub trait InstructionConstraints {
+    /// Run every declared check, returning the first failure.
+    fn check(&self) -> ProgramResult;
+}
+
+/// Runs [`InstructionConstraints::check`] if the instruction implements it.
+///
+/// Used by the `#[instruction]` impl macro, which can't tell whether the
+/// struct form declared any constraints: method resolution on
+/// `(&ConstraintProbe(ix)).check()` picks [`ProbeConstraints`] when the type
+/// implements [`InstructionConstraints`] and falls back to
+/// [`ProbeNoConstraints`] otherwise.
+#[doc(hidden)]
+pub struct ConstraintProbe<'r, T>(pub &'r T);
+
+#[doc(hidden)]
+pub trait ProbeConstraints {
+    fn check(&self) -> ProgramResult;
+}
+
+impl<T: InstructionConstraints> ProbeConstraints for ConstraintProbe<'_, T> {
+    #[inline(always)]
+    fn check(&self) -> ProgramResult {
+        self.0.check()
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeNoConstraints {
+    #[inline(always)]
+    fn check(&self) -> ProgramResult {
+        Ok(())
+    }
+}
+
+impl<T> ProbeNoConstraints for &ConstraintProbe<'_, T> {}
@@ -17,11 +17,19 @@
 //!     )
 //! }
 //! ```
+//!
+//! To print the IDL from a binary target without any boilerplate, use
+//! [`idl_main!`](crate::idl_main):
+//!
+//! ```ignore
+//! // src/bin/idl.rs
+//! solzempic::idl_main!("YourProgram11111111111111111111111111111111", my_program::IDL_INSTRUCTIONS);
+//! ```
 
 use alloc::string::{String, ToString};
 use alloc::format;
 use alloc::vec::Vec;
-use crate::{InstructionMeta, AccountTypeMeta};
+use crate::{InstructionMeta, AccountTypeMeta, ErrorMeta, SeedMeta};
 
 /// Configuration for IDL generation
 pub struct IdlConfig<'a> {
@@ -57,7 +65,8 @@ pub fn to_json(
     for (i, instr) in instructions.iter().enumerate() {
         json.push_str("    {\n");
         json.push_str(&format!("      \"name\": \"{}\",\n", to_camel_case(instr.name)));
-        json.push_str(&format!("      \"discriminator\": [{}],\n", instr.discriminator));
+        push_docs(&mut json, "      ", instr.docs);
+        json.push_str(&format!("      \"discriminator\": [{}],\n", join_bytes(instr.discriminator)));
 
         // Accounts
         json.push_str("      \"accounts\": [\n");
@@ -66,7 +75,8 @@ pub fn to_json(
             json.push_str(&format!("          \"name\": \"{}\",\n", to_camel_case(acc.name)));
             json.push_str(&format!("          \"isMut\": {},\n", acc.is_writable));
             json.push_str(&format!("          \"isSigner\": {},\n", acc.is_signer));
-            json.push_str("          \"isOptional\": false\n");
+            push_pda(&mut json, "          ", acc.seeds);
+            json.push_str(&format!("          \"isOptional\": {}\n", acc.is_optional));
             json.push_str("        }");
             if j < instr.accounts.len() - 1 {
                 json.push(',');
@@ -80,6 +90,7 @@ pub fn to_json(
         for (j, param) in instr.params.iter().enumerate() {
             json.push_str("        {\n");
             json.push_str(&format!("          \"name\": \"{}\",\n", to_camel_case(param.name)));
+            push_docs(&mut json, "          ", param.docs);
             json.push_str(&format!("          \"type\": {}\n", rust_type_to_idl_json(param.type_name)));
             json.push_str("        }");
             if j < instr.params.len() - 1 {
@@ -106,11 +117,12 @@ pub fn to_json(
     json
 }
 
-/// Generate JSON IDL with auto-collected account types.
+/// Generate JSON IDL with auto-collected account types and errors.
 ///
 /// This function uses the `inventory` crate to automatically collect all account
-/// types registered with `#[account(discriminator = ...)]` and generates the
-/// complete IDL including the "accounts" and "types" sections.
+/// types registered with `#[account(discriminator = ...)]` and all error enums
+/// defined with `define_errors!`, and generates the complete IDL including the
+/// "accounts", "types", and "errors" sections.
 ///
 /// # Example
 ///
@@ -138,7 +150,71 @@ pub fn to_json_with_accounts(
         .copied()
         .collect();
 
-    to_json_full(address, name, version, instructions, &accounts)
+    let mut errors: Vec<ErrorMeta> = crate::inventory::iter::<crate::ErrorTable>()
+        .flat_map(|table| table.0.iter().copied())
+        .collect();
+    errors.sort_by_key(|error| error.code);
+
+    to_json_full_with_errors(address, name, version, instructions, &accounts, &errors)
+}
+
+/// Generate a `main` that prints the program IDL as JSON to stdout.
+///
+/// Intended for a small binary target gated on a dedicated feature, so the
+/// IDL can be produced with `cargo run --features idl-bin` and no hand-written
+/// `main`. The macro expands to:
+///
+/// - `pub fn idl_json() -> String` - the IDL rendered via [`to_json_with_accounts`]
+/// - `fn main()` - prints `idl_json()` to stdout
+///
+/// The program name and version default to `CARGO_PKG_NAME` and
+/// `CARGO_PKG_VERSION` of the crate invoking the macro.
+///
+/// # Example
+///
+/// ```toml
+/// [features]
+/// idl = ["solzempic/idl"]
+/// idl-bin = ["idl"]
+///
+/// [[bin]]
+/// name = "idl"
+/// path = "src/bin/idl.rs"
+/// required-features = ["idl-bin"]
+/// ```
+///
+/// ```ignore
+/// // src/bin/idl.rs
+/// solzempic::idl_main!("YourProgram11111111111111111111111111111111", my_program::IDL_INSTRUCTIONS);
+///
+/// // Or with an explicit name and version:
+/// solzempic::idl_main!(
+///     "YourProgram11111111111111111111111111111111",
+///     "my_program",
+///     "0.1.0",
+///     my_program::IDL_INSTRUCTIONS,
+/// );
+/// ```
+#[macro_export]
+macro_rules! idl_main {
+    ($address:expr, $instructions:expr $(,)?) => {
+        $crate::idl_main!(
+            $address,
+            ::core::env!("CARGO_PKG_NAME"),
+            ::core::env!("CARGO_PKG_VERSION"),
+            $instructions,
+        );
+    };
+    ($address:expr, $name:expr, $version:expr, $instructions:expr $(,)?) => {
+        /// Render the program IDL as a JSON string.
+        pub fn idl_json() -> ::std::string::String {
+            $crate::idl::to_json_with_accounts($address, $name, $version, $instructions)
+        }
+
+        fn main() {
+            ::std::print!("{}", idl_json());
+        }
+    };
 }
 
 /// Generate JSON IDL with explicit account types.
@@ -151,6 +227,33 @@ pub fn to_json_full(
     version: &str,
     instructions: &[InstructionMeta],
     accounts: &[&AccountTypeMeta],
+) -> String {
+    to_json_full_with_errors(address, name, version, instructions, accounts, &[])
+}
+
+/// Generate JSON IDL with explicit account types and errors.
+///
+/// Like [`to_json_full`], plus an "errors" section so clients can decode
+/// `Custom(code)` into a name and message. Pass the `ERRORS` table of each
+/// `define_errors!` enum:
+///
+/// ```ignore
+/// let json = solzempic::idl::to_json_full_with_errors(
+///     "YourProgram11111111111111111111111111111111",
+///     "your_program",
+///     "0.1.0",
+///     IDL_INSTRUCTIONS,
+///     &[&Market::META],
+///     MarketError::ERRORS,
+/// );
+/// ```
+pub fn to_json_full_with_errors(
+    address: &str,
+    name: &str,
+    version: &str,
+    instructions: &[InstructionMeta],
+    accounts: &[&AccountTypeMeta],
+    errors: &[ErrorMeta],
 ) -> String {
     let mut json = String::with_capacity(128 * 1024);
 
@@ -167,7 +270,8 @@ pub fn to_json_full(
     for (i, instr) in instructions.iter().enumerate() {
         json.push_str("    {\n");
         json.push_str(&format!("      \"name\": \"{}\",\n", to_camel_case(instr.name)));
-        json.push_str(&format!("      \"discriminator\": [{}],\n", instr.discriminator));
+        push_docs(&mut json, "      ", instr.docs);
+        json.push_str(&format!("      \"discriminator\": [{}],\n", join_bytes(instr.discriminator)));
 
         // Accounts
         json.push_str("      \"accounts\": [\n");
@@ -176,7 +280,8 @@ pub fn to_json_full(
             json.push_str(&format!("          \"name\": \"{}\",\n", to_camel_case(acc.name)));
             json.push_str(&format!("          \"isMut\": {},\n", acc.is_writable));
             json.push_str(&format!("          \"isSigner\": {},\n", acc.is_signer));
-            json.push_str("          \"isOptional\": false\n");
+            push_pda(&mut json, "          ", acc.seeds);
+            json.push_str(&format!("          \"isOptional\": {}\n", acc.is_optional));
             json.push_str("        }");
             if j < instr.accounts.len() - 1 {
                 json.push(',');
@@ -190,6 +295,7 @@ pub fn to_json_full(
         for (j, param) in instr.params.iter().enumerate() {
             json.push_str("        {\n");
             json.push_str(&format!("          \"name\": \"{}\",\n", to_camel_case(param.name)));
+            push_docs(&mut json, "          ", param.docs);
             json.push_str(&format!("          \"type\": {}\n", rust_type_to_idl_json(param.type_name)));
             json.push_str("        }");
             if j < instr.params.len() - 1 {
@@ -213,7 +319,8 @@ pub fn to_json_full(
         json.push_str("    {\n");
         json.push_str(&format!("      \"name\": \"{}\",\n", acc.name));
         // 8-byte discriminator array with value in first byte
-        json.push_str(&format!("      \"discriminator\": [{}, 0, 0, 0, 0, 0, 0, 0]\n", acc.discriminator));
+        json.push_str(&format!("      \"discriminator\": [{}, 0, 0, 0, 0, 0, 0, 0],\n", acc.discriminator));
+        json.push_str(&format!("      \"size\": {}\n", acc.size));
         json.push_str("    }");
         if i < accounts.len() - 1 {
             json.push(',');
@@ -233,6 +340,7 @@ pub fn to_json_full(
         for (j, field) in acc.fields.iter().enumerate() {
             json.push_str("          {\n");
             json.push_str(&format!("            \"name\": \"{}\",\n", to_camel_case(field.name)));
+            push_docs(&mut json, "            ", field.docs);
             json.push_str(&format!("            \"type\": {}\n", rust_type_to_idl_json(field.type_name)));
             json.push_str("          }");
             if j < acc.fields.len() - 1 {
@@ -250,12 +358,138 @@ pub fn to_json_full(
     }
     json.push_str("  ],\n");
 
-    json.push_str("  \"errors\": []\n");
+    // Errors section (custom error codes)
+    json.push_str("  \"errors\": [\n");
+    for (i, error) in errors.iter().enumerate() {
+        json.push_str("    {\n");
+        json.push_str(&format!("      \"code\": {},\n", error.code));
+        if error.msg.is_empty() {
+            json.push_str(&format!("      \"name\": \"{}\"\n", error.name));
+        } else {
+            json.push_str(&format!("      \"name\": \"{}\",\n", error.name));
+            json.push_str(&format!("      \"msg\": \"{}\"\n", escape_json(error.msg)));
+        }
+        json.push_str("    }");
+        if i < errors.len() - 1 {
+            json.push(',');
+        }
+        json.push('\n');
+    }
+    json.push_str("  ]\n");
     json.push_str("}\n");
 
     json
 }
 
+/// Render the instructions as a `#[derive(ShankInstruction)]` enum source.
+///
+/// Shank numbers variants by position and can't read solzempic's expression
+/// discriminants, so this writes out the equivalent enum for Shank's IDL
+/// generator: one variant per instruction, ordered by discriminator, with
+/// its `#[account(...)]` attributes and params as named fields. Gaps in the
+/// discriminators are filled with `UnusedN` variants so the positions line
+/// up. Shank only supports one-byte discriminators; instructions with wider
+/// ones are left out, with a comment in their place.
+///
+/// # Example
+///
+/// ```ignore
+/// // In a build script or idl binary:
+/// let source = solzempic::idl::to_shank_enum("MyInstruction", IDL_INSTRUCTIONS);
+/// std::fs::write("shank/instruction.rs", source)?;
+/// ```
+pub fn to_shank_enum(enum_name: &str, instructions: &[InstructionMeta]) -> String {
+    let mut sorted: Vec<&InstructionMeta> = instructions.iter().collect();
+    sorted.sort_by_key(|instr| (instr.discriminator.len(), instr.discriminator));
+
+    let mut src = String::with_capacity(16 * 1024);
+    src.push_str("#[derive(ShankInstruction)]\n");
+    src.push_str(&format!("pub enum {} {{\n", enum_name));
+
+    let mut next = 0usize;
+    for instr in sorted {
+        let disc = match instr.discriminator {
+            [disc] => *disc as usize,
+            _ => {
+                src.push_str(&format!("    // {}: multi-byte discriminator, not representable in Shank\n", instr.name));
+                continue;
+            }
+        };
+        for unused in next..disc {
+            src.push_str(&format!("    Unused{},\n", unused));
+        }
+        next = disc + 1;
+
+        for line in instr.docs {
+            src.push_str(&format!("    ///{}{}\n", if line.is_empty() { "" } else { " " }, line));
+        }
+        for account in instr.accounts {
+            src.push_str(&format!("    {}\n", account.to_shank_attribute()));
+        }
+
+        let variant = to_pascal_case(instr.name);
+        if instr.params.is_empty() {
+            src.push_str(&format!("    {},\n", variant));
+            continue;
+        }
+        src.push_str(&format!("    {} {{\n", variant));
+        for param in instr.params {
+            for line in param.docs {
+                src.push_str(&format!("        ///{}{}\n", if line.is_empty() { "" } else { " " }, line));
+            }
+            src.push_str(&format!("        {}: {},\n", param.name, param.type_name));
+        }
+        src.push_str("    },\n");
+    }
+
+    src.push_str("}\n");
+    src
+}
+
+/// Append a `"docs": [...]` entry (with trailing comma) if there are any doc lines.
+fn push_docs(json: &mut String, indent: &str, docs: &[&str]) {
+    if docs.is_empty() {
+        return;
+    }
+    let lines: Vec<String> = docs.iter().map(|line| format!("\"{}\"", escape_json(line))).collect();
+    json.push_str(&format!("{}\"docs\": [{}],\n", indent, lines.join(", ")));
+}
+
+/// Emit a `"pda"` line (with trailing comma) if the account has a seed template.
+fn push_pda(json: &mut String, indent: &str, seeds: &[SeedMeta]) {
+    if seeds.is_empty() {
+        return;
+    }
+    let seeds: Vec<String> = seeds.iter().map(|seed| match seed {
+        SeedMeta::Const(bytes) => format!("{{ \"kind\": \"const\", \"value\": [{}] }}", join_bytes(bytes)),
+        SeedMeta::Account(name) => format!("{{ \"kind\": \"account\", \"path\": \"{}\" }}", to_camel_case(name)),
+    }).collect();
+    json.push_str(&format!("{}\"pda\": {{ \"seeds\": [{}] }},\n", indent, seeds.join(", ")));
+}
+
+/// Format bytes as a comma-separated list for a JSON array.
+fn join_bytes(bytes: &[u8]) -> String {
+    let items: Vec<String> = bytes.iter().map(|b| b.to_string()).collect();
+    items.join(", ")
+}
+
+/// Escape a string for inclusion in a JSON string literal.
+fn escape_json(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
 /// Convert snake_case to camelCase
 fn to_camel_case(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
@@ -281,6 +515,16 @@ fn to_camel_case(s: &str) -> String {
     result
 }
 
+/// Convert snake_case or camelCase to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    let camel = to_camel_case(s);
+    let mut chars = camel.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => camel,
+    }
+}
+
 /// Convert Rust type names to Anchor IDL JSON type format
 fn rust_type_to_idl_json(rust_type: &str) -> String {
     match rust_type {
@@ -294,7 +538,7 @@ fn rust_type_to_idl_json(rust_type: &str) -> String {
         "i32" => "\"i32\"".to_string(),
         "i64" => "\"i64\"".to_string(),
         "i128" => "\"i128\"".to_string(),
-        "bool" => "\"bool\"".to_string(),
+        "bool" | "PodBool" => "\"bool\"".to_string(),
         "Pubkey" | "solana_address::Address" | "Address" => "\"pubkey\"".to_string(),
         s if s.starts_with("[") && s.ends_with("]") => {
             // Parse array type "[T; N]"
@@ -331,10 +575,133 @@ mod tests {
         assert_eq!(to_camel_case("payer"), "payer");
     }
 
+    #[test]
+    fn test_escape_json() {
+        assert_eq!(escape_json("plain"), "plain");
+        assert_eq!(escape_json("a \"quoted\" \\ path"), "a \\\"quoted\\\" \\\\ path");
+        assert_eq!(escape_json("tab\there"), "tab\\there");
+    }
+
     #[test]
     fn test_rust_type_to_idl_json() {
         assert_eq!(rust_type_to_idl_json("u64"), "\"u64\"");
         assert_eq!(rust_type_to_idl_json("[u8; 8]"), "{ \"array\": [\"u8\", 8] }");
         assert_eq!(rust_type_to_idl_json("Pubkey"), "\"pubkey\"");
+        assert_eq!(rust_type_to_idl_json("PodBool"), "\"bool\"");
+    }
+
+    mod idl_main_expansion {
+        use crate::{InstructionMeta, ParamField, SeedMeta, ShankAccountMeta};
+
+        const INSTRUCTIONS: &[InstructionMeta] = &[InstructionMeta {
+            name: "deposit_funds",
+            discriminator: &[3],
+            accounts: &[
+                ShankAccountMeta::new(0, "payer").signer(true).writable(true),
+                ShankAccountMeta::new(1, "user_vault")
+                    .writable(true)
+                    .optional(true)
+                    .seeds(&[SeedMeta::Const(b"vault"), SeedMeta::Account("payer")]),
+            ],
+            params: &[
+                ParamField { name: "amount", type_name: "u64", docs: &["Lamports to deposit."] },
+                ParamField { name: "memo", type_name: "[u8; 8]", docs: &[] },
+            ],
+            docs: &["Deposit funds into the \"vault\"."],
+        }];
+
+        crate::idl_main!("Test111111111111111111111111111111111111111", "test_program", "0.1.0", INSTRUCTIONS);
+
+        #[test]
+        fn test_idl_main_prints_valid_json() {
+            let _entry: fn() = main;
+
+            let json: serde_json::Value = serde_json::from_str(&idl_json()).expect("IDL is not valid JSON");
+            assert_eq!(json["metadata"]["name"], "test_program");
+            assert_eq!(json["instructions"][0]["name"], "depositFunds");
+            assert_eq!(json["instructions"][0]["accounts"].as_array().map(|a| a.len()), Some(2));
+            assert_eq!(json["instructions"][0]["args"][1]["type"]["array"][1], 8);
+            assert_eq!(json["instructions"][0]["docs"][0], "Deposit funds into the \"vault\".");
+            assert_eq!(json["instructions"][0]["args"][0]["docs"][0], "Lamports to deposit.");
+            assert!(json["instructions"][0]["args"][1].get("docs").is_none());
+
+            let seeds = &json["instructions"][0]["accounts"][1]["pda"]["seeds"];
+            assert_eq!(seeds[0]["kind"], "const");
+            assert_eq!(seeds[0]["value"][0], b'v');
+            assert_eq!(seeds[1]["path"], "payer");
+            assert!(json["instructions"][0]["accounts"][0].get("pda").is_none());
+            assert_eq!(json["instructions"][0]["accounts"][0]["isOptional"], false);
+            assert_eq!(json["instructions"][0]["accounts"][1]["isOptional"], true);
+        }
+
+        #[test]
+        fn test_to_shank_enum() {
+            let instructions = [
+                InstructionMeta { name: "Close", discriminator: &[1], accounts: &[], params: &[], docs: &[] },
+                INSTRUCTIONS[0],
+                InstructionMeta { name: "Wide", discriminator: &[1, 0], accounts: &[], params: &[], docs: &[] },
+            ];
+
+            let expected = "\
+#[derive(ShankInstruction)]
+pub enum TestInstruction {
+    Unused0,
+    Close,
+    Unused2,
+    /// Deposit funds into the \"vault\".
+    #[account(0, writable, signer, name=\"payer\")]
+    #[account(1, writable, optional, name=\"user_vault\")]
+    DepositFunds {
+        /// Lamports to deposit.
+        amount: u64,
+        memo: [u8; 8],
+    },
+    // Wide: multi-byte discriminator, not representable in Shank
+}
+";
+            assert_eq!(crate::idl::to_shank_enum("TestInstruction", &instructions), expected);
+        }
+    }
+
+    #[test]
+    fn test_to_json_full_includes_account_size() {
+        use crate::FieldMeta;
+
+        const MARKET: AccountTypeMeta = AccountTypeMeta {
+            name: "Market",
+            discriminator: 2,
+            fields: &[
+                FieldMeta { name: "discriminator", type_name: "[u8; 8]", docs: &[] },
+                FieldMeta { name: "flag", type_name: "u8", docs: &[] },
+                FieldMeta { name: "total", type_name: "u64", docs: &[] },
+            ],
+            size: 24,
+        };
+
+        let json = to_json_full("Test111111111111111111111111111111111111111", "test", "0.1.0", &[], &[&MARKET]);
+        let json: serde_json::Value = serde_json::from_str(&json).expect("IDL is not valid JSON");
+        assert_eq!(json["accounts"][0]["discriminator"][0], 2);
+        assert_eq!(json["accounts"][0]["size"], 24);
+    }
+
+    crate::define_errors! {
+        enum TestError {
+            Rejected = 6000 => "order \"rejected\"",
+            Paused = 6001,
+        }
+    }
+
+    #[test]
+    fn test_to_json_full_with_errors() {
+        assert_eq!(TestError::Rejected.message(), "order \"rejected\"");
+        assert_eq!(TestError::Paused.message(), "");
+
+        let json = to_json_full_with_errors("Test111111111111111111111111111111111111111", "test", "0.1.0", &[], &[], TestError::ERRORS);
+        let json: serde_json::Value = serde_json::from_str(&json).expect("IDL is not valid JSON");
+        assert_eq!(json["errors"][0]["code"], 6000);
+        assert_eq!(json["errors"][0]["name"], "Rejected");
+        assert_eq!(json["errors"][0]["msg"], "order \"rejected\"");
+        assert_eq!(json["errors"][1]["code"], 6001);
+        assert!(json["errors"][1].get("msg").is_none());
     }
 }
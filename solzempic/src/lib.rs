@@ -33,14 +33,15 @@
 //! - [`AccountRefMut<T>`]: Writable typed access with ownership + is_writable checks
 //! - [`ShardRefContext<T>`]: Read-only navigation context for sharded data structures
 //! - [`ShardRefMutContext<T>`]: Writable navigation context for sharded data structures
+//! - [`SliceView<H, R>`]: Header plus growable record array for variable-length accounts
 //!
 //! ### Program Wrappers
 //!
 //! Type-safe wrappers for common Solana programs and sysvars:
 //!
 //! - [`SystemProgram`], [`TokenProgram`], [`AtaProgram`], [`AltProgram`]
-//! - [`Signer`], [`Payer`] - Validated signer accounts
-//! - [`Mint`], [`Vault`], [`TokenAccountRefMut`] - SPL Token accounts
+//! - [`Signer`], [`Payer`] - Validated signer accounts ([`ProgramSigner`] for PDA signers)
+//! - [`Mint`], [`Vault`], [`TokenAccountRef`], [`TokenAccountRefMut`] - SPL Token accounts
 //! - [`ClockSysvar`], [`RentSysvar`], [`SlotHashesSysvar`] - Sysvars
 //!
 //! ## Quick Start
@@ -68,7 +69,7 @@
 //! ### 2. Implement an Instruction
 //!
 //! ```ignore
-//! use solzempic::{Signer, ValidatedAccount};
+//! use solzempic::{SafeMath, Signer, ValidatedAccount};
 //!
 //! #[repr(C)]
 //! #[derive(Clone, Copy)]
@@ -103,8 +104,8 @@
 //!     }
 //!
 //!     fn execute(&self, _program_id: &Pubkey, params: &TransferParams) -> ProgramResult {
-//!         self.from.get_mut().balance -= params.amount;
-//!         self.to.get_mut().balance += params.amount;
+//!         self.from.get_mut().balance.safe_sub_assign(params.amount)?;
+//!         self.to.get_mut().balance.safe_add_assign(params.amount)?;
 //!         Ok(())
 //!     }
 //! }
@@ -121,7 +122,7 @@
 //! ## Module Organization
 //!
 //! - [`programs`]: Program and sysvar account wrappers
-//! - Account utilities: [`create_pda_account`], [`transfer_lamports`], [`rent_exempt_minimum`]
+//! - Account utilities: [`create_pda_account`], [`transfer_lamports`], [`rent_exempt_minimum`], [`try_rent_exempt_minimum`]
 //!
 //! ## Performance
 //!
@@ -136,16 +137,36 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(test)]
+extern crate std;
 
 mod account;
+mod address_fmt;
+mod constraints;
+mod math;
+mod pod;
+mod return_data;
 pub mod programs;
+mod seeds;
+mod trace;
 pub mod traits;
 mod wrappers;
 
 #[cfg(feature = "idl")]
 pub mod idl;
 
-pub use account::{create_pda_account, rent_exempt_minimum, transfer_lamports, LAMPORTS_PER_BYTE, MAX_ACCOUNT_SIZE};
+#[cfg(not(target_os = "solana"))]
+pub mod test_utils;
+
+pub use account::{create_pda_account, invoke_as_pda, next_account, rent_exempt_minimum, transfer_lamports, transfer_lamports_owned, transfer_lamports_signed, try_rent_exempt_minimum, LAMPORTS_PER_BYTE, MAX_ACCOUNT_SIZE, MAX_SEEDS};
+pub use address_fmt::{format_address, AddressStr, MAX_BASE58_LEN};
+pub use constraints::InstructionConstraints;
+#[doc(hidden)]
+pub use constraints::{ConstraintProbe, ProbeConstraints, ProbeNoConstraints};
+pub use math::SafeMath;
+pub use pod::PodBool;
+pub use return_data::{return_data, return_value, MAX_RETURN_DATA};
+pub use seeds::Seeds;
 
 // Re-export programs module items at crate root for convenience
 pub use programs::{
@@ -153,42 +174,53 @@ pub use programs::{
     SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID,
     ADDRESS_LOOKUP_TABLE_PROGRAM_ID, CLOCK_SYSVAR_ID, RENT_SYSVAR_ID, SLOT_HASHES_SYSVAR_ID,
     INSTRUCTIONS_SYSVAR_ID, RECENT_BLOCKHASHES_SYSVAR_ID, LAST_RESTART_SLOT_SYSVAR_ID,
+    EPOCH_SCHEDULE_SYSVAR_ID, EPOCH_REWARDS_SYSVAR_ID,
     // Traits
     ValidatedAccount,
     // Program wrappers
-    SystemProgram, TokenProgram, AtaProgram, AltProgram, Lut,
+    SystemProgram, TokenProgram, AtaProgram, AltProgram, Lut, MAX_EXTEND_ADDRESSES, derive_ata, assert_is_ata,
     // Signer/Payer/MutSigner
-    Signer, Payer, MutSigner,
+    Signer, Payer, MutSigner, ProgramSigner,
     // Explicit mutability wrappers (for raw AccountView)
     Writable, ReadOnly,
     // Sysvars
-    ClockSysvar, RentSysvar, SlotHashesSysvar, InstructionsSysvar, RecentBlockhashesSysvar,
-    LastRestartSlotSysvar, LastRestartSlot,
+    ClockSysvar, RentSysvar, SlotHashesSysvar, InstructionsSysvar, IntrospectedIx, IntrospectedAccount,
+    RecentBlockhashesSysvar,
+    LastRestartSlotSysvar, LastRestartSlot, EpochScheduleSysvar, EpochSchedule, EpochRewardsSysvar,
     // Token
-    Mint, TokenAccountData, TokenAccountRefMut, Vault, SolVault,
+    Mint, TokenAccountData, TokenAccountRef, TokenAccountRefMut, Vault, VaultMut, SolVault, assert_same_mint,
+    // Token-2022 extensions
+    Extension, TransferFee, TransferFeeAmount, TransferFeeConfig,
     // Validation
     validate_token_program, validate_system_program, validate_clock_sysvar,
-    validate_slot_hashes_sysvar, validate_rent_sysvar,
+    validate_slot_hashes_sysvar, validate_rent_sysvar, validate_epoch_schedule_sysvar,
+    validate_epoch_rewards_sysvar, assert_one_of, validate_key_one_of, validate_owner_one_of,
 };
-pub use wrappers::{AccountRef, AccountRefMut, AsAccountRef, ShardRefContext, ShardRefMutContext};
+pub use wrappers::{AccountRef, AccountRefMut, AsAccountRef, ForeignRef, ForeignRefMut, ShardLinks, ShardRefContext, ShardRefMutContext, SliceHeader, SliceView};
 
 // Re-export core traits
-pub use traits::{check_discriminator, Account, Initializable, Loadable};
+pub use traits::{check_discriminator, check_discriminator_ct, Account, Discriminator, Initializable, Loadable};
 
 // Re-export derive macros (Account derive renamed to avoid conflict with Account trait)
-pub use solzempic_macros::{Account as AccountDerive, SolzempicEntrypoint, account, instruction, params};
+pub use solzempic_macros::{Account as AccountDerive, BorshParams, Framework, Params, SolzempicEntrypoint, account, instruction, params};
 
 /// Define an AccountType enum with automatic discriminator values.
 ///
 /// This macro generates a `#[repr(u8)]` enum with the specified variants and values,
 /// along with helper methods for discriminator checking.
 ///
+/// Each variant may name the account struct it tags with `=> Type`. For
+/// those variants the macro checks at compile time that
+/// `<Type as Loadable>::DISCRIMINATOR` equals the variant's value, and
+/// `len` reports the struct's `LEN`, so the enum and the
+/// structs cannot silently disagree.
+///
 /// # Example
 ///
 /// ```ignore
 /// solzempic::define_account_types! {
-///     Counter = 1,
-///     Market = 2,
+///     Counter = 1 => CounterAccount,
+///     Market = 2 => Market,
 ///     User = 3,
 /// }
 /// ```
@@ -205,6 +237,8 @@ pub use solzempic_macros::{Account as AccountDerive, SolzempicEntrypoint, accoun
 /// }
 ///
 /// impl AccountType {
+///     pub const ALL: &'static [AccountType] = &[AccountType::Counter, AccountType::Market, AccountType::User];
+///
 ///     pub const fn to_bytes(self) -> [u8; 8] {
 ///         [self as u8, 0, 0, 0, 0, 0, 0, 0]
 ///     }
@@ -212,13 +246,20 @@ pub use solzempic_macros::{Account as AccountDerive, SolzempicEntrypoint, accoun
 ///     pub fn check(data: &[u8], expected: Self) -> bool {
 ///         !data.is_empty() && data[0] == expected as u8
 ///     }
+///
+///     pub fn from_data(data: &[u8]) -> Option<Self> { /* match data[0] */ }
+///     pub const fn name(self) -> &'static str { /* "Counter", ... */ }
+///     pub const fn len(self) -> Option<usize> { /* Some(CounterAccount::LEN), ..., None */ }
 /// }
+///
+/// const _: () = assert!(<CounterAccount as Loadable>::DISCRIMINATOR == AccountType::Counter as u8);
+/// const _: () = assert!(<Market as Loadable>::DISCRIMINATOR == AccountType::Market as u8);
 /// ```
 #[macro_export]
 macro_rules! define_account_types {
     (
         $(
-            $variant:ident = $value:expr
+            $variant:ident = $value:expr $(=> $ty:ty)?
         ),* $(,)?
     ) => {
         /// Account type discriminators for the program.
@@ -231,6 +272,9 @@ macro_rules! define_account_types {
         }
 
         impl AccountType {
+            /// Every account type, in declaration order.
+            pub const ALL: &'static [AccountType] = &[$(AccountType::$variant),*];
+
             /// Convert the account type to an 8-byte discriminator array.
             ///
             /// The discriminator value is stored in the first byte,
@@ -248,6 +292,132 @@ macro_rules! define_account_types {
             pub fn check(data: &[u8], expected: Self) -> bool {
                 !data.is_empty() && data[0] == expected as u8
             }
+
+            /// Identify the account type from the first byte of account data.
+            ///
+            /// Returns `None` for empty data or an unknown discriminator.
+            #[inline]
+            pub fn from_data(data: &[u8]) -> Option<Self> {
+                let &first = data.first()?;
+                $(
+                    if first == AccountType::$variant as u8 {
+                        return Some(AccountType::$variant);
+                    }
+                )*
+                None
+            }
+
+            /// The variant name, for IDL account enumeration.
+            #[inline]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(AccountType::$variant => stringify!($variant),)*
+                }
+            }
+
+            /// `LEN` of the account struct registered with `=> Type`, if any.
+            #[inline]
+            pub const fn len(self) -> Option<usize> {
+                match self {
+                    $(AccountType::$variant => {
+                        let _len: Option<usize> = None;
+                        $(let _len = Some(<$ty as $crate::Loadable>::LEN);)?
+                        _len
+                    })*
+                }
+            }
+        }
+
+        $($(
+            const _: () = assert!(
+                <$ty as $crate::Loadable>::DISCRIMINATOR == AccountType::$variant as u8,
+                concat!("discriminator of ", stringify!($ty), " does not match AccountType::", stringify!($variant)),
+            );
+        )?)*
+    };
+}
+
+/// Define a program error enum that converts into `ProgramError::Custom`.
+///
+/// Each variant's value is its custom error code. Use the enum as the
+/// `error = ...` type of `#[instruction(Params, error = MyError)]`, or return
+/// it anywhere a `ProgramError` is expected via `?` / `.into()`.
+///
+/// # Example
+///
+/// ```ignore
+/// solzempic::define_errors! {
+///     pub enum MarketError {
+///         /// Not enough liquidity to fill the order.
+///         InsufficientLiquidity = 6000 => "not enough liquidity",
+///         Unauthorized = 6001,
+///     }
+/// }
+///
+/// return Err(MarketError::Unauthorized.into()); // ProgramError::Custom(6001)
+/// ```
+///
+/// Generates a `#[repr(u32)]` enum deriving `Clone, Copy, PartialEq, Eq, Debug`,
+/// `impl From<MarketError> for ProgramError`, and:
+///
+/// - `MarketError::ERRORS: &[ErrorMeta]` - code, name, and message of every
+///   variant, registered for the IDL `errors` section when the `idl` feature
+///   is enabled
+/// - `MarketError::message(self) -> &'static str` - the `=> "..."` message,
+///   or `""` if none was given
+#[macro_export]
+macro_rules! define_errors {
+    (@msg $msg:literal) => { $msg };
+    (@msg) => { "" };
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $code:expr $(=> $msg:literal)?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(u32)]
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant = $code,
+            )*
+        }
+
+        impl $name {
+            /// Code, name, and message of every variant, for IDL generation.
+            pub const ERRORS: &'static [$crate::ErrorMeta] = &[
+                $($crate::ErrorMeta {
+                    code: $name::$variant as u32,
+                    name: ::core::stringify!($variant),
+                    msg: $crate::define_errors!(@msg $($msg)?),
+                },)*
+            ];
+
+            /// Human-readable message for this error (`""` if none was given).
+            #[inline]
+            pub const fn message(self) -> &'static str {
+                match self {
+                    $($name::$variant => $crate::define_errors!(@msg $($msg)?),)*
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for ::pinocchio::error::ProgramError {
+            #[inline]
+            fn from(error: $name) -> Self {
+                ::pinocchio::error::ProgramError::Custom(error as u32)
+            }
+        }
+
+        // Auto-register with inventory when idl feature is enabled
+        #[cfg(feature = "idl")]
+        $crate::inventory::submit! {
+            $crate::ErrorTable($name::ERRORS)
         }
     };
 }
@@ -299,8 +469,8 @@ pub use shank;
 ///     }
 ///
 ///     fn execute(&self, _program_id: &Pubkey, params: &Self::Params) -> ProgramResult {
-///         self.from.get_mut().balance -= params.amount;
-///         self.to.get_mut().balance += params.amount;
+///         self.from.get_mut().balance.safe_sub_assign(params.amount)?;
+///         self.to.get_mut().balance.safe_add_assign(params.amount)?;
 ///         Ok(())
 ///     }
 /// }
@@ -318,8 +488,62 @@ pub use shank;
 ///
 /// This allows dispatch to know the params type without a lifetime parameter.
 pub trait InstructionParams {
-    /// The parameter type for this instruction (must be `Copy` for zero-copy parsing).
-    type Params: Copy;
+    /// The parameter type for this instruction.
+    ///
+    /// Any `Copy` type decodes zero-copy through [`ParamsDecode`];
+    /// borsh-decoded params implement it with `#[derive(BorshParams)]`.
+    type Params: ParamsDecode;
+
+    /// Instruction name for `trace` logs.
+    ///
+    /// The `#[instruction]` macro sets it to the struct name, the same as
+    /// `IDL_NAME`.
+    const NAME: &'static str = "instruction";
+
+    /// Decode the parameters from instruction data (discriminator already stripped).
+    ///
+    /// Defaults to [`ParamsDecode::decode`], which is a zero-copy
+    /// [`parse_params`] cast for `Copy` params.
+    #[inline]
+    fn decode_params(data: &[u8]) -> Result<Self::Params, ProgramError> {
+        <Self::Params as ParamsDecode>::decode(data)
+    }
+
+    /// Decode the parameters and return the instruction data that follows them.
+    ///
+    /// Defaults to [`ParamsDecode::decode_with_rest`], so hand-written impls
+    /// pass the trailing bytes to [`build_with_rest`](Instruction::build_with_rest)
+    /// the same way generated ones do.
+    #[inline]
+    fn decode_params_with_rest(data: &[u8]) -> Result<(Self::Params, &[u8]), ProgramError> {
+        <Self::Params as ParamsDecode>::decode_with_rest(data)
+    }
+}
+
+/// How a params type is read from instruction data.
+///
+/// Implemented for every `Copy` type as a zero-copy [`parse_params`] cast,
+/// so `#[params]` structs and hand-written `#[repr(C)]` params need nothing
+/// extra. Params with variable-length fields derive it with
+/// `#[derive(BorshDeserialize, BorshParams)]` (`borsh` feature).
+pub trait ParamsDecode: Sized {
+    /// Decode the params from the start of `data`.
+    fn decode(data: &[u8]) -> Result<Self, ProgramError>;
+
+    /// Decode the params and return the bytes that follow them.
+    fn decode_with_rest(data: &[u8]) -> Result<(Self, &[u8]), ProgramError>;
+}
+
+impl<T: Copy> ParamsDecode for T {
+    #[inline]
+    fn decode(data: &[u8]) -> Result<Self, ProgramError> {
+        parse_params(data)
+    }
+
+    #[inline]
+    fn decode_with_rest(data: &[u8]) -> Result<(Self, &[u8]), ProgramError> {
+        parse_params_with_rest(data)
+    }
 }
 
 /// The Instruction trait defines the three-phase instruction processing pattern.
@@ -360,29 +584,168 @@ pub trait InstructionParams {
 ///     }
 ///
 ///     fn execute(&self, _program_id: &Pubkey, params: &Self::Params) -> ProgramResult {
-///         self.from.get_mut().balance -= params.amount;
-///         self.to.get_mut().balance += params.amount;
+///         self.from.get_mut().balance.safe_sub_assign(params.amount)?;
+///         self.to.get_mut().balance.safe_add_assign(params.amount)?;
 ///         Ok(())
 ///     }
 /// }
 /// ```
 pub trait Instruction<'a>: InstructionParams + Sized {
+    /// Reject invalid parameters before any account is loaded.
+    ///
+    /// Runs ahead of [`build`](Self::build), so cheap checks (a zero amount,
+    /// an out-of-range index) fail without paying for account loading.
+    /// Defaults to `Ok(())`.
+    #[inline]
+    fn precheck(params: &Self::Params) -> ProgramResult {
+        let _ = params;
+        Ok(())
+    }
+
     /// Build the instruction context from accounts and parameters.
     fn build(accounts: &'a [AccountView], params: &Self::Params) -> Result<Self, ProgramError>;
 
+    /// Build the instruction context, also receiving the instruction data left
+    /// after the fixed-size parameters.
+    ///
+    /// Implement this instead of relying on [`build`](Self::build) for
+    /// instructions with a variable-length payload after their params (e.g. a
+    /// list of amounts). `rest` borrows the instruction data, so the context
+    /// can keep it. Defaults to `build`, ignoring `rest`.
+    #[inline]
+    fn build_with_rest(accounts: &'a [AccountView], params: &Self::Params, rest: &'a [u8]) -> Result<Self, ProgramError> {
+        let _ = rest;
+        Self::build(accounts, params)
+    }
+
+    /// Run the account constraints declared on the instruction struct.
+    ///
+    /// [`process`](Self::process) calls this between `build` and `validate`.
+    /// The `#[instruction]` macro forwards it to the struct's
+    /// [`InstructionConstraints`] (`#[has_one]`, `#[address]`) when it has
+    /// any. Defaults to `Ok(())`.
+    #[inline]
+    fn check_constraints(&self) -> ProgramResult {
+        Ok(())
+    }
+
     /// Validate business logic invariants.
     fn validate(&self, program_id: &Address, params: &Self::Params) -> ProgramResult;
 
     /// Execute the instruction and perform state changes.
     fn execute(&mut self, program_id: &Address, params: &Self::Params) -> ProgramResult;
 
-    /// Process the instruction (parse params -> build context -> validate -> execute).
+    /// Process the instruction (parse params -> precheck -> build context ->
+    /// check constraints -> validate -> execute).
+    ///
+    /// `data` borrows for `'a`, like `accounts`, so the bytes after the params
+    /// can be handed to [`build_with_rest`](Self::build_with_rest) and kept in
+    /// the instruction struct.
     #[inline(never)]
-    fn process(program_id: &Address, accounts: &'a [AccountView], data: &[u8]) -> ProgramResult {
-        let params = parse_params::<Self::Params>(data)?;
-        let mut ctx = Self::build(accounts, &params)?;
-        ctx.validate(program_id, &params)?;
-        ctx.execute(program_id, &params)
+    fn process(program_id: &Address, accounts: &'a [AccountView], data: &'a [u8]) -> ProgramResult {
+        let (params, rest) = Self::decode_params_with_rest(data)?;
+        trace::phase(Self::NAME, "precheck", <Self as Instruction<'a>>::precheck(&params))?;
+        let mut ctx = trace::phase(Self::NAME, "build", Self::build_with_rest(accounts, &params, rest))?;
+        trace::phase(Self::NAME, "constraints", <Self as Instruction<'a>>::check_constraints(&ctx))?;
+        trace::phase(Self::NAME, "validate", ctx.validate(program_id, &params))?;
+        trace::phase(Self::NAME, "execute", ctx.execute(program_id, &params))
+    }
+}
+
+/// Instruction whose phases share state derived while building it.
+///
+/// `build_with_context` returns the instruction plus a `Ctx` value (a resolved
+/// price, a loaded config, ...) that [`process_with_context`](Self::process_with_context)
+/// passes by reference to `validate_with_context` and `execute_with_context`.
+/// This keeps derived values out of the account struct, where the IDL
+/// analysis would treat them as accounts.
+///
+/// The `#[instruction]` macro implements this when the impl block declares
+/// `type Ctx = ...;`. Every [`Instruction`] is also a `ContextInstruction`
+/// with `Ctx = ()` whose `process_with_context` calls
+/// [`Instruction::process`], and the entrypoint dispatches through
+/// `process_with_context`, so both kinds of instruction are routed the same
+/// way. A context instruction doesn't implement [`Instruction`]; call its
+/// phases through this trait.
+pub trait ContextInstruction<'a>: InstructionParams + Sized {
+    /// State computed in `build_with_context` and shared by the later phases.
+    type Ctx;
+
+    /// Reject invalid parameters before any account is loaded.
+    ///
+    /// Same as [`Instruction::precheck`]. Defaults to `Ok(())`.
+    #[inline]
+    fn precheck(params: &Self::Params) -> ProgramResult {
+        let _ = params;
+        Ok(())
+    }
+
+    /// Build the instruction and its context from accounts, parameters, and
+    /// the instruction data left after the parameters.
+    fn build_with_context(
+        accounts: &'a [AccountView],
+        params: &Self::Params,
+        rest: &'a [u8],
+    ) -> Result<(Self, Self::Ctx), ProgramError>;
+
+    /// Run the account constraints declared on the instruction struct.
+    ///
+    /// Same as [`Instruction::check_constraints`]. Defaults to `Ok(())`.
+    #[inline]
+    fn check_constraints(&self) -> ProgramResult {
+        Ok(())
+    }
+
+    /// Validate business logic invariants.
+    fn validate_with_context(&self, ctx: &Self::Ctx, program_id: &Address, params: &Self::Params) -> ProgramResult;
+
+    /// Execute the instruction and perform state changes.
+    fn execute_with_context(&mut self, ctx: &Self::Ctx, program_id: &Address, params: &Self::Params) -> ProgramResult;
+
+    /// Process the instruction, threading the context through the phases.
+    #[inline(never)]
+    fn process_with_context(program_id: &Address, accounts: &'a [AccountView], data: &'a [u8]) -> ProgramResult {
+        let (params, rest) = Self::decode_params_with_rest(data)?;
+        trace::phase(Self::NAME, "precheck", <Self as ContextInstruction<'a>>::precheck(&params))?;
+        let (mut ix, ctx) = trace::phase(Self::NAME, "build", Self::build_with_context(accounts, &params, rest))?;
+        trace::phase(Self::NAME, "constraints", <Self as ContextInstruction<'a>>::check_constraints(&ix))?;
+        trace::phase(Self::NAME, "validate", ix.validate_with_context(&ctx, program_id, &params))?;
+        trace::phase(Self::NAME, "execute", ix.execute_with_context(&ctx, program_id, &params))
+    }
+}
+
+impl<'a, T: Instruction<'a>> ContextInstruction<'a> for T {
+    type Ctx = ();
+
+    #[inline]
+    fn precheck(params: &Self::Params) -> ProgramResult {
+        <T as Instruction<'a>>::precheck(params)
+    }
+
+    #[inline]
+    fn build_with_context(accounts: &'a [AccountView], params: &Self::Params, rest: &'a [u8]) -> Result<(Self, ()), ProgramError> {
+        Ok((T::build_with_rest(accounts, params, rest)?, ()))
+    }
+
+    #[inline]
+    fn check_constraints(&self) -> ProgramResult {
+        <T as Instruction<'a>>::check_constraints(self)
+    }
+
+    #[inline]
+    fn validate_with_context(&self, _ctx: &(), program_id: &Address, params: &Self::Params) -> ProgramResult {
+        self.validate(program_id, params)
+    }
+
+    #[inline]
+    fn execute_with_context(&mut self, _ctx: &(), program_id: &Address, params: &Self::Params) -> ProgramResult {
+        self.execute(program_id, params)
+    }
+
+    // Keep an overridden `Instruction::process` in charge of dispatch
+    #[inline]
+    fn process_with_context(program_id: &Address, accounts: &'a [AccountView], data: &'a [u8]) -> ProgramResult {
+        T::process(program_id, accounts, data)
     }
 }
 
@@ -432,6 +795,72 @@ pub fn parse_params<T: Copy>(data: &[u8]) -> Result<T, ProgramError> {
     Ok(unsafe { *ptr })
 }
 
+/// Parse instruction parameters zero-copy and return the bytes after them.
+///
+/// Like [`parse_params`], but also returns `&data[size_of::<T>()..]` so a
+/// fixed header can be followed by a variable-length payload.
+///
+/// # Errors
+///
+/// Returns `InvalidInstructionData` if the data slice is shorter than
+/// `size_of::<T>()`.
+#[inline]
+pub fn parse_params_with_rest<T: Copy>(data: &[u8]) -> Result<(T, &[u8]), ProgramError> {
+    let params = parse_params::<T>(data)?;
+    Ok((params, &data[core::mem::size_of::<T>()..]))
+}
+
+/// Decode instruction parameters from raw bytes using borsh.
+///
+/// This is the escape hatch for instructions whose parameters contain
+/// variable-length data (e.g., a `Vec<u8>` memo or a `String`). It is used by
+/// `#[instruction(MyParams, borsh)]`; hot-path instructions should keep using
+/// the zero-copy [`parse_params`].
+///
+/// Trailing bytes after the encoded value are ignored, matching
+/// [`parse_params`].
+///
+/// # Errors
+///
+/// Returns `InvalidInstructionData` if the data cannot be decoded as `T`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(BorshDeserialize)]
+/// pub struct SetMetadataParams {
+///     pub fee_bps: u16,
+///     pub memo: Vec<u8>,
+/// }
+///
+/// let params = parse_params_borsh::<SetMetadataParams>(data)?;
+/// ```
+#[cfg(feature = "borsh")]
+#[inline]
+pub fn parse_params_borsh<T: borsh::BorshDeserialize>(data: &[u8]) -> Result<T, ProgramError> {
+    let mut cursor = data;
+    T::deserialize(&mut cursor).map_err(|_| ProgramError::InvalidInstructionData)
+}
+
+/// Decode borsh instruction parameters and return the bytes after them.
+///
+/// The borsh counterpart of [`parse_params_with_rest`].
+///
+/// # Errors
+///
+/// Returns `InvalidInstructionData` if the data cannot be decoded as `T`.
+#[cfg(feature = "borsh")]
+#[inline]
+pub fn parse_params_borsh_with_rest<T: borsh::BorshDeserialize>(data: &[u8]) -> Result<(T, &[u8]), ProgramError> {
+    let mut cursor = data;
+    let params = T::deserialize(&mut cursor).map_err(|_| ProgramError::InvalidInstructionData)?;
+    Ok((params, cursor))
+}
+
+// Re-export borsh for use by generated code
+#[cfg(feature = "borsh")]
+pub use borsh;
+
 /// Trait that defines program-specific configuration for account wrappers.
 ///
 /// The `Framework` trait allows account wrappers ([`AccountRef`], [`AccountRefMut`])
@@ -509,6 +938,45 @@ pub mod errors {
     pub fn account_already_initialized() -> ProgramError {
         ProgramError::AccountAlreadyInitialized
     }
+
+    /// Error returned when an instruction receives fewer accounts than it reads.
+    ///
+    /// This is returned by [`next_account`](crate::next_account) for an
+    /// out-of-bounds index, and by `TokenProgram::for_account` when the
+    /// owning token program was not passed.
+    #[inline]
+    pub fn not_enough_account_keys() -> ProgramError {
+        ProgramError::NotEnoughAccountKeys
+    }
+
+    /// Error returned when a requested account size exceeds the runtime limit.
+    ///
+    /// This is returned by [`create_pda_account`](crate::create_pda_account) and
+    /// [`AccountRefMut::init_pda`] when `space` is larger than
+    /// [`MAX_ACCOUNT_SIZE`](crate::MAX_ACCOUNT_SIZE).
+    #[inline]
+    pub fn account_too_large() -> ProgramError {
+        ProgramError::InvalidArgument
+    }
+
+    /// Error returned when a token account is frozen.
+    ///
+    /// This is returned by `TokenAccountRef::load_active` and
+    /// `TokenAccountRefMut::load_active`. It is a builtin error rather than a
+    /// custom code, since custom codes are read against the failing
+    /// program's own errors and would collide with `define_errors!`.
+    #[inline]
+    pub fn account_frozen() -> ProgramError {
+        ProgramError::InvalidAccountData
+    }
+
+    /// Error returned when a deadline has passed.
+    ///
+    /// This is returned by `ClockSysvar::assert_not_expired`.
+    #[inline]
+    pub fn expired() -> ProgramError {
+        ProgramError::InvalidArgument
+    }
 }
 
 /// Metadata for a single account in a Shank-compatible instruction.
@@ -522,12 +990,17 @@ pub mod errors {
 /// ```ignore
 /// // Generated by #[instruction] on a struct:
 /// const ACCOUNTS: [ShankAccountMeta; 3] = [
-///     ShankAccountMeta { index: 0, name: "source", is_signer: false, is_writable: true, is_program: false },
-///     ShankAccountMeta { index: 1, name: "destination", is_signer: false, is_writable: true, is_program: false },
-///     ShankAccountMeta { index: 2, name: "owner", is_signer: true, is_writable: false, is_program: false },
+///     ShankAccountMeta::new(0, "source").writable(true),
+///     ShankAccountMeta::new(1, "destination").writable(true),
+///     ShankAccountMeta::new(2, "owner").signer(true),
 /// ];
 /// ```
+///
+/// The struct is `#[non_exhaustive]`, so new metadata can be added without
+/// breaking callers: build it with [`new`](Self::new) and the flag setters
+/// rather than a struct literal.
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub struct ShankAccountMeta {
     /// Account index in the accounts array (0-based).
     pub index: usize,
@@ -539,6 +1012,73 @@ pub struct ShankAccountMeta {
     pub is_writable: bool,
     /// Whether this account is a program account.
     pub is_program: bool,
+    /// Whether this account is only passed for some params (`#[present_if]`).
+    pub is_optional: bool,
+    /// PDA seed template from `#[seeds(...)]`, empty if not a declared PDA.
+    pub seeds: &'static [SeedMeta],
+}
+
+impl ShankAccountMeta {
+    /// Metadata for the account at `index`, with every flag cleared and no seeds.
+    #[inline]
+    pub const fn new(index: usize, name: &'static str) -> Self {
+        Self {
+            index,
+            name,
+            is_signer: false,
+            is_writable: false,
+            is_program: false,
+            is_optional: false,
+            seeds: &[],
+        }
+    }
+
+    /// Set whether the account must be a signer.
+    #[inline]
+    pub const fn signer(mut self, is_signer: bool) -> Self {
+        self.is_signer = is_signer;
+        self
+    }
+
+    /// Set whether the account must be writable.
+    #[inline]
+    pub const fn writable(mut self, is_writable: bool) -> Self {
+        self.is_writable = is_writable;
+        self
+    }
+
+    /// Set whether the account is a program account.
+    #[inline]
+    pub const fn program(mut self, is_program: bool) -> Self {
+        self.is_program = is_program;
+        self
+    }
+
+    /// Set whether the account is only passed for some params.
+    #[inline]
+    pub const fn optional(mut self, is_optional: bool) -> Self {
+        self.is_optional = is_optional;
+        self
+    }
+
+    /// Set the PDA seed template.
+    #[inline]
+    pub const fn seeds(mut self, seeds: &'static [SeedMeta]) -> Self {
+        self.seeds = seeds;
+        self
+    }
+}
+
+/// One seed of a PDA declared with `#[seeds(...)]` on an instruction struct field.
+///
+/// Lets clients derive the account's address instead of hardcoding the
+/// derivation. The bump is not part of the template; the canonical bump is used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeedMeta {
+    /// A constant byte string, e.g. `b"market"`.
+    Const(&'static [u8]),
+    /// The address of another account in the same instruction, by field name.
+    Account(&'static str),
 }
 
 #[cfg(feature = "idl")]
@@ -554,6 +1094,9 @@ impl ShankAccountMeta {
         if self.is_signer {
             parts.push(alloc::string::String::from("signer"));
         }
+        if self.is_optional {
+            parts.push(alloc::string::String::from("optional"));
+        }
 
         parts.push(alloc::format!("name=\"{}\"", self.name));
 
@@ -569,6 +1112,8 @@ pub struct ParamField {
     pub name: &'static str,
     /// Type name (e.g., "u64", "u8", "[u8; 7]").
     pub type_name: &'static str,
+    /// Doc comment lines from the field.
+    pub docs: &'static [&'static str],
 }
 
 /// Metadata for a complete instruction, combining accounts and parameters.
@@ -577,12 +1122,30 @@ pub struct ParamField {
 pub struct InstructionMeta {
     /// Instruction name (e.g., "CancelClmmPosition").
     pub name: &'static str,
-    /// Discriminator value.
-    pub discriminator: u8,
+    /// Discriminator bytes (little-endian, `disc_len` long).
+    pub discriminator: &'static [u8],
     /// Account metadata slice.
     pub accounts: &'static [ShankAccountMeta],
     /// Parameter field metadata slice.
     pub params: &'static [ParamField],
+    /// Doc comment lines from the instruction struct.
+    pub docs: &'static [&'static str],
+}
+
+/// Account ordering for one instruction, for off-chain transaction builders.
+///
+/// Generated by `#[SolzempicEntrypoint]` into `ACCOUNT_ORDER` when the
+/// program's `client` feature is enabled. Each entry's `accounts` lists the
+/// instruction's accounts in the order `build` expects them, so a client can
+/// assemble its `AccountMeta`s without duplicating the on-chain layout.
+#[derive(Clone, Copy, Debug)]
+pub struct InstructionAccounts {
+    /// Instruction name (e.g., "CancelClmmPosition").
+    pub name: &'static str,
+    /// Discriminator bytes (little-endian, `disc_len` long).
+    pub discriminator: &'static [u8],
+    /// Accounts in instruction order.
+    pub accounts: &'static [ShankAccountMeta],
 }
 
 /// Trait for types that provide instruction parameter metadata.
@@ -611,6 +1174,8 @@ pub struct FieldMeta {
     pub name: &'static str,
     /// Type name (e.g., "u64", "pubkey", "[u8; 8]").
     pub type_name: &'static str,
+    /// Doc comment lines from the field.
+    pub docs: &'static [&'static str],
 }
 
 /// Metadata for an account type definition.
@@ -623,8 +1188,27 @@ pub struct AccountTypeMeta {
     pub discriminator: u8,
     /// Field metadata slice.
     pub fields: &'static [FieldMeta],
+    /// Total size in bytes (`size_of::<T>()`, including padding).
+    pub size: usize,
 }
 
+/// Metadata for a custom program error.
+/// Used for generating the "errors" section of the IDL.
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorMeta {
+    /// Code returned as `ProgramError::Custom(code)`.
+    pub code: u32,
+    /// Variant name (e.g., "InsufficientLiquidity").
+    pub name: &'static str,
+    /// Human-readable message (empty if none).
+    pub msg: &'static str,
+}
+
+/// The `ERRORS` table of one `define_errors!` enum, as registered with
+/// inventory for IDL generation.
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorTable(pub &'static [ErrorMeta]);
+
 /// Trait for account types that provide IDL metadata.
 /// Implemented by `#[account]` macro when `idl` feature is enabled.
 pub trait AccountIdlMeta {
@@ -646,6 +1230,10 @@ pub use inventory;
 #[cfg(feature = "idl")]
 inventory::collect!(&'static AccountTypeMeta);
 
+// Inventory collection for error tables
+#[cfg(feature = "idl")]
+inventory::collect!(ErrorTable);
+
 /// Define framework type aliases for account wrappers.
 ///
 /// This macro creates program-specific type aliases that bake in your program ID,
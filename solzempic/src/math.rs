@@ -0,0 +1,105 @@
+//! Overflow-checked arithmetic for balances and counters.
+//!
+//! Release builds of Solana programs may wrap on overflow, so an unchecked
+//! `balance -= amount` can turn an underflow into a huge balance. [`SafeMath`]
+//! wraps the standard `checked_*` methods so every failure surfaces as
+//! [`ProgramError::ArithmeticOverflow`] and composes with `?`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use solzempic::SafeMath;
+//!
+//! fn execute(&mut self, _program_id: &Address, params: &TransferParams) -> ProgramResult {
+//!     self.from.get_mut().balance.safe_sub_assign(params.amount)?;
+//!     self.to.get_mut().balance.safe_add_assign(params.amount)?;
+//!     Ok(())
+//! }
+//! ```
+
+use pinocchio::{error::ProgramError, ProgramResult};
+
+/// Checked arithmetic returning [`ProgramError::ArithmeticOverflow`] on failure.
+///
+/// Implemented for the unsigned integer types. Division by zero is reported
+/// as `ArithmeticOverflow` too, since `ProgramError` has no dedicated variant.
+pub trait SafeMath: Sized {
+    /// `self + rhs`, or an error on overflow.
+    fn safe_add(self, rhs: Self) -> Result<Self, ProgramError>;
+
+    /// `self - rhs`, or an error on underflow.
+    fn safe_sub(self, rhs: Self) -> Result<Self, ProgramError>;
+
+    /// `self * rhs`, or an error on overflow.
+    fn safe_mul(self, rhs: Self) -> Result<Self, ProgramError>;
+
+    /// `self / rhs`, or an error if `rhs` is zero.
+    fn safe_div(self, rhs: Self) -> Result<Self, ProgramError>;
+
+    /// `*self += rhs`, leaving `self` unchanged on overflow.
+    fn safe_add_assign(&mut self, rhs: Self) -> ProgramResult;
+
+    /// `*self -= rhs`, leaving `self` unchanged on underflow.
+    fn safe_sub_assign(&mut self, rhs: Self) -> ProgramResult;
+}
+
+macro_rules! impl_safe_math {
+    ($($ty:ty),*) => {
+        $(
+            impl SafeMath for $ty {
+                #[inline]
+                fn safe_add(self, rhs: Self) -> Result<Self, ProgramError> {
+                    self.checked_add(rhs).ok_or(ProgramError::ArithmeticOverflow)
+                }
+
+                #[inline]
+                fn safe_sub(self, rhs: Self) -> Result<Self, ProgramError> {
+                    self.checked_sub(rhs).ok_or(ProgramError::ArithmeticOverflow)
+                }
+
+                #[inline]
+                fn safe_mul(self, rhs: Self) -> Result<Self, ProgramError> {
+                    self.checked_mul(rhs).ok_or(ProgramError::ArithmeticOverflow)
+                }
+
+                #[inline]
+                fn safe_div(self, rhs: Self) -> Result<Self, ProgramError> {
+                    self.checked_div(rhs).ok_or(ProgramError::ArithmeticOverflow)
+                }
+
+                #[inline]
+                fn safe_add_assign(&mut self, rhs: Self) -> ProgramResult {
+                    *self = self.safe_add(rhs)?;
+                    Ok(())
+                }
+
+                #[inline]
+                fn safe_sub_assign(&mut self, rhs: Self) -> ProgramResult {
+                    *self = self.safe_sub(rhs)?;
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_safe_math!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_math_reports_overflow() {
+        let mut balance: u64 = 10;
+        assert_eq!(balance.safe_sub_assign(4), Ok(()));
+        assert_eq!(balance, 6);
+        assert_eq!(balance.safe_sub_assign(7), Err(ProgramError::ArithmeticOverflow));
+        assert_eq!(balance, 6);
+
+        assert_eq!(u64::MAX.safe_add(1), Err(ProgramError::ArithmeticOverflow));
+        assert_eq!(u64::MAX.safe_mul(2), Err(ProgramError::ArithmeticOverflow));
+        assert_eq!(6u64.safe_div(0), Err(ProgramError::ArithmeticOverflow));
+        assert_eq!(6u64.safe_div(4), Ok(1));
+    }
+}
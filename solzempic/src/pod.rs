@@ -0,0 +1,86 @@
+//! `Pod`-safe field types for zero-copy account structs.
+//!
+//! `bool` is not `Pod` (only 0 and 1 are valid bit patterns), so flags in
+//! account layouts are usually stored as a raw `u8` and compared with `!= 0`.
+//! [`PodBool`] wraps that byte with a typed API.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use solzempic::{account, PodBool};
+//!
+//! #[account(discriminator = AccountType::Market)]
+//! pub struct Market {
+//!     pub paused: PodBool,
+//!     pub _padding: [u8; 7],
+//!     pub volume: u64,
+//! }
+//!
+//! if market.get().paused.is_true() {
+//!     return Err(ProgramError::InvalidAccountData);
+//! }
+//! market.get_mut().paused.set(true);
+//! ```
+
+use bytemuck::{Pod, Zeroable};
+
+/// A one-byte boolean usable in `Pod` account structs.
+///
+/// Any non-zero byte reads as `true`, so data written by other programs
+/// never fails to load; [`set`](Self::set) always writes 0 or 1.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable)]
+pub struct PodBool(pub u8);
+
+impl PodBool {
+    /// `false`, stored as 0.
+    pub const FALSE: Self = Self(0);
+
+    /// `true`, stored as 1.
+    pub const TRUE: Self = Self(1);
+
+    /// Whether the stored byte is non-zero.
+    #[inline]
+    pub const fn is_true(self) -> bool {
+        self.0 != 0
+    }
+
+    /// Store `value` as 0 or 1.
+    #[inline]
+    pub fn set(&mut self, value: bool) {
+        self.0 = value as u8;
+    }
+}
+
+impl From<bool> for PodBool {
+    #[inline]
+    fn from(value: bool) -> Self {
+        Self(value as u8)
+    }
+}
+
+impl From<PodBool> for bool {
+    #[inline]
+    fn from(value: PodBool) -> Self {
+        value.is_true()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pod_bool() {
+        assert_eq!(PodBool::from(true), PodBool::TRUE);
+        assert!(!bool::from(PodBool::default()));
+        // Bytes written elsewhere may be any non-zero value
+        assert!(bytemuck::cast::<u8, PodBool>(0xff).is_true());
+
+        let mut flag = PodBool(0xff);
+        flag.set(false);
+        assert_eq!(flag, PodBool::FALSE);
+        flag.set(true);
+        assert_eq!(bytemuck::bytes_of(&flag), &[1]);
+    }
+}
@@ -4,7 +4,7 @@
 //! Associated Token Account (ATA) program.
 
 use pinocchio::{AccountView, error::ProgramError};
-use solana_address::address_eq;
+use solana_address::{Address, address_eq};
 
 use super::ids::ASSOCIATED_TOKEN_PROGRAM_ID;
 use super::traits::ValidatedAccount;
@@ -74,3 +74,63 @@ impl<'a> ValidatedAccount<'a> for AtaProgram<'a> {
         self.info
     }
 }
+
+/// Derive the Associated Token Account address for `wallet` and `mint`.
+///
+/// Uses the standard seeds `[wallet, token_program, mint]` under
+/// [`ASSOCIATED_TOKEN_PROGRAM_ID`]. Pass the mint's owning program
+/// (SPL Token or Token-2022) as `token_program`; the two give different ATAs.
+///
+/// Returns the address and its bump seed.
+#[inline]
+pub fn derive_ata(wallet: &Address, mint: &Address, token_program: &Address) -> (Address, u8) {
+    Address::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+}
+
+/// Check that `account` is the ATA of `wallet` for `mint`, without a CPI.
+///
+/// # Errors
+///
+/// * [`ProgramError::InvalidSeeds`] - `account` is not at the derived ATA address
+#[inline]
+pub fn assert_is_ata(
+    account: &AccountView,
+    wallet: &Address,
+    mint: &Address,
+    token_program: &Address,
+) -> Result<(), ProgramError> {
+    let (expected, _) = derive_ata(wallet, mint, token_program);
+    if !address_eq(account.address(), &expected) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::AccountBuilder;
+    use crate::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+
+    use super::*;
+
+    #[test]
+    fn test_assert_is_ata() {
+        let wallet = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let (ata, _) = derive_ata(&wallet, &mint, &TOKEN_PROGRAM_ID);
+        let account = AccountBuilder::new().address(ata).build();
+
+        assert_eq!(assert_is_ata(account.info(), &wallet, &mint, &TOKEN_PROGRAM_ID), Ok(()));
+        assert_eq!(
+            assert_is_ata(account.info(), &wallet, &mint, &TOKEN_2022_PROGRAM_ID),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            assert_is_ata(account.info(), &mint, &wallet, &TOKEN_PROGRAM_ID),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+}
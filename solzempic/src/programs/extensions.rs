@@ -0,0 +1,238 @@
+//! Zero-copy readers for Token-2022 extensions.
+//!
+//! Token-2022 mints and token accounts store extensions as TLV entries after
+//! the base layout: the account type byte sits at offset 165 (mints are padded
+//! up to it), followed by `type: u16, length: u16, value` entries. This module
+//! finds an entry by its [`Extension::TYPE`] and casts the value in place.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use solzempic::{Mint, TransferFeeConfig, ValidatedAccount};
+//!
+//! let mint = Mint::wrap(&accounts[2])?;
+//! let received = match mint.extension::<TransferFeeConfig>() {
+//!     Some(config) => {
+//!         let fee = config.calculate_epoch_fee(epoch, amount).ok_or(ProgramError::ArithmeticOverflow)?;
+//!         amount - fee
+//!     }
+//!     None => amount,
+//! };
+//! ```
+
+use bytemuck::{Pod, Zeroable};
+
+/// Offset of the account type byte in Token-2022 accounts and mints.
+const ACCOUNT_TYPE_OFFSET: usize = 165;
+
+/// Account type byte of a Token-2022 mint.
+pub(crate) const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// Account type byte of a Token-2022 token account.
+pub(crate) const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// Basis points in 100%.
+const ONE_IN_BASIS_POINTS: u128 = 10_000;
+
+/// A Token-2022 extension that can be read in place from the TLV area.
+///
+/// Implementors must have alignment 1 (byte-array fields only), since TLV
+/// values are not aligned.
+pub trait Extension: Pod {
+    /// The `ExtensionType` discriminant identifying this extension's entry.
+    const TYPE: u16;
+}
+
+/// Find extension `E` in Token-2022 `data` of the given account type.
+///
+/// Returns `None` for legacy SPL Token data, a different account type, or
+/// a missing or truncated entry.
+pub(crate) fn find_extension<E: Extension>(data: &[u8], account_type: u8) -> Option<&E> {
+    if data.get(ACCOUNT_TYPE_OFFSET) != Some(&account_type) {
+        return None;
+    }
+    let mut offset = ACCOUNT_TYPE_OFFSET + 1;
+    while let Some(header) = data.get(offset..offset + 4) {
+        let ty = u16::from_le_bytes([header[0], header[1]]);
+        let len = u16::from_le_bytes([header[2], header[3]]) as usize;
+        // Type 0 (Uninitialized) marks the end of the written entries
+        if ty == 0 {
+            return None;
+        }
+        let value = data.get(offset + 4..offset + 4 + len)?;
+        if ty == E::TYPE {
+            return bytemuck::try_from_bytes(value.get(..core::mem::size_of::<E>())?).ok();
+        }
+        offset += 4 + len;
+    }
+    None
+}
+
+/// Transfer fee schedule for one epoch range.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct TransferFee {
+    /// First epoch where the fee applies.
+    pub epoch: [u8; 8],
+    /// Maximum fee per transfer, in raw token units.
+    pub maximum_fee: [u8; 8],
+    /// Fee in basis points of the transferred amount.
+    pub transfer_fee_basis_points: [u8; 2],
+}
+
+impl TransferFee {
+    /// First epoch where the fee applies.
+    #[inline]
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    /// Maximum fee per transfer, in raw token units.
+    #[inline]
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    /// Fee in basis points of the transferred amount.
+    #[inline]
+    pub fn basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+
+    /// Fee withheld from a transfer of `amount`, rounded up and capped at
+    /// [`maximum_fee`](Self::maximum_fee), as Token-2022 computes it.
+    ///
+    /// Returns `None` only on arithmetic overflow.
+    #[inline]
+    pub fn calculate_fee(&self, amount: u64) -> Option<u64> {
+        let basis_points = self.basis_points() as u128;
+        if basis_points == 0 || amount == 0 {
+            return Some(0);
+        }
+        let numerator = (amount as u128).checked_mul(basis_points)?;
+        let raw_fee = numerator.checked_add(ONE_IN_BASIS_POINTS - 1)? / ONE_IN_BASIS_POINTS;
+        Some(raw_fee.min(self.maximum_fee() as u128) as u64)
+    }
+}
+
+/// Mint extension configuring fees on every transfer (`ExtensionType` 1).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct TransferFeeConfig {
+    /// Authority that can change the fee (all zeros if none).
+    pub transfer_fee_config_authority: [u8; 32],
+    /// Authority that can withdraw withheld fees (all zeros if none).
+    pub withdraw_withheld_authority: [u8; 32],
+    /// Fees withheld on the mint itself.
+    pub withheld_amount: [u8; 8],
+    /// Fee in effect before `newer_transfer_fee.epoch`.
+    pub older_transfer_fee: TransferFee,
+    /// Fee in effect from its epoch onward.
+    pub newer_transfer_fee: TransferFee,
+}
+
+impl Extension for TransferFeeConfig {
+    const TYPE: u16 = 1;
+}
+
+impl TransferFeeConfig {
+    /// Fee schedule in effect at `epoch`.
+    #[inline]
+    pub fn get_epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+
+    /// Fee withheld from a transfer of `amount` at `epoch`.
+    ///
+    /// The recipient receives `amount - fee`. Returns `None` only on overflow.
+    #[inline]
+    pub fn calculate_epoch_fee(&self, epoch: u64, amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch).calculate_fee(amount)
+    }
+
+    /// Fees withheld on the mint itself.
+    #[inline]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+}
+
+/// Token account extension holding fees withheld from incoming transfers
+/// (`ExtensionType` 2).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct TransferFeeAmount {
+    /// Withheld fees, in raw token units.
+    pub withheld_amount: [u8; 8],
+}
+
+impl Extension for TransferFeeAmount {
+    const TYPE: u16 = 2;
+}
+
+impl TransferFeeAmount {
+    /// Withheld fees, in raw token units.
+    #[inline]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use super::*;
+
+    fn fee(epoch: u64, maximum_fee: u64, basis_points: u16) -> TransferFee {
+        TransferFee {
+            epoch: epoch.to_le_bytes(),
+            maximum_fee: maximum_fee.to_le_bytes(),
+            transfer_fee_basis_points: basis_points.to_le_bytes(),
+        }
+    }
+
+    /// Token-2022 mint data with a leading unrelated entry, then `config`.
+    fn mint_with_fee_config(config: &TransferFeeConfig) -> Vec<u8> {
+        let mut data = vec![0u8; ACCOUNT_TYPE_OFFSET];
+        data.push(ACCOUNT_TYPE_MINT);
+        // MintCloseAuthority (type 3, 32 bytes)
+        data.extend_from_slice(&3u16.to_le_bytes());
+        data.extend_from_slice(&32u16.to_le_bytes());
+        data.extend_from_slice(&[7u8; 32]);
+        data.extend_from_slice(&TransferFeeConfig::TYPE.to_le_bytes());
+        data.extend_from_slice(&(core::mem::size_of::<TransferFeeConfig>() as u16).to_le_bytes());
+        data.extend_from_slice(bytemuck::bytes_of(config));
+        data
+    }
+
+    #[test]
+    fn test_find_transfer_fee_config() {
+        let config = TransferFeeConfig {
+            transfer_fee_config_authority: [0; 32],
+            withdraw_withheld_authority: [0; 32],
+            withheld_amount: 0u64.to_le_bytes(),
+            older_transfer_fee: fee(0, 1_000, 50),
+            newer_transfer_fee: fee(10, 5, 100),
+        };
+        assert_eq!(core::mem::size_of::<TransferFeeConfig>(), 108);
+
+        let data = mint_with_fee_config(&config);
+        let found = find_extension::<TransferFeeConfig>(&data, ACCOUNT_TYPE_MINT).unwrap();
+        // 0.5% of 1001, rounded up
+        assert_eq!(found.calculate_epoch_fee(9, 1_001), Some(6));
+        // Capped at the newer maximum
+        assert_eq!(found.calculate_epoch_fee(10, 1_001), Some(5));
+
+        assert!(find_extension::<TransferFeeConfig>(&data, ACCOUNT_TYPE_ACCOUNT).is_none());
+        assert!(find_extension::<TransferFeeAmount>(&data, ACCOUNT_TYPE_MINT).is_none());
+        assert!(find_extension::<TransferFeeConfig>(&data[..82], ACCOUNT_TYPE_MINT).is_none());
+        assert!(find_extension::<TransferFeeConfig>(&data[..data.len() - 1], ACCOUNT_TYPE_MINT).is_none());
+    }
+}
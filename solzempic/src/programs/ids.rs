@@ -23,6 +23,8 @@
 //! | [`SLOT_HASHES_SYSVAR_ID`] | SlotHashes | Recent slot hashes |
 //! | [`INSTRUCTIONS_SYSVAR_ID`] | Instructions | Transaction introspection |
 //! | [`RECENT_BLOCKHASHES_SYSVAR_ID`] | RecentBlockhashes | Recent blockhashes |
+//! | [`EPOCH_SCHEDULE_SYSVAR_ID`] | EpochSchedule | Epoch length and warmup parameters |
+//! | [`EPOCH_REWARDS_SYSVAR_ID`] | EpochRewards | Partitioned rewards distribution status |
 //!
 //! # Example
 //!
@@ -214,3 +216,29 @@ pub const RECENT_BLOCKHASHES_SYSVAR_ID: Address =
 /// Address: `SysvarLastRestartS1ot1111111111111111111111`
 pub const LAST_RESTART_SLOT_SYSVAR_ID: Address =
     Address::new_from_array(pinocchio_pubkey::pubkey!("SysvarLastRestartS1ot1111111111111111111111"));
+
+/// The EpochSchedule sysvar address.
+///
+/// The EpochSchedule sysvar describes how slots map to epochs:
+/// - `slots_per_epoch`: Number of slots in each normal epoch
+/// - `leader_schedule_slot_offset`: Slots before an epoch its leader schedule is computed
+/// - `warmup`: Whether epochs start short and grow to `slots_per_epoch`
+/// - `first_normal_epoch` / `first_normal_slot`: Where warmup ends
+///
+/// Useful for programs that compute epoch boundaries from slot numbers.
+///
+/// Address: `SysvarEpochSchedu1e111111111111111111111111`
+pub const EPOCH_SCHEDULE_SYSVAR_ID: Address =
+    Address::new_from_array(pinocchio_pubkey::pubkey!("SysvarEpochSchedu1e111111111111111111111111"));
+
+/// The EpochRewards sysvar address.
+///
+/// The EpochRewards sysvar tracks partitioned staking rewards distribution:
+/// - Whether rewards distribution is currently active
+/// - Total and distributed reward amounts for the epoch
+///
+/// Useful for staking programs that must avoid acting mid-distribution.
+///
+/// Address: `SysvarEpochRewards1111111111111111111111111`
+pub const EPOCH_REWARDS_SYSVAR_ID: Address =
+    Address::new_from_array(pinocchio_pubkey::pubkey!("SysvarEpochRewards1111111111111111111111111"));
@@ -1,13 +1,26 @@
 //! Address Lookup Table account wrapper.
 //!
 //! This module provides [`Lut`], a wrapper for Address Lookup Table accounts
-//! that handles both initialized and uninitialized states.
+//! that handles both initialized and uninitialized states, and can create,
+//! extend, deactivate and close the table through the ALT program.
 
-use pinocchio::{AccountView, error::ProgramError};
+use pinocchio::{
+    error::ProgramError,
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
 use solana_address::{Address, address_eq};
 
+use crate::invoke_as_pda;
+
 use super::ids::{ADDRESS_LOOKUP_TABLE_PROGRAM_ID, SYSTEM_PROGRAM_ID};
 
+/// Most addresses accepted by one [`Lut::extend`] call.
+///
+/// A transaction can't carry many more 32-byte addresses anyway; larger
+/// sets must be added over several transactions.
+pub const MAX_EXTEND_ADDRESSES: usize = 30;
+
 /// Address Lookup Table account wrapper.
 ///
 /// `Lut` wraps a lookup table account, handling both initialized (active)
@@ -27,20 +40,30 @@ use super::ids::{ADDRESS_LOOKUP_TABLE_PROGRAM_ID, SYSTEM_PROGRAM_ID};
 /// ```ignore
 /// use solzempic::Lut;
 ///
-/// fn ensure_lut_exists<'a>(accounts: &'a [AccountInfo]) -> ProgramResult {
+/// fn ensure_lut_exists(accounts: &[AccountView], authority_bump: u8, slot: u64) -> ProgramResult {
 ///     let lut = Lut::wrap(&accounts[0])?;
+///     let authority = &accounts[1];
+///     let system_program = &accounts[2];
+///     let market = &accounts[3];
+///     let bump = [authority_bump];
+///     let seeds: &[&[u8]] = &[b"lut_authority", &bump];
 ///
-///     if lut.needs_init() {
-///         // Create the lookup table via CPI
-///         create_lookup_table(...)?;
-///     } else {
-///         // LUT already exists, can extend or use it
-///     }
-///
+///     // No-op if the table already exists
+///     lut.create(authority, authority, system_program, slot, seeds)?;
+///     lut.extend(authority, authority, system_program, &[*market.address()], seeds)?;
 ///     Ok(())
 /// }
 /// ```
 ///
+/// Tearing a table down takes two transactions, since a table can only be
+/// closed once its deactivation slot has left the `SlotHashes` sysvar:
+///
+/// ```ignore
+/// lut.deactivate(authority, seeds)?;
+/// // ...at least ~513 slots later:
+/// lut.close(authority, recipient, seeds)?;
+/// ```
+///
 /// # When to Use
 ///
 /// Use `Lut` for:
@@ -116,4 +139,226 @@ impl<'a> Lut<'a> {
     pub fn needs_init(&self) -> bool {
         !self.initialized
     }
+
+    /// Derive a lookup table address from its authority and recent slot.
+    ///
+    /// Returns the address and bump, as the ALT program derives them.
+    #[inline]
+    pub fn derive_address(authority: &Address, recent_slot: u64) -> (Address, u8) {
+        Address::find_program_address(
+            &[authority.as_ref(), &recent_slot.to_le_bytes()],
+            &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+        )
+    }
+
+    /// Create the lookup table via the ALT program's `CreateLookupTable`.
+    ///
+    /// Does nothing if the account is already owned by the ALT program, so
+    /// it can be called unconditionally. `recent_slot` must be a slot from
+    /// the `SlotHashes` sysvar, and this account must be the table derived
+    /// from `authority` and `recent_slot` (see [`derive_address`](Self::derive_address)).
+    ///
+    /// `seeds` sign for `payer` when it is a PDA of the calling program;
+    /// pass `&[]` when it signed the transaction.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidSeeds`] - Wrong table address, or too many seeds
+    /// * ALT program errors - Slot too old, insufficient funds, etc.
+    pub fn create(
+        &self,
+        authority: &AccountView,
+        payer: &AccountView,
+        system_program: &AccountView,
+        recent_slot: u64,
+        seeds: &[&[u8]],
+    ) -> ProgramResult {
+        if address_eq(unsafe { self.info.owner() }, &ADDRESS_LOOKUP_TABLE_PROGRAM_ID) {
+            return Ok(());
+        }
+        let (expected, bump) = Self::derive_address(authority.address(), recent_slot);
+        if !address_eq(self.info.address(), &expected) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        // CreateLookupTable = 0, then recent_slot: u64, bump_seed: u8
+        let mut instruction_data = [0u8; 13];
+        instruction_data[4..12].copy_from_slice(&recent_slot.to_le_bytes());
+        instruction_data[12] = bump;
+
+        let account_metas = [
+            InstructionAccount { address: self.info.address(), is_writable: true, is_signer: false },
+            InstructionAccount { address: authority.address(), is_writable: false, is_signer: false },
+            InstructionAccount { address: payer.address(), is_writable: true, is_signer: true },
+            InstructionAccount { address: system_program.address(), is_writable: false, is_signer: false },
+        ];
+        let instruction = InstructionView {
+            program_id: &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_as_pda(&instruction, &[self.info, authority, payer, system_program], seeds)
+    }
+
+    /// Append addresses via the ALT program's `ExtendLookupTable`.
+    ///
+    /// `authority` must sign; `payer` funds the extra rent. `seeds` sign for
+    /// whichever of them is a PDA of the calling program (typically the same
+    /// PDA is both); pass `&[]` when they signed the transaction.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidArgument`] - Empty `addresses`, or more than [`MAX_EXTEND_ADDRESSES`]
+    /// * [`ProgramError::InvalidSeeds`] - Too many seeds
+    /// * ALT program errors - Wrong authority, deactivated table, table full, etc.
+    pub fn extend(
+        &self,
+        authority: &AccountView,
+        payer: &AccountView,
+        system_program: &AccountView,
+        addresses: &[Address],
+        seeds: &[&[u8]],
+    ) -> ProgramResult {
+        if addresses.is_empty() || addresses.len() > MAX_EXTEND_ADDRESSES {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // ExtendLookupTable = 2, then new_addresses: Vec<Address> (u64 length prefix)
+        let mut instruction_data = [0u8; 12 + 32 * MAX_EXTEND_ADDRESSES];
+        instruction_data[0..4].copy_from_slice(&2u32.to_le_bytes());
+        instruction_data[4..12].copy_from_slice(&(addresses.len() as u64).to_le_bytes());
+        for (chunk, address) in instruction_data[12..].chunks_exact_mut(32).zip(addresses) {
+            chunk.copy_from_slice(address.as_ref());
+        }
+        let data_len = 12 + 32 * addresses.len();
+
+        let account_metas = [
+            InstructionAccount { address: self.info.address(), is_writable: true, is_signer: false },
+            InstructionAccount { address: authority.address(), is_writable: false, is_signer: true },
+            InstructionAccount { address: payer.address(), is_writable: true, is_signer: true },
+            InstructionAccount { address: system_program.address(), is_writable: false, is_signer: false },
+        ];
+        let instruction = InstructionView {
+            program_id: &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+            accounts: &account_metas,
+            data: &instruction_data[..data_len],
+        };
+
+        invoke_as_pda(&instruction, &[self.info, authority, payer, system_program], seeds)
+    }
+
+    /// Deactivate the table via the ALT program's `DeactivateLookupTable`.
+    ///
+    /// A deactivated table can no longer be extended or used by new
+    /// transactions, and can be [`close`](Self::close)d once the
+    /// deactivation slot is no longer in the `SlotHashes` sysvar.
+    ///
+    /// `seeds` sign for `authority` when it is a PDA of the calling program;
+    /// pass `&[]` when it signed the transaction.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::UninitializedAccount`] - The table hasn't been created
+    /// * [`ProgramError::InvalidSeeds`] - Too many seeds
+    /// * ALT program errors - Wrong authority, already deactivated, etc.
+    pub fn deactivate(&self, authority: &AccountView, seeds: &[&[u8]]) -> ProgramResult {
+        self.require_created()?;
+
+        // DeactivateLookupTable = 3
+        let instruction_data = 3u32.to_le_bytes();
+
+        let account_metas = [
+            InstructionAccount { address: self.info.address(), is_writable: true, is_signer: false },
+            InstructionAccount { address: authority.address(), is_writable: false, is_signer: true },
+        ];
+        let instruction = InstructionView {
+            program_id: &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_as_pda(&instruction, &[self.info, authority], seeds)
+    }
+
+    /// Close a deactivated table via the ALT program's `CloseLookupTable`,
+    /// sending its rent lamports to `recipient`.
+    ///
+    /// `seeds` sign for `authority` when it is a PDA of the calling program;
+    /// pass `&[]` when it signed the transaction.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::UninitializedAccount`] - The table hasn't been created
+    /// * [`ProgramError::InvalidSeeds`] - Too many seeds
+    /// * ALT program errors - Wrong authority, not deactivated, deactivation too recent, etc.
+    pub fn close(&self, authority: &AccountView, recipient: &AccountView, seeds: &[&[u8]]) -> ProgramResult {
+        self.require_created()?;
+
+        // CloseLookupTable = 4
+        let instruction_data = 4u32.to_le_bytes();
+
+        let account_metas = [
+            InstructionAccount { address: self.info.address(), is_writable: true, is_signer: false },
+            InstructionAccount { address: authority.address(), is_writable: false, is_signer: true },
+            InstructionAccount { address: recipient.address(), is_writable: true, is_signer: false },
+        ];
+        let instruction = InstructionView {
+            program_id: &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_as_pda(&instruction, &[self.info, authority, recipient], seeds)
+    }
+
+    /// Fail unless the account is owned by the ALT program.
+    #[inline]
+    fn require_created(&self) -> ProgramResult {
+        if !address_eq(unsafe { self.info.owner() }, &ADDRESS_LOOKUP_TABLE_PROGRAM_ID) {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::AccountBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_create_skips_existing_table_and_checks_address() {
+        let authority = AccountBuilder::new().address(Address::new_from_array([3u8; 32])).build();
+        let system = AccountBuilder::new().address(SYSTEM_PROGRAM_ID).build();
+
+        let existing = AccountBuilder::new().owner(ADDRESS_LOOKUP_TABLE_PROGRAM_ID).data(&[1]).build();
+        let lut = Lut::wrap(existing.info()).unwrap();
+        assert_eq!(lut.create(authority.info(), authority.info(), system.info(), 42, &[]), Ok(()));
+
+        let wrong = AccountBuilder::new().owner(SYSTEM_PROGRAM_ID).build();
+        let lut = Lut::wrap(wrong.info()).unwrap();
+        assert_eq!(
+            lut.create(authority.info(), authority.info(), system.info(), 42, &[]),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            lut.extend(authority.info(), authority.info(), system.info(), &[], &[]),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_deactivate_and_close_require_created_table() {
+        let authority = AccountBuilder::new().address(Address::new_from_array([3u8; 32])).build();
+        let pending = AccountBuilder::new().owner(SYSTEM_PROGRAM_ID).build();
+        let lut = Lut::wrap(pending.info()).unwrap();
+
+        assert_eq!(lut.deactivate(authority.info(), &[]), Err(ProgramError::UninitializedAccount));
+        assert_eq!(
+            lut.close(authority.info(), authority.info(), &[]),
+            Err(ProgramError::UninitializedAccount)
+        );
+    }
 }
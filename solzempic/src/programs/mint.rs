@@ -3,9 +3,10 @@
 //! This module provides [`Mint`], a validated wrapper for SPL Token mint
 //! accounts that provides convenient access to mint metadata.
 
-use pinocchio::{AccountView, error::ProgramError};
+use pinocchio::{AccountView, ProgramResult, error::ProgramError};
 use solana_address::{Address, address_eq};
 
+use super::extensions::{find_extension, Extension, ACCOUNT_TYPE_MINT};
 use super::ids::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
 use super::traits::ValidatedAccount;
 
@@ -27,7 +28,8 @@ use super::traits::ValidatedAccount;
 /// | 46 | 4 | freeze_authority COption discriminant |
 /// | 50 | 32 | freeze_authority Pubkey |
 ///
-/// Token-2022 mints may have extensions after byte 82.
+/// Token-2022 mints may have extensions after byte 82; read them with
+/// [`extension`](Self::extension).
 ///
 /// # Example
 ///
@@ -203,6 +205,22 @@ impl<'a> Mint<'a> {
         data[Self::DECIMALS_OFFSET]
     }
 
+    /// Check that the mint has `expected` decimals.
+    ///
+    /// Use this to pin the precision assumed by price or amount math, so a
+    /// look-alike mint with different decimals is rejected.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidArgument`] - Decimals differ from `expected`
+    #[inline]
+    pub fn assert_decimals(&self, expected: u8) -> ProgramResult {
+        if self.decimals() != expected {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
     /// Check if this is a Token-2022 mint.
     ///
     /// Returns `true` if the mint is owned by the Token-2022 program,
@@ -214,6 +232,23 @@ impl<'a> Mint<'a> {
     pub fn is_token_2022(&self) -> bool {
         address_eq(unsafe { self.info.owner() }, &TOKEN_2022_PROGRAM_ID)
     }
+
+    /// Read a Token-2022 extension in place, e.g. [`TransferFeeConfig`](super::TransferFeeConfig).
+    ///
+    /// Returns `None` for SPL Token mints and mints without the extension.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(config) = mint.extension::<TransferFeeConfig>() {
+    ///     let fee = config.calculate_epoch_fee(clock.epoch, amount).ok_or(ProgramError::ArithmeticOverflow)?;
+    /// }
+    /// ```
+    #[inline]
+    pub fn extension<E: Extension>(&self) -> Option<&'a E> {
+        let data = unsafe { self.info.borrow_unchecked() };
+        find_extension(data, ACCOUNT_TYPE_MINT)
+    }
 }
 
 #[cfg(test)]
@@ -264,6 +299,19 @@ mod tests {
         assert!(data.get(44).is_none(), "Should not be able to access decimals offset");
     }
 
+    #[test]
+    fn test_assert_decimals() {
+        let data = create_valid_mint_data(6);
+        let account = crate::test_utils::AccountBuilder::new()
+            .owner(TOKEN_PROGRAM_ID)
+            .data(&data)
+            .build();
+        let mint = Mint::wrap(account.info()).unwrap();
+
+        assert_eq!(mint.assert_decimals(6), Ok(()));
+        assert_eq!(mint.assert_decimals(9), Err(ProgramError::InvalidArgument));
+    }
+
     #[test]
     fn test_mint_offsets_are_correct() {
         // Verify our offset constants match the SPL Token layout
@@ -21,6 +21,7 @@
 pub mod ids;
 mod alt;
 mod ata;
+mod extensions;
 mod lut;
 mod mint;
 mod signer;
@@ -37,17 +38,19 @@ pub use ids::*;
 
 // Re-export wrappers
 pub use alt::AltProgram;
-pub use ata::AtaProgram;
-pub use lut::Lut;
+pub use ata::{assert_is_ata, derive_ata, AtaProgram};
+pub use extensions::{Extension, TransferFee, TransferFeeAmount, TransferFeeConfig};
+pub use lut::{Lut, MAX_EXTEND_ADDRESSES};
 pub use mint::Mint;
-pub use signer::{MutSigner, Payer, ReadOnly, Signer, Writable};
+pub use signer::{MutSigner, Payer, ProgramSigner, ReadOnly, Signer, Writable};
 pub use system::SystemProgram;
-pub use sysvars::{ClockSysvar, InstructionsSysvar, LastRestartSlot, LastRestartSlotSysvar, RecentBlockhashesSysvar, RentSysvar, SlotHashesSysvar};
-pub use token_account::{TokenAccountData, TokenAccountRefMut};
+pub use sysvars::{ClockSysvar, EpochRewardsSysvar, EpochSchedule, EpochScheduleSysvar, InstructionsSysvar, IntrospectedAccount, IntrospectedIx, LastRestartSlot, LastRestartSlotSysvar, RecentBlockhashesSysvar, RentSysvar, SlotHashesSysvar};
+pub use token_account::{assert_same_mint, TokenAccountData, TokenAccountRef, TokenAccountRefMut};
 pub use token_program::TokenProgram;
 pub use traits::ValidatedAccount;
-pub use vault::{SolVault, Vault};
+pub use vault::{SolVault, Vault, VaultMut};
 pub use validation::{
-    validate_clock_sysvar, validate_rent_sysvar, validate_slot_hashes_sysvar,
-    validate_system_program, validate_token_program,
+    assert_one_of, validate_clock_sysvar, validate_epoch_rewards_sysvar, validate_epoch_schedule_sysvar,
+    validate_rent_sysvar, validate_slot_hashes_sysvar, validate_system_program,
+    validate_key_one_of, validate_owner_one_of, validate_token_program,
 };
@@ -2,11 +2,14 @@
 //!
 //! This module provides [`Signer`] and [`Payer`] types for validated
 //! signer accounts. These ensure that an account has actually signed
-//! the transaction.
+//! the transaction. [`ProgramSigner`] covers PDAs that sign CPIs through
+//! `invoke_signed` instead.
 
 use pinocchio::{AccountView, error::ProgramError};
+use solana_address::{Address, address_eq};
 
 use super::traits::ValidatedAccount;
+use crate::{Seeds, MAX_SEEDS};
 
 /// Validated signer account wrapper.
 ///
@@ -105,6 +108,57 @@ impl<'a> Signer<'a> {
     pub fn key(&self) -> &solana_address::Address {
         self.info.address()
     }
+
+    /// Validate a signer, additionally requiring writability when `writable` is set.
+    ///
+    /// Use this for authorities that only need to be writable on some code
+    /// paths (e.g., when they receive lamports back from a close), where
+    /// choosing between `Signer` and [`MutSigner`] at the type level doesn't fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::MissingRequiredSignature`] if not a signer.
+    /// Returns [`ProgramError::InvalidAccountData`] if `writable` is set and
+    /// the account is not writable.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Authority only needs to be writable when it receives the refund
+    /// let authority = Signer::wrap_writable_if(&accounts[0], params.refund != 0)?;
+    /// ```
+    #[inline]
+    pub fn wrap_writable_if(info: &'a AccountView, writable: bool) -> Result<Self, ProgramError> {
+        let signer = Self::wrap(info)?;
+        if writable && !info.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(signer)
+    }
+
+    /// Check whether this signer is the PDA of `program_id` derived from `seeds`.
+    ///
+    /// For cross-program authorization, where another program signs into
+    /// this one with its PDA (via `invoke_signed`). `seeds` excludes the
+    /// bump; the canonical bump is used.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let authority = Signer::wrap(&accounts[0])?;
+    /// if !authority.is_pda_of(&[b"vault_authority"], &PARTNER_PROGRAM_ID) {
+    ///     return Err(ProgramError::InvalidSeeds);
+    /// }
+    /// ```
+    ///
+    /// # Performance
+    ///
+    /// Runs `find_program_address` (roughly 1,500 CUs per bump attempted).
+    #[inline]
+    pub fn is_pda_of(&self, seeds: &[&[u8]], program_id: &Address) -> bool {
+        let (expected, _) = Address::find_program_address(seeds, program_id);
+        address_eq(self.info.address(), &expected)
+    }
 }
 
 /// Type alias for payer accounts.
@@ -193,6 +247,89 @@ impl<'a> MutSigner<'a> {
     }
 }
 
+/// PDA of this program that will sign a CPI via `invoke_signed`.
+///
+/// A PDA is passed to the instruction with `is_signer == false`, so
+/// [`Signer::wrap`] rejects it even though it can sign a CPI. `ProgramSigner`
+/// checks instead that the account is the PDA derived from the given seeds,
+/// and keeps those seeds (with the bump appended) for the later CPI.
+///
+/// # Example
+///
+/// ```ignore
+/// use solzempic::ProgramSigner;
+///
+/// let authority = ProgramSigner::wrap(&accounts[2], &ID, &[b"vault_authority", market.as_ref()])?;
+/// transfer_lamports_signed(authority.info(), recipient, amount, authority.seeds())?;
+/// ```
+///
+/// # Performance
+///
+/// [`wrap`](Self::wrap) runs `find_program_address`, which costs far more
+/// than a signer flag check (roughly 1,500 CUs per bump attempted).
+///
+/// # Shank IDL
+///
+/// In Shank IDL generation, `ProgramSigner` is a readonly, non-signer account.
+pub struct ProgramSigner<'a> {
+    info: &'a AccountView,
+    seeds: Seeds<'a>,
+    bump: u8,
+}
+
+impl<'a> ProgramSigner<'a> {
+    /// Validate that the account is the PDA of `program_id` derived from `seeds`.
+    ///
+    /// `seeds` excludes the bump; the canonical bump is found and appended.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidSeeds`] if the address doesn't match,
+    /// or if there is no room left for the bump seed.
+    #[inline]
+    pub fn wrap(info: &'a AccountView, program_id: &Address, seeds: &[&'a [u8]]) -> Result<Self, ProgramError> {
+        if seeds.len() >= MAX_SEEDS {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        let (expected, bump) = Address::find_program_address(seeds, program_id);
+        if !address_eq(info.address(), &expected) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        let seeds = seeds.iter().fold(Seeds::new(), |list, &seed| list.push(seed)).with_bump(bump);
+        Ok(Self { info, seeds, bump })
+    }
+
+    /// Get the underlying AccountView.
+    #[inline]
+    pub fn info(&self) -> &'a AccountView {
+        self.info
+    }
+
+    /// Returns the account's public key.
+    #[inline]
+    pub fn address(&self) -> &Address {
+        self.info.address()
+    }
+
+    /// Alias for address() - returns the account's public key.
+    #[inline]
+    pub fn key(&self) -> &Address {
+        self.info.address()
+    }
+
+    /// The canonical bump found at validation.
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// Signer seeds for `invoke_signed`, with the bump as the last seed.
+    #[inline]
+    pub fn seeds(&self) -> &[&'a [u8]] {
+        self.seeds.as_slice()
+    }
+}
+
 /// Explicit wrapper for writable raw account references.
 ///
 /// Use `Writable` when you have a raw `&AccountView` that needs to be
@@ -294,3 +431,87 @@ impl<'a> ReadOnly<'a> {
         self.info.address()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{AccountBuilder, TestAccount};
+
+    use super::*;
+
+    fn test_account(is_signer: bool, is_writable: bool) -> TestAccount {
+        AccountBuilder::new().signer(is_signer).writable(is_writable).build()
+    }
+
+    #[test]
+    fn test_wrap_writable_if_false_accepts_readonly_signer() {
+        let account = test_account(true, false);
+        assert!(Signer::wrap_writable_if(account.info(), false).is_ok());
+    }
+
+    #[test]
+    fn test_wrap_writable_if_true_rejects_readonly_signer() {
+        let account = test_account(true, false);
+        assert_eq!(
+            Signer::wrap_writable_if(account.info(), true).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_wrap_writable_if_true_accepts_writable_signer() {
+        let account = test_account(true, true);
+        assert!(Signer::wrap_writable_if(account.info(), true).is_ok());
+    }
+
+    #[test]
+    fn test_program_signer_checks_derivation() {
+        let program_id = Address::new_from_array([7u8; 32]);
+        let (pda, bump) = Address::find_program_address(&[b"authority"], &program_id);
+        let account = AccountBuilder::new().address(pda).build();
+
+        let signer = ProgramSigner::wrap(account.info(), &program_id, &[b"authority"]).unwrap();
+        assert_eq!(signer.bump(), bump);
+        assert_eq!(signer.seeds(), &[&b"authority"[..], &[bump]]);
+
+        assert_eq!(
+            ProgramSigner::wrap(account.info(), &program_id, &[b"other"]).err(),
+            Some(ProgramError::InvalidSeeds)
+        );
+        assert!(Signer::wrap(account.info()).is_err());
+    }
+
+    #[test]
+    fn test_signer_is_pda_of() {
+        let program_id = Address::new_from_array([7u8; 32]);
+        let (pda, _) = Address::find_program_address(&[b"authority"], &program_id);
+        let account = AccountBuilder::new().address(pda).signer(true).build();
+        let signer = Signer::wrap(account.info()).unwrap();
+
+        assert!(signer.is_pda_of(&[b"authority"], &program_id));
+        assert!(!signer.is_pda_of(&[b"other"], &program_id));
+        assert!(!signer.is_pda_of(&[b"authority"], &Address::new_from_array([8u8; 32])));
+    }
+
+    #[test]
+    fn test_key_eq_compares_address() {
+        let address = Address::new_from_array([3u8; 32]);
+        let account = AccountBuilder::new().address(address).signer(true).build();
+        let signer = Signer::wrap(account.info()).unwrap();
+
+        assert!(signer.key_eq(&address));
+        assert!(!signer.key_eq(&Address::new_from_array([4u8; 32])));
+    }
+
+    #[test]
+    fn test_wrap_writable_if_requires_signature() {
+        let account = test_account(false, true);
+        assert_eq!(
+            Signer::wrap_writable_if(account.info(), false).err(),
+            Some(ProgramError::MissingRequiredSignature)
+        );
+        assert_eq!(
+            Signer::wrap_writable_if(account.info(), true).err(),
+            Some(ProgramError::MissingRequiredSignature)
+        );
+    }
+}
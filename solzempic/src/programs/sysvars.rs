@@ -10,30 +10,38 @@
 //! | [`ClockSysvar`] | Current slot, timestamp, epoch |
 //! | [`RentSysvar`] | Rent calculation parameters |
 //! | [`SlotHashesSysvar`] | Recent slot hashes (large!) |
-//! | [`InstructionsSysvar`] | Transaction introspection |
+//! | [`InstructionsSysvar`] | Transaction introspection (see [`IntrospectedIx`]) |
 //! | [`RecentBlockhashesSysvar`] | Recent blockhashes (deprecated) |
+//! | [`EpochScheduleSysvar`] | Epoch length and warmup parameters |
+//! | [`EpochRewardsSysvar`] | Partitioned rewards distribution status |
 //!
 //! # Example
 //!
 //! ```ignore
 //! use solzempic::{ValidatedAccount, ClockSysvar};
 //!
-//! fn check_expiration(accounts: &[AccountInfo]) -> ProgramResult {
+//! fn check_expiration(accounts: &[AccountInfo], order: &Order) -> ProgramResult {
 //!     let clock = ClockSysvar::wrap(&accounts[0])?;
-//!     let current_time = unsafe { *(clock.info().data_ptr() as *const i64) };
-//!     // ... check order expiration
+//!     clock.assert_not_expired(order.expires_at)?;
 //!     Ok(())
 //! }
 //! ```
 //!
 //! # Direct Sysvar Access
 //!
-//! For sysvars that support it, you can also use `solana_program`'s
-//! `Sysvar::get()` method which doesn't require an account. However,
-//! this uses more compute units.
+//! [`ClockSysvar::get_via_syscall`], [`RentSysvar::get_via_syscall`], and
+//! [`LastRestartSlotSysvar::get_via_syscall`] read the sysvar through a
+//! syscall instead, so the account doesn't have to be passed at all:
+//!
+//! ```ignore
+//! let clock = ClockSysvar::get_via_syscall()?;
+//! if clock.unix_timestamp > order.expires_at {
+//!     return Err(ProgramError::InvalidArgument);
+//! }
+//! ```
 
 use pinocchio::{AccountView, error::ProgramError};
-use solana_address::address_eq;
+use solana_address::{Address, address_eq};
 
 use super::ids::*;
 use super::traits::ValidatedAccount;
@@ -89,14 +97,136 @@ impl<'a> ClockSysvar<'a> {
     pub fn get(&self) -> Result<pinocchio::account::Ref<'a, pinocchio::sysvars::clock::Clock>, ProgramError> {
         pinocchio::sysvars::clock::Clock::from_account_view(self.info)
     }
+
+    /// Get the Clock directly via syscall (no account needed).
+    ///
+    /// Saves an account slot in the transaction. Off-chain this returns a
+    /// zeroed Clock.
+    #[inline]
+    pub fn get_via_syscall() -> Result<pinocchio::sysvars::clock::Clock, ProgramError> {
+        let mut var = core::mem::MaybeUninit::<pinocchio::sysvars::clock::Clock>::zeroed();
+        let var_addr = var.as_mut_ptr() as *mut u8;
+
+        #[cfg(target_os = "solana")]
+        let result = unsafe { pinocchio::syscalls::sol_get_clock_sysvar(var_addr) };
+
+        #[cfg(not(target_os = "solana"))]
+        let result = {
+            let _ = var_addr;
+            0u64 // Return success for non-solana targets
+        };
+
+        match result {
+            // Safety: written by the syscall, or zeroed (all-integer fields)
+            0 => Ok(unsafe { var.assume_init() }),
+            _ => Err(ProgramError::UnsupportedSysvar),
+        }
+    }
+
+    /// Get the current slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if the data is too short.
+    #[inline]
+    pub fn slot(&self) -> Result<u64, ProgramError> {
+        self.read_word(CLOCK_SLOT_OFFSET).map(u64::from_le_bytes)
+    }
+
+    /// Get the estimated current Unix timestamp, in seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if the data is too short.
+    #[inline]
+    pub fn unix_timestamp(&self) -> Result<i64, ProgramError> {
+        self.read_word(CLOCK_UNIX_TIMESTAMP_OFFSET).map(i64::from_le_bytes)
+    }
+
+    /// Check whether the Unix timestamp `deadline` has passed.
+    ///
+    /// A deadline is still valid during its own second: this returns `true`
+    /// only once `unix_timestamp > deadline`.
+    #[inline]
+    pub fn is_expired(&self, deadline: i64) -> Result<bool, ProgramError> {
+        Ok(self.unix_timestamp()? > deadline)
+    }
+
+    /// Require that the Unix timestamp `deadline` has not passed.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidArgument`] - `unix_timestamp > deadline`
+    /// * [`ProgramError::InvalidAccountData`] - Clock data too short
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let clock = ClockSysvar::wrap(&accounts[4])?;
+    /// clock.assert_not_expired(order.expires_at)?;
+    /// ```
+    #[inline]
+    pub fn assert_not_expired(&self, deadline: i64) -> Result<(), ProgramError> {
+        if self.is_expired(deadline)? {
+            return Err(crate::errors::expired());
+        }
+        Ok(())
+    }
+
+    /// Check whether the current slot is strictly after `slot`.
+    #[inline]
+    pub fn is_past_slot(&self, slot: u64) -> Result<bool, ProgramError> {
+        Ok(self.slot()? > slot)
+    }
+
+    #[inline]
+    fn read_word(&self, offset: usize) -> Result<[u8; 8], ProgramError> {
+        let data = unsafe { self.info.borrow_unchecked() };
+        let bytes = data.get(offset..offset + 8).ok_or(ProgramError::InvalidAccountData)?;
+        Ok(bytes.try_into().unwrap())
+    }
 }
 
+/// Byte offset of `slot` in the Clock sysvar data.
+const CLOCK_SLOT_OFFSET: usize = 0;
+
+/// Byte offset of `unix_timestamp` in the Clock sysvar data, after `slot`,
+/// `epoch_start_timestamp`, `epoch`, and `leader_schedule_epoch`.
+const CLOCK_UNIX_TIMESTAMP_OFFSET: usize = 32;
+
 define_sysvar!(
     RentSysvar,
     RENT_SYSVAR_ID,
     "Validated Rent sysvar account.\n\nProvides rent calculation parameters.\nUseful for computing rent-exempt minimum balances."
 );
 
+impl RentSysvar<'_> {
+    /// Get the Rent parameters directly via syscall (no account needed).
+    ///
+    /// Saves an account slot in the transaction. Off-chain this returns
+    /// zeroed parameters.
+    #[inline]
+    pub fn get_via_syscall() -> Result<pinocchio::sysvars::rent::Rent, ProgramError> {
+        let mut var = core::mem::MaybeUninit::<pinocchio::sysvars::rent::Rent>::zeroed();
+        let var_addr = var.as_mut_ptr() as *mut u8;
+
+        #[cfg(target_os = "solana")]
+        let result = unsafe { pinocchio::syscalls::sol_get_rent_sysvar(var_addr) };
+
+        #[cfg(not(target_os = "solana"))]
+        let result = {
+            let _ = var_addr;
+            0u64 // Return success for non-solana targets
+        };
+
+        match result {
+            // Safety: written by the syscall, or zeroed (plain numeric fields)
+            0 => Ok(unsafe { var.assume_init() }),
+            _ => Err(ProgramError::UnsupportedSysvar),
+        }
+    }
+}
+
 define_sysvar!(
     SlotHashesSysvar,
     SLOT_HASHES_SYSVAR_ID,
@@ -109,6 +239,156 @@ define_sysvar!(
     "Validated Instructions sysvar account.\n\nEnables transaction introspection.\nUseful for flash loan protection and multi-instruction checks."
 );
 
+/// Read a little-endian `u16` at `offset`, or `None` if out of bounds.
+#[inline]
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+impl<'a> InstructionsSysvar<'a> {
+    #[inline]
+    fn data(&self) -> &'a [u8] {
+        unsafe { self.info.borrow_unchecked() }
+    }
+
+    /// Get the number of instructions in the current transaction.
+    ///
+    /// Returns 0 if the sysvar data is malformed.
+    #[inline]
+    pub fn num_instructions(&self) -> u16 {
+        read_u16(self.data(), 0).unwrap_or(0)
+    }
+
+    /// Get the index of the currently executing instruction.
+    ///
+    /// Stored in the last two bytes of the sysvar. Returns 0 if the sysvar
+    /// data is malformed.
+    #[inline]
+    pub fn current_index(&self) -> u16 {
+        let data = self.data();
+        data.len()
+            .checked_sub(2)
+            .and_then(|offset| read_u16(data, offset))
+            .unwrap_or(0)
+    }
+
+    /// Load the instruction at `index` in the current transaction.
+    ///
+    /// The returned view borrows the sysvar data; nothing is copied.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidArgument`] - `index` is out of range
+    /// * [`ProgramError::InvalidAccountData`] - The serialized instruction is truncated
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Require a repay instruction from this program later in the transaction
+    /// let sysvar = InstructionsSysvar::wrap(&accounts[4])?;
+    /// let repaid = (sysvar.current_index() + 1..sysvar.num_instructions()).any(|i| {
+    ///     sysvar.load_instruction(i).is_ok_and(|ix| {
+    ///         address_eq(ix.program_id(), &ID) && ix.data().first() == Some(&REPAY)
+    ///     })
+    /// });
+    /// ```
+    #[inline]
+    pub fn load_instruction(&self, index: u16) -> Result<IntrospectedIx<'a>, ProgramError> {
+        if index >= self.num_instructions() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let data = self.data();
+        let offset = read_u16(data, 2 + 2 * index as usize)
+            .ok_or(ProgramError::InvalidAccountData)? as usize;
+        IntrospectedIx::parse(data.get(offset..).ok_or(ProgramError::InvalidAccountData)?)
+    }
+}
+
+/// A single instruction read from the Instructions sysvar.
+///
+/// # Layout
+///
+/// | Size | Field |
+/// |------|-------|
+/// | 2 | num_accounts |
+/// | 33 * num_accounts | flags (bit 0 signer, bit 1 writable) + address |
+/// | 32 | program_id |
+/// | 2 | data_len |
+/// | data_len | data |
+#[derive(Clone, Copy, Debug)]
+pub struct IntrospectedIx<'a> {
+    /// Serialized instruction, exactly as long as its layout.
+    data: &'a [u8],
+    num_accounts: u16,
+}
+
+/// An account reference within an [`IntrospectedIx`].
+#[derive(Clone, Copy, Debug)]
+pub struct IntrospectedAccount<'a> {
+    /// The account's address.
+    pub address: &'a Address,
+    /// Whether the account signs the instruction.
+    pub is_signer: bool,
+    /// Whether the account is writable in the instruction.
+    pub is_writable: bool,
+}
+
+impl<'a> IntrospectedIx<'a> {
+    /// Serialized size of one account entry (flags byte + address).
+    const ACCOUNT_LEN: usize = 1 + 32;
+
+    /// Validate the instruction starting at the front of `data`.
+    fn parse(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let num_accounts = read_u16(data, 0).ok_or(ProgramError::InvalidAccountData)?;
+        let program_id_offset = 2 + num_accounts as usize * Self::ACCOUNT_LEN;
+        let data_len = read_u16(data, program_id_offset + 32)
+            .ok_or(ProgramError::InvalidAccountData)? as usize;
+        let end = program_id_offset + 32 + 2 + data_len;
+        let data = data.get(..end).ok_or(ProgramError::InvalidAccountData)?;
+        Ok(Self { data, num_accounts })
+    }
+
+    #[inline]
+    fn program_id_offset(&self) -> usize {
+        2 + self.num_accounts as usize * Self::ACCOUNT_LEN
+    }
+
+    /// Get the program the instruction invokes.
+    #[inline]
+    pub fn program_id(&self) -> &'a Address {
+        let offset = self.program_id_offset();
+        unsafe { &*(self.data[offset..offset + 32].as_ptr() as *const Address) }
+    }
+
+    /// Get the number of accounts passed to the instruction.
+    #[inline]
+    pub fn num_accounts(&self) -> u16 {
+        self.num_accounts
+    }
+
+    /// Get the account at `index`, or `None` if out of range.
+    #[inline]
+    pub fn account(&self, index: u16) -> Option<IntrospectedAccount<'a>> {
+        if index >= self.num_accounts {
+            return None;
+        }
+        let offset = 2 + index as usize * Self::ACCOUNT_LEN;
+        let flags = self.data[offset];
+        Some(IntrospectedAccount {
+            address: unsafe { &*(self.data[offset + 1..offset + Self::ACCOUNT_LEN].as_ptr() as *const Address) },
+            is_signer: flags & 1 != 0,
+            is_writable: flags & 2 != 0,
+        })
+    }
+
+    /// Get the instruction data.
+    #[inline]
+    pub fn data(&self) -> &'a [u8] {
+        &self.data[self.program_id_offset() + 32 + 2..]
+    }
+}
+
 define_sysvar!(
     RecentBlockhashesSysvar,
     RECENT_BLOCKHASHES_SYSVAR_ID,
@@ -177,3 +457,230 @@ impl<'a> LastRestartSlotSysvar<'a> {
         }
     }
 }
+
+define_sysvar!(
+    EpochScheduleSysvar,
+    EPOCH_SCHEDULE_SYSVAR_ID,
+    "Validated EpochSchedule sysvar account.\n\nProvides slots per epoch and warmup parameters.\nUse for computing epoch boundaries from slot numbers."
+);
+
+define_sysvar!(
+    EpochRewardsSysvar,
+    EPOCH_REWARDS_SYSVAR_ID,
+    "Validated EpochRewards sysvar account.\n\nProvides the status of partitioned staking rewards distribution.\nUseful for staking programs that must not act while rewards are being paid out."
+);
+
+/// EpochSchedule sysvar data structure.
+///
+/// Describes how slots are grouped into epochs. When `warmup` is enabled, early
+/// epochs are shorter and double in length until reaching `slots_per_epoch` at
+/// `first_normal_epoch`.
+///
+/// # Account Layout (33 bytes, bincode)
+///
+/// | Offset | Size | Field |
+/// |--------|------|-------|
+/// | 0 | 8 | slots_per_epoch |
+/// | 8 | 8 | leader_schedule_slot_offset |
+/// | 16 | 1 | warmup |
+/// | 17 | 8 | first_normal_epoch |
+/// | 25 | 8 | first_normal_slot |
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EpochSchedule {
+    /// The maximum number of slots in each epoch.
+    pub slots_per_epoch: u64,
+    /// Number of slots before the beginning of an epoch to calculate its leader schedule.
+    pub leader_schedule_slot_offset: u64,
+    /// Whether epochs start short and grow.
+    pub warmup: bool,
+    /// The first epoch after the warmup period.
+    pub first_normal_epoch: u64,
+    /// The first slot after the warmup period.
+    pub first_normal_slot: u64,
+}
+
+impl EpochSchedule {
+    /// Serialized size of the sysvar account data in bytes.
+    pub const LEN: usize = 33;
+
+    /// Parse the EpochSchedule from raw sysvar account data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if `data` is shorter than [`LEN`](Self::LEN).
+    #[inline]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(Self {
+            slots_per_epoch: u64::from_le_bytes(data[0..8].try_into().unwrap()),
+            leader_schedule_slot_offset: u64::from_le_bytes(data[8..16].try_into().unwrap()),
+            warmup: data[16] != 0,
+            first_normal_epoch: u64::from_le_bytes(data[17..25].try_into().unwrap()),
+            first_normal_slot: u64::from_le_bytes(data[25..33].try_into().unwrap()),
+        })
+    }
+}
+
+impl<'a> EpochScheduleSysvar<'a> {
+    /// Get the EpochSchedule data from this sysvar account.
+    #[inline]
+    pub fn get(&self) -> Result<EpochSchedule, ProgramError> {
+        EpochSchedule::from_bytes(unsafe { self.info.borrow_unchecked() })
+    }
+
+    /// Get the number of slots per (normal) epoch.
+    #[inline]
+    pub fn slots_per_epoch(&self) -> Result<u64, ProgramError> {
+        Ok(self.get()?.slots_per_epoch)
+    }
+
+    /// Get the first epoch after the warmup period.
+    #[inline]
+    pub fn first_normal_epoch(&self) -> Result<u64, ProgramError> {
+        Ok(self.get()?.first_normal_epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec::Vec;
+
+    use crate::test_utils::AccountBuilder;
+
+    use super::*;
+
+    fn create_epoch_schedule_data(schedule: &EpochSchedule) -> Vec<u8> {
+        let mut data = Vec::with_capacity(EpochSchedule::LEN);
+        data.extend_from_slice(&schedule.slots_per_epoch.to_le_bytes());
+        data.extend_from_slice(&schedule.leader_schedule_slot_offset.to_le_bytes());
+        data.push(schedule.warmup as u8);
+        data.extend_from_slice(&schedule.first_normal_epoch.to_le_bytes());
+        data.extend_from_slice(&schedule.first_normal_slot.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_epoch_schedule_from_bytes() {
+        let expected = EpochSchedule {
+            slots_per_epoch: 432_000,
+            leader_schedule_slot_offset: 432_000,
+            warmup: true,
+            first_normal_epoch: 14,
+            first_normal_slot: 524_256,
+        };
+        let data = create_epoch_schedule_data(&expected);
+        assert_eq!(data.len(), EpochSchedule::LEN);
+        assert_eq!(EpochSchedule::from_bytes(&data), Ok(expected));
+    }
+
+    #[test]
+    fn test_epoch_schedule_without_warmup() {
+        let expected = EpochSchedule {
+            slots_per_epoch: 8192,
+            leader_schedule_slot_offset: 8192,
+            warmup: false,
+            first_normal_epoch: 0,
+            first_normal_slot: 0,
+        };
+        let data = create_epoch_schedule_data(&expected);
+        let parsed = EpochSchedule::from_bytes(&data).unwrap();
+        assert_eq!(parsed.slots_per_epoch, 8192);
+        assert_eq!(parsed.first_normal_epoch, 0);
+        assert!(!parsed.warmup);
+    }
+
+    /// Serialize a transaction's instructions as `(program_id, [(flags, address)], data)`.
+    fn create_instructions_data(ixs: &[(Address, &[(u8, Address)], &[u8])], current: u16) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&(ixs.len() as u16).to_le_bytes());
+        let offsets_start = data.len();
+        data.resize(offsets_start + 2 * ixs.len(), 0);
+        for (i, (program_id, accounts, ix_data)) in ixs.iter().enumerate() {
+            let offset = data.len() as u16;
+            data[offsets_start + 2 * i..offsets_start + 2 * i + 2].copy_from_slice(&offset.to_le_bytes());
+            data.extend_from_slice(&(accounts.len() as u16).to_le_bytes());
+            for (flags, address) in accounts.iter() {
+                data.push(*flags);
+                data.extend_from_slice(address.as_ref());
+            }
+            data.extend_from_slice(program_id.as_ref());
+            data.extend_from_slice(&(ix_data.len() as u16).to_le_bytes());
+            data.extend_from_slice(ix_data);
+        }
+        data.extend_from_slice(&current.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_instructions_sysvar_introspection() {
+        let lender = Address::new_from_array([1u8; 32]);
+        let other = Address::new_from_array([2u8; 32]);
+        let user = Address::new_from_array([3u8; 32]);
+        let data = create_instructions_data(
+            &[
+                (lender, &[(3, user)], &[0, 42]),
+                (other, &[], &[]),
+                (lender, &[(0, other), (2, user)], &[1]),
+            ],
+            1,
+        );
+        let account = AccountBuilder::new().address(INSTRUCTIONS_SYSVAR_ID).data(&data).build();
+        let sysvar = InstructionsSysvar::wrap(account.info()).unwrap();
+
+        assert_eq!(sysvar.num_instructions(), 3);
+        assert_eq!(sysvar.current_index(), 1);
+
+        let borrow = sysvar.load_instruction(0).unwrap();
+        assert!(address_eq(borrow.program_id(), &lender));
+        assert_eq!(borrow.data(), &[0, 42]);
+        let signer = borrow.account(0).unwrap();
+        assert!(address_eq(signer.address, &user));
+        assert!(signer.is_signer && signer.is_writable);
+        assert!(borrow.account(1).is_none());
+
+        let repay = sysvar.load_instruction(2).unwrap();
+        assert_eq!(repay.num_accounts(), 2);
+        assert_eq!(repay.data(), &[1]);
+        let writable = repay.account(1).unwrap();
+        assert!(!writable.is_signer && writable.is_writable);
+
+        assert_eq!(sysvar.load_instruction(3).err(), Some(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_clock_expiration_checks() {
+        let mut data = [0u8; 40];
+        data[0..8].copy_from_slice(&500u64.to_le_bytes());
+        data[32..40].copy_from_slice(&1_700_000_000i64.to_le_bytes());
+        let account = AccountBuilder::new().address(CLOCK_SYSVAR_ID).data(&data).build();
+        let clock = ClockSysvar::wrap(account.info()).unwrap();
+
+        assert_eq!(clock.unix_timestamp(), Ok(1_700_000_000));
+        assert_eq!(clock.is_expired(1_700_000_000), Ok(false));
+        assert_eq!(clock.is_expired(1_699_999_999), Ok(true));
+        assert_eq!(clock.assert_not_expired(1_700_000_000), Ok(()));
+        assert_eq!(clock.assert_not_expired(1_699_999_999), Err(ProgramError::InvalidArgument));
+        assert_eq!(clock.is_past_slot(499), Ok(true));
+        assert_eq!(clock.is_past_slot(500), Ok(false));
+
+        let short = AccountBuilder::new().address(CLOCK_SYSVAR_ID).data(&data[..32]).build();
+        let clock = ClockSysvar::wrap(short.info()).unwrap();
+        assert_eq!(clock.unix_timestamp(), Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_syscall_getters_default_off_chain() {
+        let clock = ClockSysvar::get_via_syscall().unwrap();
+        assert_eq!((clock.slot, clock.unix_timestamp), (0, 0));
+        assert_eq!(RentSysvar::get_via_syscall().unwrap().lamports_per_byte_year, 0);
+    }
+
+    #[test]
+    fn test_epoch_schedule_truncated_data() {
+        let data = [0u8; EpochSchedule::LEN - 1];
+        assert_eq!(EpochSchedule::from_bytes(&data), Err(ProgramError::InvalidAccountData));
+    }
+}
@@ -3,13 +3,17 @@
 //! This module provides types for working with SPL Token accounts:
 //!
 //! - [`TokenAccountData`] - Zero-copy struct for token account layout
+//! - [`TokenAccountRef`] - Read-only wrapper for balance and ownership checks
 //! - [`TokenAccountRefMut`] - Writable wrapper with utility methods
 //!
-//! Both types work with SPL Token and Token-2022 accounts.
+//! All types work with SPL Token and Token-2022 accounts.
 
-use pinocchio::{AccountView, error::ProgramError};
+use pinocchio::{AccountView, ProgramResult, error::ProgramError};
 use solana_address::{Address, address_eq};
 
+use crate::invoke_as_pda;
+
+use super::extensions::{find_extension, Extension, ACCOUNT_TYPE_ACCOUNT};
 use super::ids::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
 
 /// SPL Token account data layout.
@@ -83,6 +87,15 @@ impl TokenAccountData {
     /// Size of the token account data in bytes.
     pub const LEN: usize = 165;
 
+    /// `state` value of an uninitialized account.
+    pub const STATE_UNINITIALIZED: u8 = 0;
+
+    /// `state` value of an initialized, unfrozen account.
+    pub const STATE_INITIALIZED: u8 = 1;
+
+    /// `state` value of a frozen account.
+    pub const STATE_FROZEN: u8 = 2;
+
     /// Get the token amount held in this account.
     #[inline]
     pub fn amount(&self) -> u64 {
@@ -128,6 +141,149 @@ impl TokenAccountData {
     pub fn has_close_authority(&self) -> bool {
         u32::from_le_bytes(self.close_authority_tag) != 0
     }
+
+    /// Check if the account has been initialized (initialized or frozen).
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.state != Self::STATE_UNINITIALIZED
+    }
+
+    /// Check if the account is frozen.
+    ///
+    /// Frozen accounts cannot send or receive tokens until thawed by the
+    /// mint's freeze authority.
+    #[inline]
+    pub fn is_frozen(&self) -> bool {
+        self.state == Self::STATE_FROZEN
+    }
+}
+
+/// Read-only SPL Token Account wrapper.
+///
+/// `TokenAccountRef` provides read access to token account data with
+/// validation that the account is owned by a token program. Unlike
+/// [`TokenAccountRefMut`], it does not require the account to be writable,
+/// so balance and ownership checks don't force a writable flag.
+///
+/// # Example
+///
+/// ```ignore
+/// use solzempic::TokenAccountRef;
+///
+/// fn check_balance<'a>(accounts: &'a [AccountInfo], min: u64) -> ProgramResult {
+///     let token_account = TokenAccountRef::load(&accounts[0])?;
+///
+///     if token_account.amount() < min {
+///         return Err(ProgramError::InsufficientFunds);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct TokenAccountRef<'a> {
+    info: &'a AccountView,
+    data: &'a [u8],
+}
+
+impl<'a> TokenAccountRef<'a> {
+    /// Load a read-only token account.
+    ///
+    /// Validates that the account is owned by a token program.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IllegalOwner`] - Not owned by Token or Token-2022
+    /// * [`ProgramError::InvalidAccountData`] - Too small
+    #[inline]
+    pub fn load(info: &'a AccountView) -> Result<Self, ProgramError> {
+        let owner = unsafe { info.owner() };
+        if !address_eq(owner, &TOKEN_PROGRAM_ID) && !address_eq(owner, &TOKEN_2022_PROGRAM_ID) {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = unsafe { info.borrow_unchecked() };
+        if data.len() < TokenAccountData::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(Self { info, data })
+    }
+
+    /// Load a read-only token account, rejecting frozen accounts.
+    ///
+    /// Same as [`load`](Self::load), plus a state check.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IllegalOwner`] - Not owned by Token or Token-2022
+    /// * [`ProgramError::InvalidAccountData`] - Too small or frozen (see [`account_frozen`](crate::errors::account_frozen))
+    #[inline]
+    pub fn load_active(info: &'a AccountView) -> Result<Self, ProgramError> {
+        let account = Self::load(info)?;
+        if account.get().is_frozen() {
+            return Err(crate::errors::account_frozen());
+        }
+        Ok(account)
+    }
+
+    /// Get the underlying AccountView.
+    #[inline]
+    pub fn info(&self) -> &'a AccountView {
+        self.info
+    }
+
+    /// Get the account's address.
+    #[inline]
+    pub fn address(&self) -> &Address {
+        self.info.address()
+    }
+
+    /// Get a reference to the parsed token account data.
+    #[inline]
+    pub fn get(&self) -> &TokenAccountData {
+        bytemuck::from_bytes(&self.data[..TokenAccountData::LEN])
+    }
+
+    /// Get the mint address this account holds tokens for.
+    #[inline]
+    pub fn mint(&self) -> &Address {
+        &self.get().mint
+    }
+
+    /// Get the owner of this token account.
+    ///
+    /// Note: This is the token account owner (who can transfer tokens),
+    /// not the program owner (Token/Token-2022).
+    #[inline]
+    pub fn token_owner(&self) -> &Address {
+        &self.get().owner
+    }
+
+    /// Get the token balance.
+    #[inline]
+    pub fn amount(&self) -> u64 {
+        self.get().amount()
+    }
+
+    /// Check if this is a Token-2022 account.
+    #[inline]
+    pub fn is_token_2022(&self) -> bool {
+        address_eq(unsafe { self.info.owner() }, &TOKEN_2022_PROGRAM_ID)
+    }
+
+    /// Read a Token-2022 extension in place, e.g. [`TransferFeeAmount`](super::TransferFeeAmount).
+    ///
+    /// Returns `None` for SPL Token accounts and accounts without the extension.
+    #[inline]
+    pub fn extension<E: Extension>(&self) -> Option<&E> {
+        find_extension(self.data, ACCOUNT_TYPE_ACCOUNT)
+    }
+
+    /// Reload data after CPI.
+    ///
+    /// Call this after any CPI that modifies the token account so subsequent
+    /// reads see updated values.
+    #[inline]
+    pub fn reload(&mut self) {
+        self.data = unsafe { self.info.borrow_unchecked() };
+    }
 }
 
 /// Writable SPL Token Account wrapper.
@@ -153,10 +309,11 @@ impl TokenAccountData {
 /// # When to Use
 ///
 /// Use `TokenAccountRefMut` for:
-/// - Reading token balances
-/// - Verifying token account ownership
-/// - Checking mint associations
 /// - Working with token accounts in CPI
+/// - Token accounts that the instruction writes to
+///
+/// For balance, ownership, or mint checks on accounts that aren't written,
+/// prefer [`TokenAccountRef`].
 ///
 /// # ATA Creation
 ///
@@ -192,6 +349,25 @@ impl<'a> TokenAccountRefMut<'a> {
         Ok(Self { info, data })
     }
 
+    /// Load a writable token account, rejecting frozen accounts.
+    ///
+    /// Same as [`load`](Self::load), plus a state check. Use this for
+    /// transfer sources and destinations so a frozen account fails up front
+    /// rather than inside the token program CPI.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IllegalOwner`] - Not owned by Token or Token-2022
+    /// * [`ProgramError::InvalidAccountData`] - Not writable, too small, or frozen (see [`account_frozen`](crate::errors::account_frozen))
+    #[inline]
+    pub fn load_active(info: &'a AccountView) -> Result<Self, ProgramError> {
+        let account = Self::load(info)?;
+        if account.get().is_frozen() {
+            return Err(crate::errors::account_frozen());
+        }
+        Ok(account)
+    }
+
     /// Get the underlying AccountView.
     #[inline]
     pub fn info(&self) -> &'a AccountView {
@@ -248,6 +424,32 @@ impl<'a> TokenAccountRefMut<'a> {
         address_eq(unsafe { self.info.owner() }, &TOKEN_2022_PROGRAM_ID)
     }
 
+    /// Read a Token-2022 extension in place, e.g. [`TransferFeeAmount`](super::TransferFeeAmount).
+    ///
+    /// Returns `None` for SPL Token accounts and accounts without the extension.
+    #[inline]
+    pub fn extension<E: Extension>(&self) -> Option<&E> {
+        find_extension(self.data, ACCOUNT_TYPE_ACCOUNT)
+    }
+
+    /// Check if `authority` may transfer `amount` tokens out of this account.
+    ///
+    /// Follows the SPL Token spending rule: the token owner can always spend,
+    /// and an active delegate can spend up to its `delegated_amount()`.
+    ///
+    /// This does not check the account state (see
+    /// [`load_active`](Self::load_active)) or the balance.
+    #[inline]
+    pub fn can_spend(&self, authority: &Address, amount: u64) -> bool {
+        let data = self.get();
+        if address_eq(&data.owner, authority) {
+            return true;
+        }
+        data.has_delegate()
+            && address_eq(&data.delegate, authority)
+            && data.delegated_amount() >= amount
+    }
+
     /// Reload data after CPI.
     ///
     /// Call this after any CPI that modifies the token account (transfers,
@@ -257,6 +459,113 @@ impl<'a> TokenAccountRefMut<'a> {
         self.data = unsafe { self.info.borrow_unchecked_mut() };
     }
 
+    /// Sync a wrapped SOL account's token amount with its lamports.
+    ///
+    /// Call this after depositing lamports into a native token account; the
+    /// token program's `SyncNative` instruction credits the surplus over the
+    /// rent-exempt reserve to `amount`. Does nothing for non-native accounts.
+    /// The data is reloaded afterwards, so [`amount`](Self::amount) reflects
+    /// the deposit.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IncorrectProgramId`] - `token_program` is not the
+    ///   program that owns this account
+    /// * Any error from the token program
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// transfer_lamports(payer.info(), wsol.info(), system_program.info(), amount)?;
+    /// wsol.sync_native(token_program.info())?;
+    /// ```
+    #[inline]
+    pub fn sync_native(&mut self, token_program: &AccountView) -> ProgramResult {
+        if !address_eq(token_program.address(), unsafe { self.info.owner() }) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if self.get().is_native().is_none() {
+            return Ok(());
+        }
+
+        // Token SyncNative instruction = 17
+        let instruction_data = [17u8];
+        let account_metas = [pinocchio::instruction::InstructionAccount {
+            address: self.info.address(),
+            is_writable: true,
+            is_signer: false,
+        }];
+        let instruction = pinocchio::instruction::InstructionView {
+            program_id: token_program.address(),
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+        invoke_as_pda(&instruction, &[self.info], &[])?;
+
+        self.reload();
+        Ok(())
+    }
+
+    /// Close the token account via the token program's `CloseAccount`,
+    /// sending its lamports to `destination`.
+    ///
+    /// The account must hold no tokens (wrapped SOL accounts excepted).
+    /// Consumes the wrapper, since the data is gone afterwards.
+    ///
+    /// `signer_seeds` sign for `authority` when it is a PDA of the calling
+    /// program; pass `&[]` when it signed the transaction.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IncorrectProgramId`] - `token_program` is not the
+    ///   program that owns this account
+    /// * [`ProgramError::InvalidSeeds`] - More than [`MAX_SEEDS`](crate::MAX_SEEDS) seeds
+    /// * Token program errors - Nonzero balance, wrong authority, etc.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// escrow_ata.close(maker.info(), escrow.info(), token_program.info(), &[b"escrow", &[bump]])?;
+    /// ```
+    #[inline]
+    pub fn close(
+        self,
+        destination: &AccountView,
+        authority: &AccountView,
+        token_program: &AccountView,
+        signer_seeds: &[&[u8]],
+    ) -> ProgramResult {
+        if !address_eq(token_program.address(), unsafe { self.info.owner() }) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Token CloseAccount instruction = 9
+        let instruction_data = [9u8];
+        let account_metas = [
+            pinocchio::instruction::InstructionAccount {
+                address: self.info.address(),
+                is_writable: true,
+                is_signer: false,
+            },
+            pinocchio::instruction::InstructionAccount {
+                address: destination.address(),
+                is_writable: true,
+                is_signer: false,
+            },
+            pinocchio::instruction::InstructionAccount {
+                address: authority.address(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ];
+        let instruction = pinocchio::instruction::InstructionView {
+            program_id: token_program.address(),
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+        invoke_as_pda(&instruction, &[self.info, destination, authority], signer_seeds)
+    }
+
     /// Create or initialize an Associated Token Account idempotently.
     ///
     /// This method provides an optimized ATA creation flow:
@@ -295,6 +604,63 @@ impl<'a> TokenAccountRefMut<'a> {
         system_program: &AccountView,
         token_program: &AccountView,
         ata_program: &AccountView,
+    ) -> Result<(), ProgramError> {
+        Self::create_ata(account, payer, owner, mint, system_program, token_program, ata_program, &[])
+    }
+
+    /// Create or initialize an Associated Token Account, signing with a PDA.
+    ///
+    /// Same as [`init_ata`](Self::init_ata), but the CPI is made with
+    /// `invoke_signed` so a program PDA can sign. The ATA program only
+    /// requires the payer's signature, so pass the seeds (including bump) of
+    /// the PDA acting as `payer` - typically a market or vault PDA that also
+    /// owns the ATA and funds its rent.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidSeeds`] - More than [`MAX_SEEDS`](crate::MAX_SEEDS) seeds
+    /// * Any error from [`init_ata`](Self::init_ata)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// TokenAccountRefMut::init_ata_signed(
+    ///     &vault_ata,
+    ///     market.info(),
+    ///     market.info(),
+    ///     &mint_account,
+    ///     system_program.info(),
+    ///     token_program.info(),
+    ///     ata_program.info(),
+    ///     &[b"market", mint.as_ref(), &[bump]],
+    /// )?;
+    /// ```
+    #[inline]
+    pub fn init_ata_signed(
+        account: &AccountView,
+        payer: &AccountView,
+        owner: &AccountView,
+        mint: &AccountView,
+        system_program: &AccountView,
+        token_program: &AccountView,
+        ata_program: &AccountView,
+        signer_seeds: &[&[u8]],
+    ) -> Result<(), ProgramError> {
+        Self::create_ata(account, payer, owner, mint, system_program, token_program, ata_program, signer_seeds)
+    }
+
+    /// Shared CreateIdempotent flow for [`init_ata`](Self::init_ata) and
+    /// [`init_ata_signed`](Self::init_ata_signed).
+    #[inline]
+    fn create_ata(
+        account: &AccountView,
+        payer: &AccountView,
+        owner: &AccountView,
+        mint: &AccountView,
+        system_program: &AccountView,
+        token_program: &AccountView,
+        ata_program: &AccountView,
+        signer_seeds: &[&[u8]],
     ) -> Result<(), ProgramError> {
         // Skip CPI if already initialized - check owner is a token program
         let account_owner = unsafe { account.owner() };
@@ -358,11 +724,111 @@ impl<'a> TokenAccountRefMut<'a> {
 
         // For CPI, only pass accounts that are in account_metas
         // The program is determined by instruction.program_id
-        pinocchio::cpi::invoke(
+        invoke_as_pda(
             &instruction,
             &[payer, account, owner, mint, system_program, token_program],
+            signer_seeds,
         )?;
 
         Ok(())
     }
 }
+
+/// Check that two token accounts hold the same mint.
+///
+/// Swap and transfer instructions should call this on the source and
+/// destination accounts so a look-alike token cannot be substituted for
+/// the expected one.
+///
+/// # Errors
+///
+/// * [`ProgramError::InvalidArgument`] - The accounts have different mints
+#[inline]
+pub fn assert_same_mint(a: &TokenAccountRefMut, b: &TokenAccountRefMut) -> ProgramResult {
+    if !address_eq(a.mint(), b.mint()) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::AccountBuilder;
+
+    use super::*;
+
+    fn token_account(state: u8) -> crate::test_utils::TestAccount {
+        let mut data = [0u8; TokenAccountData::LEN];
+        data[108] = state;
+        AccountBuilder::new()
+            .owner(TOKEN_PROGRAM_ID)
+            .writable(true)
+            .data(&data)
+            .build()
+    }
+
+    #[test]
+    fn test_load_active_rejects_frozen() {
+        let account = token_account(TokenAccountData::STATE_INITIALIZED);
+        let token = TokenAccountRefMut::load_active(account.info()).unwrap();
+        assert!(token.get().is_initialized());
+        assert!(!token.get().is_frozen());
+
+        let account = token_account(TokenAccountData::STATE_FROZEN);
+        assert!(TokenAccountRefMut::load(account.info()).unwrap().get().is_frozen());
+        assert_eq!(
+            TokenAccountRefMut::load_active(account.info()).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            TokenAccountRef::load_active(account.info()).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let account = token_account(TokenAccountData::STATE_UNINITIALIZED);
+        assert!(!TokenAccountRef::load(account.info()).unwrap().get().is_initialized());
+    }
+
+    #[test]
+    fn test_sync_native_checks_program_and_skips_non_native() {
+        let account = token_account(TokenAccountData::STATE_INITIALIZED);
+        let token_program = AccountBuilder::new().address(TOKEN_PROGRAM_ID).build();
+        let token_2022_program = AccountBuilder::new().address(TOKEN_2022_PROGRAM_ID).build();
+
+        let mut token = TokenAccountRefMut::load(account.info()).unwrap();
+        assert_eq!(token.sync_native(token_2022_program.info()), Err(ProgramError::IncorrectProgramId));
+        assert_eq!(token.sync_native(token_program.info()), Ok(()));
+    }
+
+    #[test]
+    fn test_close_checks_token_program() {
+        let account = token_account(TokenAccountData::STATE_INITIALIZED);
+        let destination = AccountBuilder::new().writable(true).build();
+        let token_2022_program = AccountBuilder::new().address(TOKEN_2022_PROGRAM_ID).build();
+
+        let token = TokenAccountRefMut::load(account.info()).unwrap();
+        assert_eq!(
+            token.close(destination.info(), destination.info(), token_2022_program.info(), &[]),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+
+    #[test]
+    fn test_assert_same_mint() {
+        let a = token_account(TokenAccountData::STATE_INITIALIZED);
+        let b = token_account(TokenAccountData::STATE_INITIALIZED);
+        let mut c_data = [0u8; TokenAccountData::LEN];
+        c_data[0] = 1;
+        let c = AccountBuilder::new()
+            .owner(TOKEN_PROGRAM_ID)
+            .writable(true)
+            .data(&c_data)
+            .build();
+
+        let a = TokenAccountRefMut::load(a.info()).unwrap();
+        let b = TokenAccountRefMut::load(b.info()).unwrap();
+        let c = TokenAccountRefMut::load(c.info()).unwrap();
+        assert_eq!(assert_same_mint(&a, &b), Ok(()));
+        assert_eq!(assert_same_mint(&a, &c), Err(ProgramError::InvalidArgument));
+    }
+}
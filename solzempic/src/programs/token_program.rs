@@ -4,7 +4,7 @@
 //! the SPL Token program or Token-2022 program.
 
 use pinocchio::{error::ProgramError, AccountView};
-use solana_address::address_eq;
+use solana_address::{address_eq, Address};
 
 use super::ids::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
 use super::traits::ValidatedAccount;
@@ -101,4 +101,93 @@ impl<'a> TokenProgram<'a> {
     pub fn is_token_2022(&self) -> bool {
         address_eq(self.info.address(), &TOKEN_2022_PROGRAM_ID)
     }
+
+    /// Check whether this program owns `account`, i.e. is the program to
+    /// CPI into for operations on it.
+    #[inline]
+    pub fn owns(&self, account: &AccountView) -> bool {
+        address_eq(unsafe { account.owner() }, self.info.address())
+    }
+
+    /// Get the token program ID that owns a token account or mint.
+    ///
+    /// Use this to route a CPI when an instruction mixes legacy SPL Token
+    /// and Token-2022 accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::IncorrectProgramId`] if `account` is owned by
+    /// neither token program.
+    #[inline]
+    pub fn program_id_for(account: &AccountView) -> Result<&'static Address, ProgramError> {
+        let owner = unsafe { account.owner() };
+        if address_eq(owner, &TOKEN_PROGRAM_ID) {
+            Ok(&TOKEN_PROGRAM_ID)
+        } else if address_eq(owner, &TOKEN_2022_PROGRAM_ID) {
+            Ok(&TOKEN_2022_PROGRAM_ID)
+        } else {
+            Err(ProgramError::IncorrectProgramId)
+        }
+    }
+
+    /// Pick the token program owning `account` from the program accounts
+    /// passed to the instruction.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IncorrectProgramId`] - `account` is owned by neither
+    ///   token program
+    /// * [`ProgramError::NotEnoughAccountKeys`] - The owning program is not
+    ///   among `programs`
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Swap between a legacy SPL Token vault and a Token-2022 vault
+    /// let programs = [self.token_program.info(), self.token_2022_program.info()];
+    /// let program_a = TokenProgram::for_account(self.vault_a.info(), &programs)?;
+    /// let program_b = TokenProgram::for_account(self.vault_b.info(), &programs)?;
+    /// ```
+    #[inline]
+    pub fn for_account(account: &AccountView, programs: &[&'a AccountView]) -> Result<Self, ProgramError> {
+        let program_id = Self::program_id_for(account)?;
+        programs
+            .iter()
+            .find(|program| address_eq(program.address(), program_id))
+            .map(|&info| Self { info })
+            .ok_or_else(crate::errors::not_enough_account_keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::AccountBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_for_account_routes_by_owner() {
+        let token = AccountBuilder::new().address(TOKEN_PROGRAM_ID).executable(true).build();
+        let token_2022 = AccountBuilder::new().address(TOKEN_2022_PROGRAM_ID).executable(true).build();
+        let legacy_vault = AccountBuilder::new().owner(TOKEN_PROGRAM_ID).build();
+        let extended_vault = AccountBuilder::new().owner(TOKEN_2022_PROGRAM_ID).build();
+        let system_account = AccountBuilder::new().build();
+
+        let programs = [token.info(), token_2022.info()];
+        let program = TokenProgram::for_account(extended_vault.info(), &programs).unwrap();
+        assert!(program.is_token_2022());
+        assert!(program.owns(extended_vault.info()));
+        assert!(!program.owns(legacy_vault.info()));
+        assert!(!TokenProgram::for_account(legacy_vault.info(), &programs).unwrap().is_token_2022());
+
+        assert_eq!(TokenProgram::program_id_for(legacy_vault.info()), Ok(&TOKEN_PROGRAM_ID));
+        assert_eq!(
+            TokenProgram::for_account(system_account.info(), &programs).err(),
+            Some(ProgramError::IncorrectProgramId)
+        );
+        assert_eq!(
+            TokenProgram::for_account(extended_vault.info(), &programs[..1]).err(),
+            Some(ProgramError::NotEnoughAccountKeys)
+        );
+    }
 }
@@ -4,7 +4,7 @@
 //! consistent interface for all program and sysvar account wrappers.
 
 use pinocchio::{error::ProgramError, AccountView};
-use solana_address::Address;
+use solana_address::{address_eq, Address};
 
 /// Trait for validated program and sysvar account wrappers.
 ///
@@ -92,4 +92,12 @@ pub trait ValidatedAccount<'a>: Sized {
     fn address(&self) -> &'a Address {
         self.info().address()
     }
+
+    /// Check whether the account's address equals `other`.
+    ///
+    /// Uses [`address_eq`] instead of `==` on `&Address`.
+    #[inline]
+    fn key_eq(&self, other: &Address) -> bool {
+        address_eq(self.address(), other)
+    }
 }
@@ -11,6 +11,8 @@
 //! | Just validate, no methods | Validation functions (this module) |
 //! | Validation + methods | Wrapper types ([`SystemProgram`], [`ClockSysvar`], etc.) |
 //! | Token program (either) | [`validate_token_program`] |
+//! | Any program from a list | [`assert_one_of`] / [`validate_key_one_of`] |
+//! | Owned by any program from a list | [`validate_owner_one_of`] |
 //!
 //! # Performance
 //!
@@ -38,7 +40,7 @@
 //! - [`SystemProgram`](super::SystemProgram), [`TokenProgram`](super::TokenProgram) - Wrapper types
 
 use pinocchio::{error::ProgramError, AccountView};
-use solana_address::address_eq;
+use solana_address::{address_eq, Address};
 
 use super::ids::*;
 
@@ -110,7 +112,125 @@ pub fn validate_token_program(account: &AccountView) -> Result<(), ProgramError>
     Ok(())
 }
 
+/// Validate that an account's key matches one of several allowed IDs.
+///
+/// This generalizes [`validate_token_program`] to any allowlist, e.g. when an
+/// instruction accepts prices from multiple oracle programs.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::IncorrectProgramId`] if the account key matches
+/// none of the entries in `allowed` (including when `allowed` is empty).
+///
+/// # Example
+///
+/// ```ignore
+/// use solzempic::assert_one_of;
+///
+/// assert_one_of(&accounts[2], &[&PYTH_PROGRAM_ID, &SWITCHBOARD_PROGRAM_ID])?;
+/// ```
+///
+/// # Performance
+///
+/// ~20 CUs per entry checked (stops at the first match)
+#[inline]
+pub fn assert_one_of(account: &AccountView, allowed: &[&Address]) -> Result<(), ProgramError> {
+    let key = account.address();
+    if !allowed.iter().any(|id| address_eq(key, id)) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(())
+}
+
+/// Validate that an account's key matches one of several allowed IDs.
+///
+/// Same check as [`assert_one_of`], named to pair with
+/// [`validate_owner_one_of`] and the other `validate_*` functions.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::IncorrectProgramId`] if the account key matches
+/// none of the entries in `allowed` (including when `allowed` is empty).
+#[inline]
+pub fn validate_key_one_of(account: &AccountView, allowed: &[&Address]) -> Result<(), ProgramError> {
+    assert_one_of(account, allowed)
+}
+
+/// Validate that an account is owned by one of several allowed programs.
+///
+/// The owner-side counterpart of [`validate_key_one_of`], e.g. for an instruction
+/// that accepts an account either still owned by the System program or
+/// already initialized by this program.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::IllegalOwner`] if the account owner matches none
+/// of the entries in `allowed` (including when `allowed` is empty).
+///
+/// # Example
+///
+/// ```ignore
+/// use solzempic::validate_owner_one_of;
+///
+/// validate_owner_one_of(&accounts[1], &[&SYSTEM_PROGRAM_ID, &crate::ID])?;
+/// ```
+///
+/// # Performance
+///
+/// ~20 CUs per entry checked (stops at the first match)
+#[inline]
+pub fn validate_owner_one_of(account: &AccountView, allowed: &[&Address]) -> Result<(), ProgramError> {
+    let owner = unsafe { account.owner() };
+    if !allowed.iter().any(|id| address_eq(owner, id)) {
+        return Err(ProgramError::IllegalOwner);
+    }
+    Ok(())
+}
+
 define_validator!(validate_system_program, SYSTEM_PROGRAM_ID, "Validate that an account is the System Program.");
 define_validator!(validate_clock_sysvar, CLOCK_SYSVAR_ID, "Validate that an account is the Clock sysvar.");
 define_validator!(validate_slot_hashes_sysvar, SLOT_HASHES_SYSVAR_ID, "Validate that an account is the SlotHashes sysvar.");
 define_validator!(validate_rent_sysvar, RENT_SYSVAR_ID, "Validate that an account is the Rent sysvar.");
+define_validator!(validate_epoch_schedule_sysvar, EPOCH_SCHEDULE_SYSVAR_ID, "Validate that an account is the EpochSchedule sysvar.");
+define_validator!(validate_epoch_rewards_sysvar, EPOCH_REWARDS_SYSVAR_ID, "Validate that an account is the EpochRewards sysvar.");
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::AccountBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_assert_one_of_allowlist() {
+        let a = Address::new_from_array([1u8; 32]);
+        let b = Address::new_from_array([2u8; 32]);
+        let c = Address::new_from_array([3u8; 32]);
+        let allowed = [&a, &b, &c];
+
+        for id in [a, b, c] {
+            let account = AccountBuilder::new().address(id).executable(true).build();
+            assert_eq!(assert_one_of(account.info(), &allowed), Ok(()));
+        }
+
+        let account = AccountBuilder::new().address(Address::new_from_array([4u8; 32])).build();
+        assert_eq!(assert_one_of(account.info(), &allowed), Err(ProgramError::IncorrectProgramId));
+        assert_eq!(assert_one_of(account.info(), &[]), Err(ProgramError::IncorrectProgramId));
+        assert_eq!(validate_key_one_of(account.info(), &allowed), Err(ProgramError::IncorrectProgramId));
+        assert_eq!(validate_key_one_of(account.info(), &[&a, &Address::new_from_array([4u8; 32])]), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_owner_one_of() {
+        let program = Address::new_from_array([7u8; 32]);
+        let allowed = [&SYSTEM_PROGRAM_ID, &program];
+
+        for owner in [SYSTEM_PROGRAM_ID, program] {
+            let account = AccountBuilder::new().owner(owner).build();
+            assert_eq!(validate_owner_one_of(account.info(), &allowed), Ok(()));
+        }
+
+        let account = AccountBuilder::new().owner(TOKEN_PROGRAM_ID).build();
+        assert_eq!(validate_owner_one_of(account.info(), &allowed), Err(ProgramError::IllegalOwner));
+        assert_eq!(validate_owner_one_of(account.info(), &[]), Err(ProgramError::IllegalOwner));
+    }
+}
@@ -4,12 +4,14 @@
 //! token accounts and SOL accounts):
 //!
 //! - [`Vault`] - SPL Token vault with authority validation
-//! - [`SolVault`] - Native SOL vault (system-owned account)
+//! - [`VaultMut`] - Writable SPL Token vault with authority validation
+//! - [`SolVault`] - Native SOL vault (system-owned or program-owned account)
 
 use pinocchio::{error::ProgramError, AccountView};
 use solana_address::{Address, address_eq};
 
-use super::ids::{SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+use super::ids::SYSTEM_PROGRAM_ID;
+use super::token_account::{TokenAccountData, TokenAccountRef, TokenAccountRefMut};
 
 /// Vault token account with authority validation.
 ///
@@ -51,7 +53,7 @@ use super::ids::{SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
 /// - Staking reward pools
 /// - Any PDA-owned token account
 pub struct Vault<'a> {
-    info: &'a AccountView,
+    account: TokenAccountRef<'a>,
 }
 
 impl<'a> Vault<'a> {
@@ -65,55 +67,126 @@ impl<'a> Vault<'a> {
     /// # Errors
     ///
     /// * [`ProgramError::IllegalOwner`] - Not owned by a token program
-    /// * [`ProgramError::InvalidAccountData`] - Too small or wrong authority
+    /// * [`ProgramError::InvalidAccountData`] - Smaller than a token account, or wrong authority
     #[inline]
     pub fn wrap(info: &'a AccountView, expected_authority: &Address) -> Result<Self, ProgramError> {
-        let owner = unsafe { info.owner() };
-        if !address_eq(owner, &TOKEN_PROGRAM_ID) && !address_eq(owner, &TOKEN_2022_PROGRAM_ID) {
-            return Err(ProgramError::IllegalOwner);
-        }
-
-        // Validate token account owner matches expected authority
-        let data = unsafe { info.borrow_unchecked() };
-        if data.len() < 64 {
+        let account = TokenAccountRef::load(info)?;
+        if !address_eq(account.token_owner(), expected_authority) {
             return Err(ProgramError::InvalidAccountData);
         }
-        let token_owner: &Address = unsafe { &*(data[32..64].as_ptr() as *const Address) };
-        if !address_eq(token_owner, expected_authority) {
+        Ok(Self { account })
+    }
+
+    /// Wrap a writable vault token account, validating its authority.
+    ///
+    /// Combines [`TokenAccountRefMut::load`] with the authority check of
+    /// [`wrap`](Self::wrap), so a vault loaded for writing can't skip it.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IllegalOwner`] - Not owned by a token program
+    /// * [`ProgramError::InvalidAccountData`] - Not writable, too small, or wrong authority
+    #[inline]
+    pub fn wrap_mut(info: &'a AccountView, expected_authority: &Address) -> Result<VaultMut<'a>, ProgramError> {
+        let account = TokenAccountRefMut::load(info)?;
+        if !address_eq(account.token_owner(), expected_authority) {
             return Err(ProgramError::InvalidAccountData);
         }
-
-        Ok(Self { info })
+        Ok(VaultMut { account })
     }
 
     /// Get the underlying AccountView.
     #[inline]
     pub fn info(&self) -> &'a AccountView {
-        self.info
+        self.account.info()
     }
 
     /// Get the vault's address.
     #[inline]
     pub fn address(&self) -> &'a Address {
-        self.info.address()
+        self.account.info().address()
+    }
+
+    /// Get the token amount held in the vault.
+    #[inline]
+    pub fn amount(&self) -> u64 {
+        self.account.amount()
+    }
+
+    /// Get a reference to the token account data.
+    #[inline]
+    pub fn get(&self) -> &TokenAccountData {
+        self.account.get()
+    }
+
+    /// Reload data after a CPI that moved tokens in or out of the vault.
+    #[inline]
+    pub fn reload(&mut self) {
+        self.account.reload();
+    }
+}
+
+/// Writable vault token account with authority validation.
+///
+/// Created by [`Vault::wrap_mut`]. Provides the typed access of
+/// [`TokenAccountRefMut`] for a vault whose authority has been checked.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut vault = Vault::wrap_mut(&accounts[0], market.address())?;
+/// invoke_as_pda(&transfer_ix, &[vault.info(), user_ata, market.info()], market_seeds)?;
+/// vault.reload();
+/// let remaining = vault.amount();
+/// ```
+pub struct VaultMut<'a> {
+    account: TokenAccountRefMut<'a>,
+}
+
+impl<'a> VaultMut<'a> {
+    /// Get the underlying AccountView.
+    #[inline]
+    pub fn info(&self) -> &'a AccountView {
+        self.account.info()
+    }
+
+    /// Get the vault's address.
+    #[inline]
+    pub fn address(&self) -> &Address {
+        self.account.address()
     }
 
     /// Get the token amount held in the vault.
-    ///
-    /// Reads the amount directly from the token account data at offset 64.
     #[inline]
     pub fn amount(&self) -> u64 {
-        let data = unsafe { self.info.borrow_unchecked() };
-        let bytes: [u8; 8] = data[64..72].try_into().unwrap_or([0; 8]);
-        u64::from_le_bytes(bytes)
+        self.account.amount()
+    }
+
+    /// Get a reference to the token account data.
+    #[inline]
+    pub fn get(&self) -> &TokenAccountData {
+        self.account.get()
+    }
+
+    /// Get the vault as a [`TokenAccountRefMut`].
+    #[inline]
+    pub fn token_account(&mut self) -> &mut TokenAccountRefMut<'a> {
+        &mut self.account
+    }
+
+    /// Reload data after a CPI that moved tokens in or out of the vault.
+    #[inline]
+    pub fn reload(&mut self) {
+        self.account.reload();
     }
 }
 
 /// SOL vault account wrapper.
 ///
-/// `SolVault` wraps a system-owned account used to hold native SOL.
-/// Unlike token vaults, SOL vaults are simply accounts owned by the
-/// System program whose lamport balance represents the stored value.
+/// `SolVault` wraps an account used to hold native SOL. Unlike token
+/// vaults, SOL vaults are simply accounts whose lamport balance represents
+/// the stored value: either owned by the System program ([`wrap`](Self::wrap))
+/// or by the calling program ([`wrap_owned`](Self::wrap_owned)).
 ///
 /// # Use Cases
 ///
@@ -138,8 +211,10 @@ impl<'a> Vault<'a> {
 ///
 /// # Note
 ///
-/// To transfer SOL from a `SolVault`, use [`transfer_lamports`](crate::transfer_lamports)
-/// or direct lamport manipulation if the program owns the account via PDA.
+/// To transfer SOL out of a system-owned vault that is a PDA of this program,
+/// use [`transfer_signed`](Self::transfer_signed). A vault owned by the
+/// program itself is debited directly with
+/// [`transfer_owned`](Self::transfer_owned).
 pub struct SolVault<'a> {
     info: &'a AccountView,
 }
@@ -159,6 +234,23 @@ impl<'a> SolVault<'a> {
         Ok(Self { info })
     }
 
+    /// Wrap a SOL vault account owned by `program_id`.
+    ///
+    /// Use this for program-owned accounts that hold SOL, whose lamports
+    /// the program can move with [`transfer_owned`](Self::transfer_owned).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::IllegalOwner`] if the account is not
+    /// owned by `program_id`.
+    #[inline]
+    pub fn wrap_owned(info: &'a AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        if !address_eq(unsafe { info.owner() }, program_id) {
+            return Err(ProgramError::IllegalOwner);
+        }
+        Ok(Self { info })
+    }
+
     /// Get the underlying AccountView.
     #[inline]
     pub fn info(&self) -> &'a AccountView {
@@ -186,4 +278,120 @@ impl<'a> SolVault<'a> {
     pub fn is_writable(&self) -> bool {
         self.info.is_writable()
     }
+
+    /// Transfer SOL out of the vault, signing as a PDA with `seeds`.
+    ///
+    /// Invokes the System program's Transfer; see
+    /// [`transfer_lamports_signed`](crate::transfer_lamports_signed).
+    /// Zero-amount transfers are a no-op.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - The vault or `to` is not writable
+    /// * [`ProgramError::InvalidSeeds`] - Too many seeds
+    /// * System program errors - Insufficient funds, seeds don't match the vault, etc.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let vault = SolVault::wrap(&accounts[0])?;
+    /// vault.transfer_signed(recipient, amount, &[b"fee_vault", &[bump]])?;
+    /// ```
+    #[inline]
+    pub fn transfer_signed(&self, to: &AccountView, amount: u64, seeds: &[&[u8]]) -> Result<(), ProgramError> {
+        crate::transfer_lamports_signed(self.info, to, amount, seeds)
+    }
+
+    /// Transfer SOL out of a program-owned vault by mutating lamports directly.
+    ///
+    /// No CPI is made; see
+    /// [`transfer_lamports_owned`](crate::transfer_lamports_owned). The
+    /// runtime rejects the debit at the end of the instruction if the vault
+    /// isn't owned by the calling program, so wrap it with
+    /// [`wrap_owned`](Self::wrap_owned). Zero-amount transfers are a no-op.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - The vault or `to` is not writable
+    /// * [`ProgramError::InsufficientFunds`] - `amount` exceeds the vault's balance
+    /// * [`ProgramError::ArithmeticOverflow`] - `to`'s balance would overflow
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let vault = SolVault::wrap_owned(&accounts[0], &crate::ID)?;
+    /// vault.transfer_owned(recipient, amount)?;
+    /// ```
+    #[inline]
+    pub fn transfer_owned(&self, to: &AccountView, amount: u64) -> Result<(), ProgramError> {
+        crate::transfer_lamports_owned(self.info, to, amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::AccountBuilder;
+    use crate::TOKEN_PROGRAM_ID;
+
+    use super::*;
+
+    #[test]
+    fn test_vault_wrap_checks_authority() {
+        let authority = Address::new_from_array([5u8; 32]);
+        let mut data = [0u8; TokenAccountData::LEN];
+        data[32..64].copy_from_slice(authority.as_ref());
+
+        data[64..72].copy_from_slice(&25u64.to_le_bytes());
+
+        let vault = AccountBuilder::new().owner(TOKEN_PROGRAM_ID).data(&data).build();
+        let wrapped = Vault::wrap(vault.info(), &authority).unwrap();
+        assert_eq!(wrapped.amount(), 25);
+        assert!(address_eq(&wrapped.get().owner, &authority));
+
+        let other = Address::new_from_array([6u8; 32]);
+        assert_eq!(Vault::wrap(vault.info(), &other).err(), Some(ProgramError::InvalidAccountData));
+
+        let not_token = AccountBuilder::new().owner(SYSTEM_PROGRAM_ID).data(&data).build();
+        assert_eq!(Vault::wrap(not_token.info(), &authority).err(), Some(ProgramError::IllegalOwner));
+    }
+
+    #[test]
+    fn test_vault_wrap_mut_checks_authority_and_writable() {
+        let authority = Address::new_from_array([5u8; 32]);
+        let mut data = [0u8; TokenAccountData::LEN];
+        data[32..64].copy_from_slice(authority.as_ref());
+        data[64..72].copy_from_slice(&40u64.to_le_bytes());
+
+        let vault = AccountBuilder::new().owner(TOKEN_PROGRAM_ID).writable(true).data(&data).build();
+        assert_eq!(Vault::wrap_mut(vault.info(), &authority).unwrap().amount(), 40);
+
+        let other = Address::new_from_array([6u8; 32]);
+        assert_eq!(Vault::wrap_mut(vault.info(), &other).err(), Some(ProgramError::InvalidAccountData));
+
+        let read_only = AccountBuilder::new().owner(TOKEN_PROGRAM_ID).data(&data).build();
+        assert_eq!(Vault::wrap_mut(read_only.info(), &authority).err(), Some(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_sol_vault_transfer_owned() {
+        let program_id = Address::new_from_array([9u8; 32]);
+        let vault = AccountBuilder::new().owner(program_id).writable(true).lamports(500).build();
+        let to = AccountBuilder::new().writable(true).lamports(5).build();
+
+        assert_eq!(SolVault::wrap(vault.info()).err(), Some(ProgramError::IllegalOwner));
+        assert_eq!(
+            SolVault::wrap_owned(to.info(), &program_id).err(),
+            Some(ProgramError::IllegalOwner)
+        );
+
+        let wrapped = SolVault::wrap_owned(vault.info(), &program_id).unwrap();
+        wrapped.transfer_owned(to.info(), 200).unwrap();
+        assert_eq!(wrapped.lamports(), 300);
+        assert_eq!(to.info().lamports(), 205);
+
+        assert_eq!(wrapped.transfer_owned(to.info(), 301), Err(ProgramError::InsufficientFunds));
+
+        let read_only = AccountBuilder::new().lamports(5).build();
+        assert_eq!(wrapped.transfer_owned(read_only.info(), 1), Err(ProgramError::InvalidAccountData));
+    }
 }
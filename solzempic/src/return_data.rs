@@ -0,0 +1,76 @@
+//! Return data for callers that CPI into this program.
+//!
+//! A program invoked via CPI can hand bytes back to its caller by setting
+//! return data, which the caller reads with `sol_get_return_data` after the
+//! CPI returns. Call [`return_data`] or [`return_value`] from `execute`; the
+//! last value set before the instruction finishes is what the caller sees.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use solzempic::return_value;
+//!
+//! #[params]
+//! pub struct QuoteResult {
+//!     pub amount_out: u64,
+//!     pub fee: u64,
+//! }
+//!
+//! fn execute(&mut self, _program_id: &Address, params: &QuoteParams) -> ProgramResult {
+//!     let (amount_out, fee) = self.pool.get().quote(params.amount_in)?;
+//!     return_value(&QuoteResult { amount_out, fee })
+//! }
+//! ```
+
+use bytemuck::Pod;
+use pinocchio::{error::ProgramError, ProgramResult};
+
+/// Maximum size of instruction return data, enforced by the runtime.
+pub const MAX_RETURN_DATA: usize = 1024;
+
+/// Set the instruction's return data to `data`.
+///
+/// Replaces any return data set earlier in the instruction, including by
+/// programs it invoked. Off-chain (in unit tests) this only checks the size.
+///
+/// # Errors
+///
+/// * [`ProgramError::InvalidArgument`] - `data` is longer than [`MAX_RETURN_DATA`]
+#[inline]
+pub fn return_data(data: &[u8]) -> ProgramResult {
+    if data.len() > MAX_RETURN_DATA {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    #[cfg(target_os = "solana")]
+    unsafe {
+        pinocchio::syscalls::sol_set_return_data(data.as_ptr(), data.len() as u64);
+    }
+
+    Ok(())
+}
+
+/// Set the instruction's return data to the bytes of a `Pod` value.
+///
+/// The caller decodes it with the same `#[repr(C)]` layout, e.g. via
+/// `bytemuck::pod_read_unaligned`.
+///
+/// # Errors
+///
+/// * [`ProgramError::InvalidArgument`] - `T` is larger than [`MAX_RETURN_DATA`]
+#[inline]
+pub fn return_value<T: Pod>(value: &T) -> ProgramResult {
+    return_data(bytemuck::bytes_of(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_return_data_size_limit() {
+        assert_eq!(return_data(&[7u8; MAX_RETURN_DATA]), Ok(()));
+        assert_eq!(return_value(&[0u64; 128]), Ok(()));
+        assert_eq!(return_data(&[0u8; MAX_RETURN_DATA + 1]), Err(ProgramError::InvalidArgument));
+    }
+}
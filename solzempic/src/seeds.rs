@@ -0,0 +1,170 @@
+//! Fixed-capacity PDA seed list.
+//!
+//! [`Seeds`] collects borrowed seed slices on the stack and appends the bump
+//! as the final seed, producing the `&[&[u8]]` consumed by
+//! [`create_pda_account`](crate::create_pda_account) and
+//! [`AsAccountRef::is_pda`](crate::AsAccountRef::is_pda).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use solzempic::Seeds;
+//!
+//! let seeds = Seeds::new()
+//!     .push(b"market")
+//!     .push(mint.as_ref())
+//!     .with_bump(bump);
+//!
+//! create_pda_account(payer, market, &ID, Market::LEN, &seeds)?;
+//! ```
+
+use core::ops::Deref;
+
+use pinocchio::error::ProgramError;
+
+use crate::MAX_SEEDS;
+
+/// Every possible bump value, so a bump seed can be a `&'static [u8]`
+/// without the list borrowing from itself.
+static BUMPS: [u8; 256] = {
+    let mut bumps = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        bumps[i] = i as u8;
+        i += 1;
+    }
+    bumps
+};
+
+/// Stack-allocated list of up to [`MAX_SEEDS`] borrowed seeds.
+///
+/// Dereferences to `[&[u8]]`, so `&seeds` can be passed anywhere a seed
+/// slice is expected.
+#[derive(Clone, Copy, Debug)]
+pub struct Seeds<'a> {
+    seeds: [&'a [u8]; MAX_SEEDS],
+    len: usize,
+}
+
+impl Default for Seeds<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Seeds<'a> {
+    /// Create an empty seed list.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            seeds: [&[]; MAX_SEEDS],
+            len: 0,
+        }
+    }
+
+    /// Append a seed.
+    ///
+    /// Use [`try_push`](Self::try_push) when the number of seeds depends on
+    /// instruction input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list already holds [`MAX_SEEDS`] seeds.
+    #[inline]
+    pub fn push(self, seed: &'a [u8]) -> Self {
+        self.try_push(seed).expect("too many PDA seeds")
+    }
+
+    /// Append a seed, or fail if the list is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidSeeds`] if the list already holds
+    /// [`MAX_SEEDS`] seeds, the same error
+    /// [`invoke_as_pda`](crate::invoke_as_pda) returns for too many seeds.
+    #[inline]
+    pub fn try_push(mut self, seed: &'a [u8]) -> Result<Self, ProgramError> {
+        if self.len == MAX_SEEDS {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        self.seeds[self.len] = seed;
+        self.len += 1;
+        Ok(self)
+    }
+
+    /// Append the bump as the final seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list already holds [`MAX_SEEDS`] seeds.
+    #[inline]
+    pub fn with_bump(self, bump: u8) -> Self {
+        self.try_with_bump(bump).expect("too many PDA seeds")
+    }
+
+    /// Append the bump as the final seed, or fail if the list is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidSeeds`] if the list already holds
+    /// [`MAX_SEEDS`] seeds.
+    #[inline]
+    pub fn try_with_bump(self, bump: u8) -> Result<Self, ProgramError> {
+        let bump = bump as usize;
+        self.try_push(&BUMPS[bump..bump + 1])
+    }
+
+    /// Get the seeds as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[&'a [u8]] {
+        &self.seeds[..self.len]
+    }
+}
+
+impl<'a> Deref for Seeds<'a> {
+    type Target = [&'a [u8]];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_bump_appends_bump_seed() {
+        let mint = [9u8; 32];
+        let seeds = Seeds::new().push(b"market").push(&mint).with_bump(254);
+
+        assert_eq!(seeds.len(), 3);
+        assert_eq!(seeds[0], b"market");
+        assert_eq!(seeds[1], &mint);
+        assert_eq!(seeds[2], &[254]);
+    }
+
+    #[test]
+    fn test_try_push_rejects_past_capacity() {
+        let mut seeds = Seeds::new();
+        for _ in 0..MAX_SEEDS - 1 {
+            seeds = seeds.try_push(b"s").unwrap();
+        }
+        let full = seeds.try_with_bump(255).unwrap();
+        assert_eq!(full.len(), MAX_SEEDS);
+        assert_eq!(full[MAX_SEEDS - 1], &[255]);
+
+        assert_eq!(full.try_push(b"extra").err(), Some(ProgramError::InvalidSeeds));
+        assert_eq!(full.try_with_bump(1).err(), Some(ProgramError::InvalidSeeds));
+    }
+
+    #[test]
+    #[should_panic(expected = "too many PDA seeds")]
+    fn test_push_panics_past_capacity() {
+        let mut seeds = Seeds::new();
+        for _ in 0..=MAX_SEEDS {
+            seeds = seeds.push(b"s");
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Off-chain test support for building [`AccountView`]s.
+//!
+//! On-chain, the runtime serializes each account as a [`RuntimeAccount`]
+//! header immediately followed by its data, and `AccountView` is a pointer to
+//! that header. This module reproduces that layout in host memory so the
+//! wrappers' `load`/`wrap` constructors can be exercised in ordinary unit
+//! tests without a validator.
+//!
+//! - [`AccountBuilder`] - Configure address, owner, lamports, flags, and data
+//! - [`TestAccount`] - Owns the backing buffer and hands out the `AccountView`
+//!
+//! # Example
+//!
+//! ```ignore
+//! use solzempic::test_utils::AccountBuilder;
+//!
+//! let account = AccountBuilder::new()
+//!     .owner(ID)
+//!     .writable(true)
+//!     .data(&counter_bytes)
+//!     .build();
+//!
+//! let counter = AccountRefMut::<Counter>::load(account.info())?;
+//! assert_eq!(counter.get().count, 0);
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem::size_of;
+
+use pinocchio::account::{RuntimeAccount, NOT_BORROWED};
+use pinocchio::AccountView;
+use solana_address::Address;
+
+use crate::SYSTEM_PROGRAM_ID;
+
+/// Spare bytes reserved after the data so in-place resizes stay in bounds.
+///
+/// Matches the runtime's per-instruction realloc allowance (10 KiB).
+const REALLOC_PADDING: usize = 10 * 1024;
+
+/// Builder for an off-chain account backed by a runtime-layout buffer.
+///
+/// Defaults to a zero address, System-owned, zero lamports, no data, and
+/// all flags cleared.
+#[derive(Clone, Debug)]
+pub struct AccountBuilder {
+    address: Address,
+    owner: Address,
+    lamports: u64,
+    is_signer: bool,
+    is_writable: bool,
+    executable: bool,
+    data: Vec<u8>,
+}
+
+impl Default for AccountBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountBuilder {
+    /// Create a builder with default settings.
+    pub fn new() -> Self {
+        Self {
+            address: Address::new_from_array([0u8; 32]),
+            owner: SYSTEM_PROGRAM_ID,
+            lamports: 0,
+            is_signer: false,
+            is_writable: false,
+            executable: false,
+            data: Vec::new(),
+        }
+    }
+
+    /// Set the account's address.
+    pub fn address(mut self, address: Address) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Set the program that owns the account.
+    pub fn owner(mut self, owner: Address) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Set the account's lamport balance.
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = lamports;
+        self
+    }
+
+    /// Set the `is_signer` flag.
+    pub fn signer(mut self, is_signer: bool) -> Self {
+        self.is_signer = is_signer;
+        self
+    }
+
+    /// Set the `is_writable` flag.
+    pub fn writable(mut self, is_writable: bool) -> Self {
+        self.is_writable = is_writable;
+        self
+    }
+
+    /// Set the `executable` flag.
+    pub fn executable(mut self, executable: bool) -> Self {
+        self.executable = executable;
+        self
+    }
+
+    /// Set the account data.
+    pub fn data(mut self, data: &[u8]) -> Self {
+        self.data = data.to_vec();
+        self
+    }
+
+    /// Lay out the account in memory and return it.
+    pub fn build(self) -> TestAccount {
+        let header_len = size_of::<RuntimeAccount>();
+        let total_len = header_len + self.data.len() + REALLOC_PADDING;
+
+        // u64 storage keeps the header 8-byte aligned, as in the input buffer
+        let mut buffer: Vec<u64> = vec![0u64; total_len.div_ceil(size_of::<u64>())];
+        let raw = buffer.as_mut_ptr() as *mut RuntimeAccount;
+
+        unsafe {
+            raw.write(RuntimeAccount {
+                borrow_state: NOT_BORROWED,
+                is_signer: self.is_signer as u8,
+                is_writable: self.is_writable as u8,
+                executable: self.executable as u8,
+                resize_delta: 0,
+                address: self.address,
+                owner: self.owner,
+                lamports: self.lamports,
+                data_len: self.data.len() as u64,
+            });
+            core::ptr::copy_nonoverlapping(
+                self.data.as_ptr(),
+                (raw as *mut u8).add(header_len),
+                self.data.len(),
+            );
+        }
+
+        let view = unsafe { AccountView::new_unchecked(raw) };
+        TestAccount { _buffer: buffer, raw, view }
+    }
+}
+
+/// An account laid out in host memory, produced by [`AccountBuilder`].
+///
+/// The `AccountView` returned by [`info`](Self::info) points into this
+/// struct's heap buffer, so wrappers borrowing it cannot outlive the
+/// `TestAccount`.
+pub struct TestAccount {
+    // Only owns the allocation; all access goes through `raw`, which was
+    // taken with `as_mut_ptr` and stays valid since the heap buffer never moves
+    _buffer: Vec<u64>,
+    raw: *mut RuntimeAccount,
+    view: AccountView,
+}
+
+impl TestAccount {
+    /// Get the account view to pass to `load`/`wrap`.
+    pub fn info(&self) -> &AccountView {
+        &self.view
+    }
+
+    /// Get a pointer to the runtime header, e.g. to simulate a CPI that
+    /// resizes the account or changes its owner.
+    ///
+    /// Writing through the pointer is only sound while no wrapper holds a
+    /// data reference that the change invalidates (call `reload` afterwards,
+    /// as after a real CPI).
+    pub fn raw_mut(&self) -> *mut RuntimeAccount {
+        self.raw
+    }
+}
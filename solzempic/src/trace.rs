@@ -0,0 +1,82 @@
+//! Phase logging for the `trace` feature.
+//!
+//! With `trace` enabled, [`Instruction::process`](crate::Instruction::process)
+//! and [`ContextInstruction::process_with_context`](crate::ContextInstruction::process_with_context)
+//! log one line per phase, e.g. `Deposit: validate ok` or
+//! `Deposit: execute failed`, so devnet logs show which phase rejected a
+//! transaction. Without the feature [`phase`] returns its input unchanged and
+//! compiles away.
+
+use pinocchio::error::ProgramError;
+
+/// Longest log line; longer instruction names are truncated.
+#[cfg(feature = "trace")]
+const MAX_LINE: usize = 96;
+
+/// Log the outcome of `phase` for instruction `name` and pass `result` through.
+#[inline(always)]
+pub(crate) fn phase<T>(name: &str, phase: &str, result: Result<T, ProgramError>) -> Result<T, ProgramError> {
+    #[cfg(feature = "trace")]
+    log(name, phase, result.is_ok());
+    #[cfg(not(feature = "trace"))]
+    let _ = (name, phase);
+    result
+}
+
+/// Write `"{name}: {phase} ok|failed"` to the program log.
+#[cfg(feature = "trace")]
+fn log(name: &str, phase: &str, ok: bool) {
+    let mut line = [0u8; MAX_LINE];
+    pinocchio::msg!(format_line(&mut line, name, phase, ok));
+}
+
+/// Format `"{name}: {phase} ok|failed"` into `line`, truncating at
+/// [`MAX_LINE`], and return the formatted text.
+///
+/// `msg!` without `std` only logs a single `&str`, so the line is assembled
+/// here instead of with format arguments.
+#[cfg(feature = "trace")]
+fn format_line<'a>(line: &'a mut [u8; MAX_LINE], name: &str, phase: &str, ok: bool) -> &'a str {
+    let outcome: &[u8] = if ok { b" ok" } else { b" failed" };
+    let mut len = 0;
+    for part in [name.as_bytes(), b": ", phase.as_bytes(), outcome] {
+        let n = part.len().min(MAX_LINE - len);
+        line[len..len + n].copy_from_slice(&part[..n]);
+        len += n;
+    }
+    // Truncation can split a multi-byte character; drop the partial one
+    match core::str::from_utf8(&line[..len]) {
+        Ok(text) => text,
+        Err(err) => core::str::from_utf8(&line[..err.valid_up_to()]).unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase_passes_result_through() {
+        assert_eq!(phase("Deposit", "build", Ok(7)), Ok(7));
+        assert_eq!(
+            phase::<()>("Deposit", "validate", Err(ProgramError::InvalidArgument)),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn test_format_line() {
+        let mut line = [0u8; MAX_LINE];
+        assert_eq!(format_line(&mut line, "Deposit", "validate", true), "Deposit: validate ok");
+        assert_eq!(format_line(&mut line, "Deposit", "execute", false), "Deposit: execute failed");
+
+        // Long names are cut off at MAX_LINE instead of overflowing the buffer
+        let name = "N".repeat(MAX_LINE + 10);
+        assert_eq!(format_line(&mut line, &name, "build", true), &name[..MAX_LINE]);
+
+        // A character split by the cut is dropped whole
+        let name = alloc::format!("{}é", "N".repeat(MAX_LINE - 1));
+        assert_eq!(format_line(&mut line, &name, "build", true), &name[..MAX_LINE - 1]);
+    }
+}
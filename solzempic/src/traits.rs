@@ -85,7 +85,9 @@ pub trait Account: Pod {
 /// Trait for Pod types that can be loaded from account data.
 ///
 /// Implement this for `bytemuck::Pod` structs that represent account data.
-/// The discriminator is a single byte that identifies the account type.
+/// Every `Loadable` type gets a [`Discriminator`] impl from its
+/// [`DISCRIMINATOR_BYTES`](Self::DISCRIMINATOR_BYTES) and
+/// [`DISCRIMINATOR_OFFSET`](Self::DISCRIMINATOR_OFFSET).
 ///
 /// # Example
 ///
@@ -114,10 +116,106 @@ pub trait Loadable: Pod + Sized {
     /// matches the expected type.
     const DISCRIMINATOR: u8;
 
+    /// The full discriminator as stored in the account.
+    ///
+    /// Defaults to the single [`DISCRIMINATOR`](Self::DISCRIMINATOR) byte.
+    /// Override it for a multi-byte tag, such as a hash-derived one; it must
+    /// start with `DISCRIMINATOR`, which is checked at compile time when the
+    /// type is loaded or initialized.
+    const DISCRIMINATOR_BYTES: &'static [u8] = &[Self::DISCRIMINATOR];
+
     /// Size of the type in bytes.
     ///
     /// Defaults to `size_of::<Self>()` but can be overridden if needed.
     const LEN: usize = core::mem::size_of::<Self>();
+
+    /// Byte offset of the discriminator within the account data.
+    ///
+    /// Zero for solzempic layouts. Override it to adopt a legacy layout that
+    /// stores its type tag elsewhere; `#[derive(Account)]` sets it from
+    /// `#[account(field = "...")]`.
+    const DISCRIMINATOR_OFFSET: usize = 0;
+
+    /// Validate raw account data for this type.
+    ///
+    /// Checks that the data is at least [`LEN`](Self::LEN) bytes and holds
+    /// this type's [`DISCRIMINATOR_BYTES`](Self::DISCRIMINATOR_BYTES) at its
+    /// [`DISCRIMINATOR_OFFSET`](Self::DISCRIMINATOR_OFFSET). Used by the
+    /// account wrappers when loading.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Data too small or wrong discriminator
+    #[inline]
+    fn validate(data: &[u8]) -> Result<(), ProgramError> {
+        if data.len() < Self::LEN || !<Self as Discriminator>::matches(data) {
+            return Err(crate::errors::invalid_account_data());
+        }
+        Ok(())
+    }
+}
+
+/// Byte sequence that identifies an account type.
+///
+/// Every [`Loadable`] type implements this automatically, with `BYTES` set to
+/// its [`DISCRIMINATOR_BYTES`](Loadable::DISCRIMINATOR_BYTES) and `OFFSET` to
+/// its [`DISCRIMINATOR_OFFSET`](Loadable::DISCRIMINATOR_OFFSET); loading
+/// checks these bytes and initialization writes them. Code that only needs
+/// to recognize an account type should go through this trait so it doesn't
+/// depend on the discriminator length or position.
+///
+/// # Example
+///
+/// ```ignore
+/// impl Loadable for Pool {
+///     const DISCRIMINATOR: u8 = 7;
+///     const DISCRIMINATOR_BYTES: &'static [u8] = &[7, b'P', b'O', b'O', b'L', 0, 0, 1];
+/// }
+///
+/// if Pool::matches(data) {
+///     // data holds Pool's discriminator
+/// }
+/// ```
+pub trait Discriminator {
+    /// The discriminator bytes.
+    const BYTES: &'static [u8];
+
+    /// Byte offset of [`BYTES`](Self::BYTES) within the account data.
+    const OFFSET: usize = 0;
+
+    /// Check if `data` holds [`BYTES`](Self::BYTES) at [`OFFSET`](Self::OFFSET).
+    #[inline]
+    fn matches(data: &[u8]) -> bool {
+        data.get(Self::OFFSET..).is_some_and(|tail| tail.starts_with(Self::BYTES))
+    }
+
+    /// Like [`matches`](Self::matches), but compares every byte of
+    /// [`BYTES`](Self::BYTES) regardless of where a mismatch occurs.
+    ///
+    /// See [`check_discriminator_ct`].
+    #[inline]
+    fn matches_ct(data: &[u8]) -> bool {
+        data.get(Self::OFFSET..).is_some_and(|tail| check_discriminator_ct(tail, Self::BYTES))
+    }
+
+    /// Write [`BYTES`](Self::BYTES) into `data` at [`OFFSET`](Self::OFFSET).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is too short to hold them.
+    #[inline]
+    fn write(data: &mut [u8]) {
+        data[Self::OFFSET..Self::OFFSET + Self::BYTES.len()].copy_from_slice(Self::BYTES);
+    }
+}
+
+impl<T: Loadable> Discriminator for T {
+    // Rejects a multi-byte tag that disagrees with the one-byte DISCRIMINATOR
+    const BYTES: &'static [u8] = match T::DISCRIMINATOR_BYTES {
+        [first, ..] if *first == T::DISCRIMINATOR => T::DISCRIMINATOR_BYTES,
+        _ => panic!("Loadable::DISCRIMINATOR_BYTES must start with Loadable::DISCRIMINATOR"),
+    };
+    const OFFSET: usize = T::DISCRIMINATOR_OFFSET;
 }
 
 /// Marker trait for types that can be initialized.
@@ -167,3 +265,89 @@ pub trait Initializable: Loadable {}
 pub fn check_discriminator(data: &[u8], expected: u8) -> bool {
     !data.is_empty() && data[0] == expected
 }
+
+/// Check if account data starts with the `expected` discriminator bytes, in
+/// constant time.
+///
+/// Unlike a slice `==`, every byte of `expected` is compared even after a
+/// mismatch, so the time taken doesn't reveal how many leading bytes
+/// matched. Only the lengths, which are public, can end the check early.
+///
+/// # Returns
+///
+/// `true` if `data` is at least `expected.len()` bytes and begins with `expected`.
+#[inline]
+pub fn check_discriminator_ct(data: &[u8], expected: &[u8]) -> bool {
+    let Some(prefix) = data.get(..expected.len()) else {
+        return false;
+    };
+    let diff = prefix
+        .iter()
+        .zip(expected)
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+    core::hint::black_box(diff) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_discriminator_ct() {
+        let expected = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert!(check_discriminator_ct(&[1, 2, 3, 4, 5, 6, 7, 8, 9], &expected));
+        assert!(!check_discriminator_ct(&[1, 2, 3, 4, 5, 6, 7, 9], &expected));
+        assert!(!check_discriminator_ct(&[9, 2, 3, 4, 5, 6, 7, 8], &expected));
+        assert!(!check_discriminator_ct(&[1, 2, 3], &expected));
+        assert!(check_discriminator_ct(&[], &[]));
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    struct Pool {
+        discriminator: [u8; 8],
+        liquidity: u64,
+    }
+
+    impl Loadable for Pool {
+        const DISCRIMINATOR: u8 = 7;
+        const DISCRIMINATOR_BYTES: &'static [u8] = &[7, b'P', b'O', b'O', b'L', 0, 0, 1];
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    struct Tagged {
+        authority: [u8; 32],
+        tag: u8,
+        _padding: [u8; 7],
+    }
+
+    impl Loadable for Tagged {
+        const DISCRIMINATOR: u8 = 3;
+        const DISCRIMINATOR_OFFSET: usize = 32;
+    }
+
+    #[test]
+    fn test_discriminator_comes_from_loadable() {
+        assert_eq!(Tagged::BYTES, &[3]);
+        assert_eq!(<Tagged as Discriminator>::OFFSET, 32);
+
+        let mut data = [0u8; 40];
+        Tagged::write(&mut data);
+        assert_eq!(data[32], 3);
+        assert_eq!(Tagged::validate(&data), Ok(()));
+    }
+
+    #[test]
+    fn test_multi_byte_discriminator() {
+        let mut data = [0u8; 16];
+        Pool::write(&mut data);
+        assert_eq!(&data[..8], Pool::BYTES);
+        assert_eq!(Pool::validate(&data), Ok(()));
+        assert!(Pool::matches_ct(&data));
+
+        // Matching the first byte alone isn't enough
+        data[7] = 2;
+        assert_eq!(Pool::validate(&data), Err(ProgramError::InvalidAccountData));
+    }
+}
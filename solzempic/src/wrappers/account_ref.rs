@@ -8,8 +8,9 @@ use core::marker::PhantomData;
 use pinocchio::{error::ProgramError, AccountView};
 use solana_address::{Address, address_eq};
 
-use crate::{check_discriminator, Framework, Loadable};
+use crate::{Framework, Loadable};
 
+use super::account_ref_mut::AccountRefMut;
 use super::traits::AsAccountRef;
 
 /// Read-only account wrapper for typed account data.
@@ -106,6 +107,56 @@ impl<'a, T: Loadable, F: Framework> AccountRef<'a, T, F> {
         Self::load_unchecked(info)
     }
 
+    /// Try to load an account, returning `None` if validation fails.
+    ///
+    /// The read-only counterpart of [`AccountRefMut::try_load`], for optional
+    /// accounts that may not be initialized yet. Returns `None` for:
+    /// - System-owned accounts (uninitialized PDAs)
+    /// - Accounts not owned by this program
+    /// - Accounts that are too small or have the wrong discriminator
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let fee_bps = match AccountRef::<Config>::try_load(&accounts[4]) {
+    ///     Some(config) => config.get().fee_bps,
+    ///     None => DEFAULT_FEE_BPS,
+    /// };
+    /// ```
+    #[inline]
+    pub fn try_load(info: &'a AccountView) -> Option<Self> {
+        if !address_eq(unsafe { info.owner() }, &F::PROGRAM_ID) {
+            return None;
+        }
+        Self::load_unchecked(info).ok()
+    }
+
+    /// Load an account of any discriminator, checking ownership and size only.
+    ///
+    /// For families of account types that share the layout prefix `T`: load
+    /// with this, branch on [`discriminator_byte`](Self::discriminator_byte),
+    /// then load the concrete type.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IllegalOwner`] - Account not owned by this program
+    /// * [`ProgramError::InvalidAccountData`] - Data smaller than `T::LEN`
+    #[inline]
+    pub fn load_any(info: &'a AccountView) -> Result<Self, ProgramError> {
+        if !address_eq(unsafe { info.owner() }, &F::PROGRAM_ID) {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = unsafe { info.borrow_unchecked() };
+        if data.len() < T::LEN {
+            return Err(crate::errors::invalid_account_data());
+        }
+        Ok(Self {
+            info,
+            data,
+            _marker: PhantomData,
+        })
+    }
+
     /// Load an account without ownership validation.
     ///
     /// This skips the ownership check but still validates data size and
@@ -124,13 +175,7 @@ impl<'a, T: Loadable, F: Framework> AccountRef<'a, T, F> {
     pub fn load_unchecked(info: &'a AccountView) -> Result<Self, ProgramError> {
         let data = unsafe { info.borrow_unchecked() };
 
-        if data.len() < T::LEN {
-            return Err(crate::errors::invalid_account_data());
-        }
-
-        if !check_discriminator(data, T::DISCRIMINATOR) {
-            return Err(crate::errors::invalid_account_data());
-        }
+        T::validate(data)?;
 
         Ok(Self {
             info,
@@ -139,6 +184,38 @@ impl<'a, T: Loadable, F: Framework> AccountRef<'a, T, F> {
         })
     }
 
+    /// Wrap an account that has already passed [`load`](Self::load) validation.
+    #[inline]
+    pub(crate) fn from_validated(info: &'a AccountView) -> Self {
+        Self {
+            info,
+            data: unsafe { info.borrow_unchecked() },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Upgrade to a writable wrapper without re-validating the account data.
+    ///
+    /// The data was accepted when this wrapper was created, so the
+    /// discriminator isn't checked again. Writability and ownership are,
+    /// since `load_unchecked`, `load_any` and `from_raw_parts` skip the owner
+    /// check.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Account not writable
+    /// * [`ProgramError::IllegalOwner`] - Account not owned by the program
+    #[inline]
+    pub fn into_mut(self) -> Result<AccountRefMut<'a, T, F>, ProgramError> {
+        AccountRefMut::from_validated_writable(self.info)
+    }
+
+    /// Get the account's discriminator byte.
+    #[inline]
+    pub fn discriminator_byte(&self) -> u8 {
+        self.data[T::DISCRIMINATOR_OFFSET]
+    }
+
     /// Get the account's address.
     ///
     /// Convenience method equivalent to `self.info.address()`.
@@ -147,6 +224,30 @@ impl<'a, T: Loadable, F: Framework> AccountRef<'a, T, F> {
         self.info.address()
     }
 
+    /// Get the account's lamport balance.
+    ///
+    /// Convenience method equivalent to `self.info.lamports()`.
+    #[inline]
+    pub fn lamports(&self) -> u64 {
+        self.info.lamports()
+    }
+
+    /// Get the length of the account's data in bytes.
+    ///
+    /// Convenience method equivalent to `self.info.data_len()`.
+    #[inline]
+    pub fn data_len(&self) -> usize {
+        self.info.data_len()
+    }
+
+    /// Check if the account signed the transaction.
+    ///
+    /// Convenience method equivalent to `self.info.is_signer()`.
+    #[inline]
+    pub fn is_signer(&self) -> bool {
+        self.info.is_signer()
+    }
+
     /// Get a reference to the parsed account data.
     ///
     /// Returns a typed reference to the account's data via zero-copy pointer cast.
@@ -164,6 +265,36 @@ impl<'a, T: Loadable, F: Framework> AccountRef<'a, T, F> {
         bytemuck::from_bytes(&self.data[..T::LEN])
     }
 
+    /// Reload data reference after CPI.
+    ///
+    /// A CPI can resize the account, so re-borrow the data to see its
+    /// current length and contents.
+    #[inline]
+    pub fn reload(&mut self) {
+        self.data = unsafe { self.info.borrow_unchecked() };
+    }
+
+    /// Get the records stored after the `T` header.
+    ///
+    /// Views the bytes from `T::LEN` onward as a slice of `R`, for accounts
+    /// laid out as a header followed by fixed-size records. Trailing bytes
+    /// that don't fill a whole record are left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` is zero-sized or the records are not aligned for `R`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let levels: &[Level] = book.get_tail();
+    /// ```
+    #[inline]
+    pub fn get_tail<R: bytemuck::Pod>(&self) -> &[R] {
+        let count = (self.data.len() - T::LEN) / core::mem::size_of::<R>();
+        bytemuck::cast_slice(&self.data[T::LEN..T::LEN + count * core::mem::size_of::<R>()])
+    }
+
     /// Check if this account is a PDA derived from the given seeds.
     ///
     /// Derives the expected PDA address from the seeds and framework's program ID,
@@ -199,6 +330,16 @@ impl<'a, T: Loadable, F: Framework> AccountRef<'a, T, F> {
         let (expected, bump) = Address::find_program_address(seeds, &F::PROGRAM_ID);
         (self.info.address().as_ref() == expected.as_ref(), bump)
     }
+
+    /// Require this account to be the PDA derived from `seeds`, returning the bump.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidSeeds`] - Address doesn't match the derived PDA
+    #[inline]
+    pub fn assert_pda(&self, seeds: &[&[u8]]) -> Result<u8, ProgramError> {
+        AsAccountRef::assert_pda(self, seeds)
+    }
 }
 
 impl<'a, T: Loadable, F: Framework> AsAccountRef<'a, T, F> for AccountRef<'a, T, F> {
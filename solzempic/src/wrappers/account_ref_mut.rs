@@ -9,8 +9,9 @@ use core::marker::PhantomData;
 use pinocchio::{error::ProgramError, AccountView};
 use solana_address::{Address, address_eq};
 
-use crate::{check_discriminator, create_pda_account, Framework, Initializable, Loadable, SYSTEM_PROGRAM_ID};
+use crate::{create_pda_account, Discriminator, Framework, Initializable, Loadable, MAX_ACCOUNT_SIZE, MAX_SEEDS, SYSTEM_PROGRAM_ID, Seeds};
 
+use super::account_ref::AccountRef;
 use super::traits::AsAccountRef;
 
 /// Writable account wrapper for typed account data.
@@ -84,6 +85,7 @@ use super::traits::AsAccountRef;
 /// | `init()` | ~100 CUs (validation + write discriminator) |
 /// | `init_pda()` | ~2000 CUs (includes System CPI) |
 /// | `reload()` | ~10 CUs (re-borrow) |
+/// | `maybe_reload()` | ~10 CUs (compare + optional re-borrow) |
 ///
 /// # See Also
 ///
@@ -137,6 +139,41 @@ impl<'a, T: Loadable, F: Framework> AccountRefMut<'a, T, F> {
         Self::load_unchecked(info)
     }
 
+    /// Load an account and check its data against the instruction's other
+    /// accounts, typically that a stored authority is the signer.
+    ///
+    /// Fuses [`load`](Self::load) with the authorization check that almost
+    /// every mutating instruction performs next. `check` receives the
+    /// validated data, and its error fails the load unchanged, so it can
+    /// return a program-specific error.
+    ///
+    /// # Errors
+    ///
+    /// * Any error from [`load`](Self::load)
+    /// * Any error returned by `check`
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let owner = MutSigner::wrap(&accounts[1])?;
+    /// let position = AccountRefMut::<Position>::load_checked(&accounts[0], |position| {
+    ///     if !address_eq(&position.owner, owner.address()) {
+    ///         return Err(MyError::NotPositionOwner.into());
+    ///     }
+    ///     Ok(())
+    /// })?;
+    /// // position belongs to owner; safe to close or modify
+    /// ```
+    #[inline]
+    pub fn load_checked(
+        info: &'a AccountView,
+        check: impl FnOnce(&T) -> Result<(), ProgramError>,
+    ) -> Result<Self, ProgramError> {
+        let account = Self::load(info)?;
+        check(account.get())?;
+        Ok(account)
+    }
+
     /// Try to load an account, returning `None` if validation fails.
     ///
     /// This is useful for optional accounts that may or may not exist (e.g.,
@@ -172,6 +209,36 @@ impl<'a, T: Loadable, F: Framework> AccountRefMut<'a, T, F> {
         Self::load_unchecked(info).ok()
     }
 
+    /// Load a writable account of any discriminator, checking writability,
+    /// ownership, and size only.
+    ///
+    /// The writable counterpart of [`AccountRef::load_any`], for tagged
+    /// account families sharing the layout prefix `T`.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Account not writable
+    /// * [`ProgramError::IllegalOwner`] - Account not owned by this program
+    /// * [`ProgramError::InvalidAccountData`] - Data smaller than `T::LEN`
+    #[inline]
+    pub fn load_any(info: &'a AccountView) -> Result<Self, ProgramError> {
+        if !info.is_writable() {
+            return Err(crate::errors::account_not_writable());
+        }
+        if !address_eq(unsafe { info.owner() }, &F::PROGRAM_ID) {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = unsafe { info.borrow_unchecked_mut() };
+        if data.len() < T::LEN {
+            return Err(crate::errors::invalid_account_data());
+        }
+        Ok(Self {
+            info,
+            data,
+            _marker: PhantomData,
+        })
+    }
+
     /// Load an account without ownership or writable validation.
     ///
     /// This skips both the `is_writable` and ownership checks, but still validates
@@ -190,14 +257,56 @@ impl<'a, T: Loadable, F: Framework> AccountRefMut<'a, T, F> {
     pub fn load_unchecked(info: &'a AccountView) -> Result<Self, ProgramError> {
         let data = unsafe { info.borrow_unchecked_mut() };
 
-        if data.len() < T::LEN {
-            return Err(crate::errors::invalid_account_data());
-        }
+        T::validate(data)?;
+
+        Ok(Self {
+            info,
+            data,
+            _marker: PhantomData,
+        })
+    }
 
-        if !check_discriminator(data, T::DISCRIMINATOR) {
+    /// Wrap a typed region of an account whose data is already borrowed.
+    ///
+    /// For accounts that pack several typed regions, split the account data
+    /// once and wrap each region; `load` would re-borrow the whole account
+    /// instead. `data` is validated like a full account: it must start with
+    /// `T`'s discriminator and hold at least `T::LEN` bytes.
+    ///
+    /// [`reload`](Self::reload) and [`maybe_reload`](Self::maybe_reload)
+    /// re-borrow the whole account, so don't call them on a region wrapper.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Account not writable
+    /// * [`ProgramError::IllegalOwner`] - Account not owned by this program
+    /// * [`ProgramError::InvalidAccountData`] - `data` is not inside `info`'s
+    ///   data, is misaligned for `T`, or fails `T::validate`
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (bids, asks) = unsafe { accounts[0].borrow_unchecked_mut() }.split_at_mut(BookSide::LEN);
+    /// let mut bids = AccountRefMut::<BookSide>::from_raw_parts(&accounts[0], bids)?;
+    /// let mut asks = AccountRefMut::<BookSide>::from_raw_parts(&accounts[0], asks)?;
+    /// ```
+    #[inline]
+    pub fn from_raw_parts(info: &'a AccountView, data: &'a mut [u8]) -> Result<Self, ProgramError> {
+        if !info.is_writable() {
+            return Err(crate::errors::account_not_writable());
+        }
+        if !address_eq(unsafe { info.owner() }, &F::PROGRAM_ID) {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let account_data = unsafe { info.borrow_unchecked() }.as_ptr_range();
+        let region = data.as_ptr_range();
+        if region.start < account_data.start
+            || region.end > account_data.end
+            || region.start.align_offset(core::mem::align_of::<T>()) != 0
+        {
             return Err(crate::errors::invalid_account_data());
         }
-
+        T::validate(data)?;
         Ok(Self {
             info,
             data,
@@ -205,6 +314,70 @@ impl<'a, T: Loadable, F: Framework> AccountRefMut<'a, T, F> {
         })
     }
 
+    /// Wrap an account whose data was accepted by a read-only wrapper,
+    /// checking `is_writable` and ownership as [`load`](Self::load) does.
+    ///
+    /// The read-only wrapper may have come from `load_unchecked`, `load_any`
+    /// or `from_raw_parts`, none of which check the owner, and the program
+    /// can only write to accounts it owns.
+    #[inline]
+    pub(crate) fn from_validated_writable(info: &'a AccountView) -> Result<Self, ProgramError> {
+        if !info.is_writable() {
+            return Err(crate::errors::account_not_writable());
+        }
+        if !address_eq(unsafe { info.owner() }, &F::PROGRAM_ID) {
+            return Err(ProgramError::IllegalOwner);
+        }
+        Ok(Self {
+            info,
+            data: unsafe { info.borrow_unchecked_mut() },
+            _marker: PhantomData,
+        })
+    }
+
+    /// Downgrade to a read-only wrapper without re-validating the account.
+    #[inline]
+    pub fn into_ref(self) -> AccountRef<'a, T, F> {
+        AccountRef::from_validated(self.info)
+    }
+
+    /// Reinterpret the account as a related layout `U`, re-validating the
+    /// same data against `U::LEN` and `U`'s discriminator.
+    ///
+    /// For in-place schema migrations: load the old type, read what you
+    /// need, write the new discriminator (and resize if `U` is larger,
+    /// then [`reload`](Self::reload)), and cast.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Data shorter than `U::LEN` or
+    ///   not tagged with `U`'s discriminator
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut old = AccountRefMut::<PoolV1>::load(&accounts[0])?;
+    /// let fee_bps = old.get().fee_bps;
+    /// old.data_mut()[0] = PoolV2::DISCRIMINATOR;
+    /// let mut pool = old.cast::<PoolV2>()?;
+    /// pool.get_mut().protocol_fee_bps = fee_bps / 2;
+    /// ```
+    #[inline]
+    pub fn cast<U: Loadable>(self) -> Result<AccountRefMut<'a, U, F>, ProgramError> {
+        U::validate(self.data)?;
+        Ok(AccountRefMut {
+            info: self.info,
+            data: self.data,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the account's discriminator byte.
+    #[inline]
+    pub fn discriminator_byte(&self) -> u8 {
+        self.data[T::DISCRIMINATOR_OFFSET]
+    }
+
     /// Get the account's address.
     ///
     /// Convenience method equivalent to `self.info.address()`.
@@ -213,6 +386,66 @@ impl<'a, T: Loadable, F: Framework> AccountRefMut<'a, T, F> {
         self.info.address()
     }
 
+    /// Get the account's lamport balance.
+    ///
+    /// Convenience method equivalent to `self.info.lamports()`.
+    #[inline]
+    pub fn lamports(&self) -> u64 {
+        self.info.lamports()
+    }
+
+    /// Get the length of the account's data in bytes.
+    ///
+    /// Convenience method equivalent to `self.info.data_len()`.
+    #[inline]
+    pub fn data_len(&self) -> usize {
+        self.info.data_len()
+    }
+
+    /// Check if the account signed the transaction.
+    ///
+    /// Convenience method equivalent to `self.info.is_signer()`.
+    #[inline]
+    pub fn is_signer(&self) -> bool {
+        self.info.is_signer()
+    }
+
+    /// Credit lamports directly to this account.
+    ///
+    /// No System program CPI is needed: the runtime lets a program change the
+    /// lamports of writable accounts it owns, which `AccountRefMut` has already
+    /// checked. The debited side must balance this within the same instruction.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::ArithmeticOverflow`] - The balance would overflow `u64`
+    #[inline]
+    pub fn add_lamports(&mut self, amount: u64) -> Result<(), ProgramError> {
+        let lamports = self.info.lamports()
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.info.set_lamports(lamports);
+        Ok(())
+    }
+
+    /// Debit lamports directly from this account.
+    ///
+    /// See [`add_lamports`](Self::add_lamports). Debiting below the
+    /// rent-exempt minimum is allowed here but will fail at the end of the
+    /// transaction unless the account is closed (balance zero).
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InsufficientFunds`] - `amount` exceeds the balance
+    #[inline]
+    pub fn sub_lamports(&mut self, amount: u64) -> Result<(), ProgramError> {
+        let lamports = self.info.lamports()
+            .checked_sub(amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        self.info.set_lamports(lamports);
+        Ok(())
+    }
+
     /// Get a reference to the parsed account data.
     ///
     /// Returns a typed reference to the account's data via zero-copy pointer cast.
@@ -251,6 +484,49 @@ impl<'a, T: Loadable, F: Framework> AccountRefMut<'a, T, F> {
         bytemuck::from_bytes_mut(&mut self.data[..T::LEN])
     }
 
+    /// Get the records stored after the `T` header.
+    ///
+    /// Views the bytes from `T::LEN` onward as a slice of `R`, for accounts
+    /// laid out as a header followed by fixed-size records. Trailing bytes
+    /// that don't fill a whole record are left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` is zero-sized or the records are not aligned for `R`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let levels: &[Level] = book.get_tail();
+    /// ```
+    #[inline]
+    pub fn get_tail<R: bytemuck::Pod>(&self) -> &[R] {
+        let count = (self.data.len() - T::LEN) / core::mem::size_of::<R>();
+        bytemuck::cast_slice(&self.data[T::LEN..T::LEN + count * core::mem::size_of::<R>()])
+    }
+
+    /// Get the records stored after the `T` header mutably.
+    ///
+    /// Same view as [`get_tail`](Self::get_tail). Use
+    /// [`SliceView`](crate::SliceView) instead when the header tracks how
+    /// many records are in use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` is zero-sized or the records are not aligned for `R`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let levels: &mut [Level] = book.get_mut_tail();
+    /// levels[0].size = 0;
+    /// ```
+    #[inline]
+    pub fn get_mut_tail<R: bytemuck::Pod>(&mut self) -> &mut [R] {
+        let count = (self.data.len() - T::LEN) / core::mem::size_of::<R>();
+        bytemuck::cast_slice_mut(&mut self.data[T::LEN..T::LEN + count * core::mem::size_of::<R>()])
+    }
+
     /// Get the full account data slice.
     ///
     /// Returns an immutable reference to the complete account data, not just
@@ -306,6 +582,58 @@ impl<'a, T: Loadable, F: Framework> AccountRefMut<'a, T, F> {
         self.data = unsafe { self.info.borrow_unchecked_mut() };
     }
 
+    /// Reload after a CPI and return the refreshed typed data.
+    ///
+    /// Equivalent to [`reload`](Self::reload) followed by [`get`](Self::get).
+    #[inline]
+    pub fn reload_get(&mut self) -> &T {
+        self.reload();
+        self.get()
+    }
+
+    /// Reload after a CPI and return the refreshed typed data mutably.
+    ///
+    /// Equivalent to [`reload`](Self::reload) followed by
+    /// [`get_mut`](Self::get_mut), so post-CPI writes can't use a stale view.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// invoke(&deposit_ix, &account_infos)?;
+    /// pool.reload_get_mut().total_deposits += amount;
+    /// ```
+    #[inline]
+    pub fn reload_get_mut(&mut self) -> &mut T {
+        self.reload();
+        self.get_mut()
+    }
+
+    /// Reload the data reference only if the account was resized.
+    ///
+    /// Compares the cached data slice against the account's current data
+    /// (pointer and length) and re-borrows only when they differ, which is
+    /// what happens after a realloc. CPIs that write in place (token
+    /// transfers, lamport moves) leave the slice unchanged, so this is a
+    /// cheap no-op for them.
+    ///
+    /// Returns `true` if the data reference was refreshed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// invoke(&resize_ix, &account_infos)?;
+    /// shard.maybe_reload();
+    /// ```
+    #[inline]
+    pub fn maybe_reload(&mut self) -> bool {
+        let current = unsafe { self.info.borrow_unchecked_mut() };
+        if core::ptr::eq(current.as_ptr(), self.data.as_ptr()) && current.len() == self.data.len() {
+            return false;
+        }
+        self.data = current;
+        true
+    }
+
     /// Check if this account is a PDA derived from the given seeds.
     ///
     /// Derives the expected PDA address from the seeds and framework's program ID,
@@ -331,6 +659,16 @@ impl<'a, T: Loadable, F: Framework> AccountRefMut<'a, T, F> {
         (self.info.address().as_ref() == expected.as_ref(), bump)
     }
 
+    /// Require this account to be the PDA derived from `seeds`, returning the bump.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidSeeds`] - Address doesn't match the derived PDA
+    #[inline]
+    pub fn assert_pda(&self, seeds: &[&[u8]]) -> Result<u8, ProgramError> {
+        AsAccountRef::assert_pda(self, seeds)
+    }
+
     /// Check if an account is uninitialized and can be initialized.
     ///
     /// An account is considered uninitialized if:
@@ -347,7 +685,7 @@ impl<'a, T: Loadable, F: Framework> AccountRefMut<'a, T, F> {
 
         if address_eq(owner, &F::PROGRAM_ID) {
             let data = unsafe { info.borrow_unchecked() };
-            return data.is_empty() || data[0] == 0;
+            return data.get(T::DISCRIMINATOR_OFFSET).copied().unwrap_or(0) == 0;
         }
 
         false
@@ -401,8 +739,47 @@ impl<'a, T: Initializable, F: Framework> AccountRefMut<'a, T, F> {
         if data.len() < T::LEN {
             return Err(crate::errors::invalid_account_data());
         }
-        // Write discriminator byte
-        data[0] = T::DISCRIMINATOR;
+        // Write discriminator
+        T::write(data);
+        Self::load_unchecked(info)
+    }
+
+    /// Initialize an uninitialized account with a complete value.
+    ///
+    /// Like [`init`](Self::init), but writes all of `value` in a single copy
+    /// instead of leaving the body zeroed, so the account is never observed
+    /// half-initialized. The discriminator is forced to
+    /// [`Discriminator::BYTES`](crate::Discriminator::BYTES) regardless of
+    /// what `value` holds there.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Account not writable or too small
+    /// * [`ProgramError::AccountAlreadyInitialized`] - Account already has data
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let counter: AccountRefMut<Counter> = AccountRefMut::init_with(&accounts[0], Counter {
+    ///     discriminator: [0; 8],
+    ///     owner: *owner.key(),
+    ///     count: 0,
+    /// })?;
+    /// ```
+    #[inline]
+    pub fn init_with(info: &'a AccountView, value: T) -> Result<Self, ProgramError> {
+        if !info.is_writable() {
+            return Err(crate::errors::account_not_writable());
+        }
+        if !Self::is_uninit(info) {
+            return Err(crate::errors::account_already_initialized());
+        }
+        let data = unsafe { info.borrow_unchecked_mut() };
+        if data.len() < T::LEN {
+            return Err(crate::errors::invalid_account_data());
+        }
+        data[..T::LEN].copy_from_slice(bytemuck::bytes_of(&value));
+        T::write(data);
         Self::load_unchecked(info)
     }
 
@@ -446,8 +823,8 @@ impl<'a, T: Initializable, F: Framework> AccountRefMut<'a, T, F> {
             if data.len() < T::LEN {
                 return Err(crate::errors::invalid_account_data());
             }
-            // Write discriminator byte
-            data[0] = T::DISCRIMINATOR;
+            // Write discriminator
+            T::write(data);
         }
         Self::load_unchecked(info)
     }
@@ -469,7 +846,10 @@ impl<'a, T: Initializable, F: Framework> AccountRefMut<'a, T, F> {
     /// # Errors
     ///
     /// * [`ProgramError::InvalidAccountData`] - Account not writable
-    /// * System program errors - Insufficient funds, wrong address, etc.
+    /// * [`ProgramError::InvalidArgument`] - `space` exceeds [`MAX_ACCOUNT_SIZE`]
+    /// * [`ProgramError::InvalidSeeds`] - `info` is not the PDA of the seeds
+    ///   before the bump, or the bump is not the canonical one
+    /// * System program errors - Insufficient funds, etc.
     ///
     /// # Example
     ///
@@ -501,6 +881,8 @@ impl<'a, T: Initializable, F: Framework> AccountRefMut<'a, T, F> {
     /// # Performance
     ///
     /// This method invokes the System program (~2000 CUs for account creation).
+    /// The bump check runs `find_program_address` once more, which is worth
+    /// it for a one-time creation.
     ///
     /// # See Also
     ///
@@ -517,17 +899,109 @@ impl<'a, T: Initializable, F: Framework> AccountRefMut<'a, T, F> {
         if !info.is_writable() {
             return Err(crate::errors::account_not_writable());
         }
+        if space > MAX_ACCOUNT_SIZE {
+            return Err(crate::errors::account_too_large());
+        }
+
+        // Catch a wrong or non-canonical bump here, not inside the System program
+        let (bump_seed, base_seeds) = match seeds.split_last() {
+            Some(split) if seeds.len() <= MAX_SEEDS => split,
+            _ => return Err(ProgramError::InvalidSeeds),
+        };
+        let (expected, bump) = Address::find_program_address(base_seeds, &F::PROGRAM_ID);
+        if *bump_seed != [bump].as_slice() || !address_eq(info.address(), &expected) {
+            return Err(ProgramError::InvalidSeeds);
+        }
 
         // Create account via CPI (seeds should include bump)
         // Note: system_program param kept for API compatibility but not used
         let _ = system_program;
         create_pda_account(payer, info, &F::PROGRAM_ID, space, seeds)?;
 
-        // Initialize: write discriminator byte
+        // Initialize: write discriminator
         let data = unsafe { info.borrow_unchecked_mut() };
-        data[0] = T::DISCRIMINATOR;
+        T::write(data);
         Self::load_unchecked(info)
     }
+
+    /// Create a PDA account sized to exactly `T::LEN` bytes.
+    ///
+    /// Same as [`init_pda`](Self::init_pda) with `space = T::LEN`, so the
+    /// allocation can't drift from the struct definition. Use `init_pda`
+    /// directly for accounts with a variable-length tail.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut market: AccountRefMut<Market> = AccountRefMut::init_pda_auto(
+    ///     market_account,
+    ///     payer.info(),
+    ///     system_program.info(),
+    ///     seeds,
+    /// )?;
+    /// ```
+    #[inline]
+    pub fn init_pda_auto(
+        info: &'a AccountView,
+        payer: &AccountView,
+        system_program: &AccountView,
+        seeds: &[&[u8]],
+    ) -> Result<Self, ProgramError> {
+        Self::init_pda(info, payer, system_program, seeds, T::LEN)
+    }
+
+    /// Create a PDA account from seeds and a separate bump.
+    ///
+    /// Same as [`init_pda`](Self::init_pda), but `seeds` must **not** include
+    /// the bump; it is appended here. Keeping the bump separate makes it easy
+    /// to store in the new account so later accesses can skip
+    /// `find_program_address`.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The account to create (must be the PDA address)
+    /// * `payer` - Account paying for rent (must be signer)
+    /// * `system_program` - The System program account
+    /// * `seeds` - The PDA seeds **without** the bump seed
+    /// * `bump` - The bump seed for the PDA
+    /// * `space` - The space to allocate (should be `T::LEN` or larger)
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidSeeds`] - More than `MAX_SEEDS - 1` seeds
+    /// * Any error from [`init_pda`](Self::init_pda)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut market: AccountRefMut<Market> = AccountRefMut::init_pda_with_bump(
+    ///     market_account,
+    ///     payer.info(),
+    ///     system_program.info(),
+    ///     &[b"market", base_mint.as_ref()],
+    ///     params.bump,
+    ///     Market::LEN,
+    /// )?;
+    /// market.get_mut().bump = params.bump;
+    /// ```
+    #[inline]
+    pub fn init_pda_with_bump(
+        info: &'a AccountView,
+        payer: &AccountView,
+        system_program: &AccountView,
+        seeds: &[&[u8]],
+        bump: u8,
+        space: usize,
+    ) -> Result<Self, ProgramError> {
+        if seeds.len() >= MAX_SEEDS {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        let seeds = seeds
+            .iter()
+            .fold(Seeds::new(), |acc, seed| acc.push(seed))
+            .with_bump(bump);
+        Self::init_pda(info, payer, system_program, &seeds, space)
+    }
 }
 
 impl<'a, T: Loadable, F: Framework> AsAccountRef<'a, T, F> for AccountRefMut<'a, T, F> {
@@ -552,3 +1026,387 @@ impl<'a, T: Loadable, F: Framework> AsAccountRef<'a, T, F> for AccountRefMut<'a,
         (self.info.address().as_ref() == expected.as_ref(), bump)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::{Pod, Zeroable};
+
+    use crate::test_utils::{AccountBuilder, TestAccount};
+
+    use super::*;
+
+    const PROGRAM_ID: Address = Address::new_from_array([7u8; 32]);
+
+    struct TestFramework;
+
+    impl Framework for TestFramework {
+        const PROGRAM_ID: Address = PROGRAM_ID;
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Pod, Zeroable)]
+    struct Counter {
+        discriminator: [u8; 8],
+        count: u64,
+    }
+
+    impl Loadable for Counter {
+        const DISCRIMINATOR: u8 = 1;
+    }
+
+    impl Initializable for Counter {}
+
+    type CounterRefMut<'a> = AccountRefMut<'a, Counter, TestFramework>;
+
+    fn counter_account(owner: Address, is_writable: bool, discriminator: u8) -> TestAccount {
+        let mut data = [0u8; 16];
+        data[0] = discriminator;
+        AccountBuilder::new()
+            .address(Address::new_from_array([1u8; 32]))
+            .owner(owner)
+            .writable(is_writable)
+            .data(&data)
+            .build()
+    }
+
+    #[test]
+    fn test_from_raw_parts_wraps_regions() {
+        let mut data = [0u8; 32];
+        data[0] = Counter::DISCRIMINATOR;
+        data[16] = Counter::DISCRIMINATOR;
+        let account = AccountBuilder::new().owner(PROGRAM_ID).writable(true).data(&data).build();
+        let other = counter_account(PROGRAM_ID, true, Counter::DISCRIMINATOR);
+
+        let (first, second) = unsafe { account.info().borrow_unchecked_mut() }.split_at_mut(16);
+        let mut first = CounterRefMut::from_raw_parts(account.info(), first).unwrap();
+        let mut second = CounterRefMut::from_raw_parts(account.info(), second).unwrap();
+        first.get_mut().count = 3;
+        second.get_mut().count = 4;
+        let raw = unsafe { account.info().borrow_unchecked() };
+        assert_eq!(raw[8], 3);
+        assert_eq!(raw[24], 4);
+
+        // A region of a different account
+        let foreign = unsafe { other.info().borrow_unchecked_mut() };
+        assert_eq!(
+            CounterRefMut::from_raw_parts(account.info(), foreign).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        // Misaligned for Counter
+        let shifted = unsafe { account.info().borrow_unchecked_mut() };
+        assert_eq!(
+            CounterRefMut::from_raw_parts(account.info(), &mut shifted[4..]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Pod, Zeroable)]
+    struct CounterV2 {
+        discriminator: [u8; 8],
+        count: u32,
+        step: u32,
+    }
+
+    impl Loadable for CounterV2 {
+        const DISCRIMINATOR: u8 = 2;
+    }
+
+    #[test]
+    fn test_cast_revalidates_for_new_layout() {
+        let account = counter_account(PROGRAM_ID, true, Counter::DISCRIMINATOR);
+        let mut counter = CounterRefMut::load(account.info()).unwrap();
+        counter.get_mut().count = 9;
+
+        counter.data_mut()[0] = CounterV2::DISCRIMINATOR;
+        let mut migrated = counter.cast::<CounterV2>().unwrap();
+        assert_eq!(migrated.get().count, 9);
+        migrated.get_mut().step = 1;
+        assert_eq!(unsafe { account.info().borrow_unchecked() }[12], 1);
+
+        // The v2 counter is no longer a valid v1 counter
+        assert_eq!(migrated.cast::<Counter>().err(), Some(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_load_validates_account() {
+        let account = counter_account(PROGRAM_ID, true, Counter::DISCRIMINATOR);
+        assert!(CounterRefMut::load(account.info()).is_ok());
+
+        let account = counter_account(PROGRAM_ID, false, Counter::DISCRIMINATOR);
+        assert_eq!(CounterRefMut::load(account.info()).err(), Some(ProgramError::InvalidAccountData));
+
+        let account = counter_account(SYSTEM_PROGRAM_ID, true, Counter::DISCRIMINATOR);
+        assert_eq!(CounterRefMut::load(account.info()).err(), Some(ProgramError::IllegalOwner));
+
+        let account = counter_account(PROGRAM_ID, true, 2);
+        assert_eq!(CounterRefMut::load(account.info()).err(), Some(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_load_checked_runs_check_after_validation() {
+        let account = counter_account(PROGRAM_ID, true, Counter::DISCRIMINATOR);
+        let expect_count = |expected: u64| {
+            move |counter: &Counter| {
+                if counter.count != expected {
+                    return Err(ProgramError::Custom(7));
+                }
+                Ok(())
+            }
+        };
+        assert!(CounterRefMut::load_checked(account.info(), expect_count(0)).is_ok());
+        assert_eq!(
+            CounterRefMut::load_checked(account.info(), expect_count(1)).err(),
+            Some(ProgramError::Custom(7))
+        );
+
+        // Validation errors take precedence over the check
+        let foreign = counter_account(Address::new_from_array([9u8; 32]), true, Counter::DISCRIMINATOR);
+        assert_eq!(
+            CounterRefMut::load_checked(foreign.info(), |_| unreachable!()).err(),
+            Some(ProgramError::IllegalOwner)
+        );
+    }
+
+    #[test]
+    fn test_try_load_read_only() {
+        // Writability is not required for read-only access
+        let account = counter_account(PROGRAM_ID, false, Counter::DISCRIMINATOR);
+        assert!(AccountRef::<Counter, TestFramework>::try_load(account.info()).is_some());
+
+        let account = counter_account(SYSTEM_PROGRAM_ID, false, Counter::DISCRIMINATOR);
+        assert!(AccountRef::<Counter, TestFramework>::try_load(account.info()).is_none());
+
+        let account = counter_account(PROGRAM_ID, false, 2);
+        assert!(AccountRef::<Counter, TestFramework>::try_load(account.info()).is_none());
+    }
+
+    #[test]
+    fn test_into_mut_rechecks_writable_and_owner() {
+        let account = counter_account(PROGRAM_ID, true, Counter::DISCRIMINATOR);
+        let counter = AccountRef::<Counter, TestFramework>::load(account.info()).unwrap();
+        assert!(counter.into_mut().is_ok());
+
+        let account = counter_account(PROGRAM_ID, false, Counter::DISCRIMINATOR);
+        let counter = AccountRef::<Counter, TestFramework>::load(account.info()).unwrap();
+        assert_eq!(counter.into_mut().err(), Some(ProgramError::InvalidAccountData));
+
+        // `load_unchecked` skips the owner check, so the upgrade makes it
+        let account = counter_account(SYSTEM_PROGRAM_ID, true, Counter::DISCRIMINATOR);
+        let counter = AccountRef::<Counter, TestFramework>::load_unchecked(account.info()).unwrap();
+        assert_eq!(counter.into_mut().err(), Some(ProgramError::IllegalOwner));
+    }
+
+    #[test]
+    fn test_tail_records_after_header() {
+        // Header, two u64 records, and three trailing bytes
+        let mut data = [0u8; 35];
+        data[0] = Counter::DISCRIMINATOR;
+        let account = AccountBuilder::new().owner(PROGRAM_ID).writable(true).data(&data).build();
+        let mut counter = CounterRefMut::load(account.info()).unwrap();
+
+        let records: &mut [u64] = counter.get_mut_tail();
+        assert_eq!(records.len(), 2);
+        records[1] = 5;
+        assert_eq!(counter.get_tail::<u64>(), &[0, 5]);
+        assert_eq!(counter.into_ref().get_tail::<[u8; 4]>().len(), 4);
+        assert_eq!(unsafe { account.info().borrow_unchecked() }[24], 5);
+    }
+
+    #[test]
+    fn test_load_any_skips_discriminator() {
+        let account = counter_account(PROGRAM_ID, true, 2);
+        assert!(CounterRefMut::load(account.info()).is_err());
+
+        let counter = CounterRefMut::load_any(account.info()).unwrap();
+        assert_eq!(counter.discriminator_byte(), 2);
+        assert_eq!(counter.into_ref().discriminator_byte(), 2);
+        assert_eq!(
+            AccountRef::<Counter, TestFramework>::load_any(account.info()).unwrap().discriminator_byte(),
+            2
+        );
+
+        let account = counter_account(SYSTEM_PROGRAM_ID, true, 2);
+        assert_eq!(CounterRefMut::load_any(account.info()).err(), Some(ProgramError::IllegalOwner));
+    }
+
+    #[test]
+    fn test_account_view_passthroughs() {
+        let mut data = [0u8; 16];
+        data[0] = Counter::DISCRIMINATOR;
+        let account = AccountBuilder::new()
+            .owner(PROGRAM_ID)
+            .writable(true)
+            .signer(true)
+            .lamports(1_000)
+            .data(&data)
+            .build();
+
+        let counter = CounterRefMut::load(account.info()).unwrap();
+        assert_eq!(counter.lamports(), 1_000);
+        assert_eq!(counter.data_len(), 16);
+        assert!(counter.is_signer());
+
+        let counter = counter.into_ref();
+        assert_eq!(AsAccountRef::lamports(&counter), 1_000);
+        assert_eq!(AsAccountRef::data_len(&counter), 16);
+        assert!(AsAccountRef::is_signer(&counter));
+    }
+
+    #[test]
+    fn test_is_rent_exempt() {
+        let mut data = [0u8; 16];
+        data[0] = Counter::DISCRIMINATOR;
+        let minimum = crate::rent_exempt_minimum(data.len());
+        let funded = AccountBuilder::new().owner(PROGRAM_ID).writable(true).lamports(minimum).data(&data).build();
+        let short = AccountBuilder::new().owner(PROGRAM_ID).writable(true).lamports(minimum - 1).data(&data).build();
+
+        let counter = CounterRefMut::load(funded.info()).unwrap();
+        assert!(counter.is_rent_exempt());
+        assert_eq!(counter.assert_rent_exempt(), Ok(()));
+
+        let counter = CounterRefMut::load(short.info()).unwrap();
+        assert!(!counter.is_rent_exempt());
+        assert_eq!(counter.assert_rent_exempt(), Err(ProgramError::AccountNotRentExempt));
+    }
+
+    #[test]
+    fn test_add_and_sub_lamports() {
+        let mut data = [0u8; 16];
+        data[0] = Counter::DISCRIMINATOR;
+        let account = AccountBuilder::new()
+            .owner(PROGRAM_ID)
+            .writable(true)
+            .lamports(500)
+            .data(&data)
+            .build();
+        let mut counter = CounterRefMut::load(account.info()).unwrap();
+
+        counter.add_lamports(250).unwrap();
+        assert_eq!(counter.lamports(), 750);
+        counter.sub_lamports(700).unwrap();
+        assert_eq!(counter.lamports(), 50);
+
+        assert_eq!(counter.sub_lamports(51), Err(ProgramError::InsufficientFunds));
+        assert_eq!(counter.add_lamports(u64::MAX), Err(ProgramError::ArithmeticOverflow));
+        assert_eq!(counter.lamports(), 50);
+    }
+
+    #[test]
+    fn test_init_with_writes_whole_value() {
+        let account = counter_account(PROGRAM_ID, true, 0);
+        let value = Counter { discriminator: [9; 8], count: 42 };
+
+        let counter = CounterRefMut::init_with(account.info(), value).unwrap();
+        assert_eq!(counter.get().count, 42);
+        assert_eq!(counter.get().discriminator[0], Counter::DISCRIMINATOR);
+
+        assert_eq!(
+            CounterRefMut::init_with(account.info(), value).err(),
+            Some(ProgramError::AccountAlreadyInitialized)
+        );
+    }
+
+    /// Legacy layout with its type tag after the first field.
+    #[repr(C)]
+    #[derive(Clone, Copy, Pod, Zeroable)]
+    struct TaggedCounter {
+        count: u64,
+        tag: u8,
+        _padding: [u8; 7],
+    }
+
+    impl Loadable for TaggedCounter {
+        const DISCRIMINATOR: u8 = 5;
+        const DISCRIMINATOR_OFFSET: usize = 8;
+    }
+
+    impl Initializable for TaggedCounter {}
+
+    #[test]
+    fn test_discriminator_at_offset() {
+        let account = counter_account(PROGRAM_ID, true, 0);
+        let mut tagged = AccountRefMut::<TaggedCounter, TestFramework>::init(account.info()).unwrap();
+        tagged.get_mut().count = 7;
+        assert_eq!(tagged.get().tag, TaggedCounter::DISCRIMINATOR);
+        assert_eq!(tagged.discriminator_byte(), TaggedCounter::DISCRIMINATOR);
+        assert_eq!(unsafe { account.info().borrow_unchecked() }[0], 7);
+
+        assert!(AccountRefMut::<TaggedCounter, TestFramework>::load(account.info()).is_ok());
+        // Byte 0 holds the count, not a Counter discriminator
+        assert_eq!(CounterRefMut::load(account.info()).err(), Some(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_assert_pda_returns_bump() {
+        let mut data = [0u8; 16];
+        data[0] = Counter::DISCRIMINATOR;
+        let (pda, bump) = Address::find_program_address(&[b"counter"], &PROGRAM_ID);
+        let account = AccountBuilder::new()
+            .address(pda)
+            .owner(PROGRAM_ID)
+            .writable(true)
+            .data(&data)
+            .build();
+        let counter = CounterRefMut::load(account.info()).unwrap();
+
+        assert_eq!(counter.assert_pda(&[b"counter"]), Ok(bump));
+        assert_eq!(counter.assert_pda(&[b"other"]), Err(ProgramError::InvalidSeeds));
+        assert_eq!(counter.into_ref().assert_pda(&[b"counter"]), Ok(bump));
+    }
+
+    #[test]
+    fn test_init_pda_rejects_wrong_bump_or_address() {
+        let (pda, bump) = Address::find_program_address(&[b"counter"], &PROGRAM_ID);
+        let payer = AccountBuilder::new().signer(true).writable(true).build();
+        let account = AccountBuilder::new()
+            .address(pda)
+            .owner(SYSTEM_PROGRAM_ID)
+            .writable(true)
+            .data(&[0u8; 16])
+            .build();
+        let other = AccountBuilder::new()
+            .owner(SYSTEM_PROGRAM_ID)
+            .writable(true)
+            .data(&[0u8; 16])
+            .build();
+
+        let wrong_bump = [bump.wrapping_sub(1)];
+        let result = CounterRefMut::init_pda(
+            account.info(),
+            payer.info(),
+            payer.info(),
+            &[b"counter", &wrong_bump],
+            Counter::LEN,
+        );
+        assert_eq!(result.err(), Some(ProgramError::InvalidSeeds));
+
+        let result = CounterRefMut::init_pda_with_bump(
+            other.info(),
+            payer.info(),
+            payer.info(),
+            &[b"counter"],
+            bump,
+            Counter::LEN,
+        );
+        assert_eq!(result.err(), Some(ProgramError::InvalidSeeds));
+
+        let result = CounterRefMut::init_pda(account.info(), payer.info(), payer.info(), &[], Counter::LEN);
+        assert_eq!(result.err(), Some(ProgramError::InvalidSeeds));
+    }
+
+    #[test]
+    fn test_maybe_reload_reborrows_only_after_resize() {
+        let account = counter_account(PROGRAM_ID, true, Counter::DISCRIMINATOR);
+
+        let mut counter = CounterRefMut::load(account.info()).unwrap();
+        assert!(!counter.maybe_reload());
+        assert_eq!(counter.data().len(), 16);
+
+        // Simulate a realloc performed by a CPI
+        unsafe { (*account.raw_mut()).data_len = 24 };
+        assert!(counter.maybe_reload());
+        assert_eq!(counter.data().len(), 24);
+        assert!(!counter.maybe_reload());
+    }
+}
@@ -0,0 +1,263 @@
+//! Typed wrappers for accounts owned by other programs.
+//!
+//! This module provides [`ForeignRef`] and [`ForeignRefMut`], which give the
+//! same zero-copy typed access as [`AccountRef`](super::AccountRef) but skip
+//! the `F::PROGRAM_ID` ownership check. Use them for cross-program reads such
+//! as another protocol's config or oracle account.
+
+use core::marker::PhantomData;
+
+use bytemuck::Pod;
+use pinocchio::{error::ProgramError, AccountView};
+use solana_address::{Address, address_eq};
+
+use crate::Loadable;
+
+/// Read-only typed wrapper for an account owned by another program.
+///
+/// Unlike [`AccountRef`](super::AccountRef), `T` only needs to be [`Pod`]:
+/// foreign accounts rarely follow this crate's discriminator scheme. Pick the
+/// constructor that matches how much you can check:
+///
+/// | Constructor | Validates |
+/// |-------------|-----------|
+/// | [`load`](Self::load) | Size |
+/// | [`load_owned_by`](Self::load_owned_by) | Owner, size |
+/// | [`load_with_discriminator`](Self::load_with_discriminator) | Size, discriminator (`T: Loadable`) |
+///
+/// # Example
+///
+/// ```ignore
+/// use solzempic::ForeignRef;
+///
+/// // Another program's config, checked against that program's ID
+/// let config = ForeignRef::<OracleConfig>::load_owned_by(&accounts[3], &ORACLE_PROGRAM_ID)?;
+/// let max_age = config.get().max_age;
+/// ```
+///
+/// # Security
+///
+/// Without an owner check, anyone can pass an account with arbitrary bytes.
+/// Prefer [`load_owned_by`](Self::load_owned_by), or pin the address with a
+/// separate check, unless the data is untrusted by design.
+pub struct ForeignRef<'a, T: Pod> {
+    /// The underlying AccountView reference.
+    pub info: &'a AccountView,
+    data: &'a [u8],
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: Pod> ForeignRef<'a, T> {
+    /// Load an account, checking only that it holds at least `size_of::<T>()` bytes.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Data too small
+    #[inline]
+    pub fn load(info: &'a AccountView) -> Result<Self, ProgramError> {
+        let data = unsafe { info.borrow_unchecked() };
+        if data.len() < core::mem::size_of::<T>() {
+            return Err(crate::errors::invalid_account_data());
+        }
+        Ok(Self {
+            info,
+            data,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Load an account owned by `owner`.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IllegalOwner`] - Not owned by `owner`
+    /// * [`ProgramError::InvalidAccountData`] - Data too small
+    #[inline]
+    pub fn load_owned_by(info: &'a AccountView, owner: &Address) -> Result<Self, ProgramError> {
+        if !address_eq(unsafe { info.owner() }, owner) {
+            return Err(ProgramError::IllegalOwner);
+        }
+        Self::load(info)
+    }
+
+    /// Get the account's address.
+    #[inline]
+    pub fn address(&self) -> &Address {
+        self.info.address()
+    }
+
+    /// Get the underlying AccountView.
+    #[inline]
+    pub fn info(&self) -> &'a AccountView {
+        self.info
+    }
+
+    /// Get a reference to the typed account data.
+    #[inline]
+    pub fn get(&self) -> &T {
+        bytemuck::from_bytes(&self.data[..core::mem::size_of::<T>()])
+    }
+}
+
+impl<'a, T: Loadable> ForeignRef<'a, T> {
+    /// Load an account whose data uses the [`Loadable`] discriminator scheme.
+    ///
+    /// Useful for reading accounts of another solzempic program.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Data too small or wrong discriminator
+    #[inline]
+    pub fn load_with_discriminator(info: &'a AccountView) -> Result<Self, ProgramError> {
+        let account = Self::load(info)?;
+        T::validate(account.data)?;
+        Ok(account)
+    }
+}
+
+/// Writable typed wrapper for an account owned by another program.
+///
+/// The writable counterpart of [`ForeignRef`]. The runtime only lets the
+/// owning program change the data, so this is mainly useful for accounts the
+/// program writes through a CPI and then re-reads after
+/// [`reload`](Self::reload), or whose lamports it credits.
+pub struct ForeignRefMut<'a, T: Pod> {
+    /// The underlying AccountView reference.
+    pub info: &'a AccountView,
+    data: &'a mut [u8],
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: Pod> ForeignRefMut<'a, T> {
+    /// Load a writable account, checking only writability and size.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Not writable or data too small
+    #[inline]
+    pub fn load(info: &'a AccountView) -> Result<Self, ProgramError> {
+        if !info.is_writable() {
+            return Err(crate::errors::account_not_writable());
+        }
+        let data = unsafe { info.borrow_unchecked_mut() };
+        if data.len() < core::mem::size_of::<T>() {
+            return Err(crate::errors::invalid_account_data());
+        }
+        Ok(Self {
+            info,
+            data,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Load a writable account owned by `owner`.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::IllegalOwner`] - Not owned by `owner`
+    /// * [`ProgramError::InvalidAccountData`] - Not writable or data too small
+    #[inline]
+    pub fn load_owned_by(info: &'a AccountView, owner: &Address) -> Result<Self, ProgramError> {
+        if !address_eq(unsafe { info.owner() }, owner) {
+            return Err(ProgramError::IllegalOwner);
+        }
+        Self::load(info)
+    }
+
+    /// Get the account's address.
+    #[inline]
+    pub fn address(&self) -> &Address {
+        self.info.address()
+    }
+
+    /// Get the underlying AccountView.
+    #[inline]
+    pub fn info(&self) -> &'a AccountView {
+        self.info
+    }
+
+    /// Get a reference to the typed account data.
+    #[inline]
+    pub fn get(&self) -> &T {
+        bytemuck::from_bytes(&self.data[..core::mem::size_of::<T>()])
+    }
+
+    /// Get a mutable reference to the typed account data.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        bytemuck::from_bytes_mut(&mut self.data[..core::mem::size_of::<T>()])
+    }
+
+    /// Reload data after a CPI that may have modified or resized the account.
+    #[inline]
+    pub fn reload(&mut self) {
+        self.data = unsafe { self.info.borrow_unchecked_mut() };
+    }
+}
+
+impl<'a, T: Loadable> ForeignRefMut<'a, T> {
+    /// Load a writable account whose data uses the [`Loadable`] discriminator scheme.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Not writable, data too small, or wrong discriminator
+    #[inline]
+    pub fn load_with_discriminator(info: &'a AccountView) -> Result<Self, ProgramError> {
+        let account = Self::load(info)?;
+        T::validate(account.data)?;
+        Ok(account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use crate::test_utils::AccountBuilder;
+
+    use super::*;
+
+    const ORACLE_ID: Address = Address::new_from_array([5u8; 32]);
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Pod, Zeroable)]
+    struct OracleConfig {
+        discriminator: [u8; 8],
+        max_age: u64,
+    }
+
+    impl Loadable for OracleConfig {
+        const DISCRIMINATOR: u8 = 4;
+    }
+
+    #[test]
+    fn test_foreign_ref_checks() {
+        let mut data = [0u8; 16];
+        data[0] = 4;
+        data[8] = 30;
+        let account = AccountBuilder::new().owner(ORACLE_ID).data(&data).build();
+
+        let config = ForeignRef::<OracleConfig>::load_owned_by(account.info(), &ORACLE_ID).unwrap();
+        assert_eq!(config.get().max_age, 30);
+        assert!(ForeignRef::<OracleConfig>::load_with_discriminator(account.info()).is_ok());
+
+        let other = Address::new_from_array([6u8; 32]);
+        assert_eq!(
+            ForeignRef::<OracleConfig>::load_owned_by(account.info(), &other).err(),
+            Some(ProgramError::IllegalOwner)
+        );
+        // Not writable
+        assert_eq!(
+            ForeignRefMut::<OracleConfig>::load(account.info()).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let short = AccountBuilder::new().owner(ORACLE_ID).data(&data[..8]).build();
+        assert!(ForeignRef::<OracleConfig>::load(short.info()).is_err());
+
+        data[0] = 5;
+        let wrong_tag = AccountBuilder::new().owner(ORACLE_ID).data(&data).build();
+        assert!(ForeignRef::<OracleConfig>::load(wrong_tag.info()).is_ok());
+        assert!(ForeignRef::<OracleConfig>::load_with_discriminator(wrong_tag.info()).is_err());
+    }
+}
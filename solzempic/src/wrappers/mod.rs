@@ -12,6 +12,9 @@
 //! | [`AccountRefMut<T, F>`] | Writable access | Owner, discriminator, size, `is_writable` |
 //! | [`ShardRefContext<T, F>`] | Read-only shard triplet | All three accounts loaded as `AccountRef` |
 //! | [`ShardRefMutContext<T, F>`] | Writable shard triplet | All three accounts loaded as `AccountRefMut` |
+//! | [`SliceView<H, R>`] | Header + growable record tail | Discriminator, alignment, length vs capacity |
+//! | [`ForeignRef<T>`] | Read-only, other program's account | Size (optionally owner, discriminator) |
+//! | [`ForeignRefMut<T>`] | Writable, other program's account | Size, `is_writable` (optionally owner, discriminator) |
 //!
 //! # The Framework Pattern
 //!
@@ -68,12 +71,16 @@
 
 mod account_ref;
 mod account_ref_mut;
+mod foreign_ref;
 mod shard_ref_context;
 mod shard_ref_mut_context;
+mod slice_view;
 mod traits;
 
 pub use account_ref::AccountRef;
 pub use account_ref_mut::AccountRefMut;
-pub use shard_ref_context::ShardRefContext;
+pub use foreign_ref::{ForeignRef, ForeignRefMut};
+pub use shard_ref_context::{ShardLinks, ShardRefContext};
 pub use shard_ref_mut_context::ShardRefMutContext;
+pub use slice_view::{SliceHeader, SliceView};
 pub use traits::AsAccountRef;
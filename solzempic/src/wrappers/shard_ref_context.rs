@@ -4,11 +4,56 @@
 //! related shard accounts (low, current, high) with read-only access.
 
 use pinocchio::{error::ProgramError, AccountView};
-use solana_address::Address;
+use solana_address::{address_eq, Address};
 
 use crate::{Framework, Loadable};
 
 use super::account_ref::AccountRef;
+use super::account_ref_mut::AccountRefMut;
+use super::shard_ref_mut_context::ShardRefMutContext;
+
+/// Shard type that stores the addresses of its neighbors.
+///
+/// Implement this for the shard data so the triplet contexts can check and
+/// rewrite the linked structure ([`ShardRefContext::validate_links`],
+/// [`ShardRefMutContext::link_after`], [`ShardRefMutContext::unlink_current`])
+/// instead of each instruction patching the neighbor fields by hand.
+///
+/// # Example
+///
+/// ```ignore
+/// impl ShardLinks for OrderShard {
+///     fn low_shard(&self) -> &Address { &self.low_shard }
+///     fn high_shard(&self) -> &Address { &self.high_shard }
+///     fn set_low_shard(&mut self, key: Address) { self.low_shard = key; }
+///     fn set_high_shard(&mut self, key: Address) { self.high_shard = key; }
+/// }
+/// ```
+pub trait ShardLinks: Loadable {
+    /// Address of the low neighbor.
+    fn low_shard(&self) -> &Address;
+
+    /// Address of the high neighbor.
+    fn high_shard(&self) -> &Address;
+
+    /// Point the low neighbor link at `key`.
+    fn set_low_shard(&mut self, key: Address);
+
+    /// Point the high neighbor link at `key`.
+    fn set_high_shard(&mut self, key: Address);
+}
+
+/// Check that `low <-> current <-> high` are linked both ways.
+#[inline]
+pub(crate) fn links_consistent<T: ShardLinks>(
+    (low, current, high): (&T, &T, &T),
+    (low_address, current_address, high_address): (&Address, &Address, &Address),
+) -> bool {
+    address_eq(low.high_shard(), current_address)
+        && address_eq(current.low_shard(), low_address)
+        && address_eq(current.high_shard(), high_address)
+        && address_eq(high.low_shard(), current_address)
+}
 
 /// Context holding read-only references to a triplet of shards.
 ///
@@ -63,7 +108,8 @@ use super::account_ref::AccountRef;
 /// Loading a `ShardRefContext` loads all three shards upfront (~150 CUs total).
 /// This is efficient when you know you'll need to read from neighboring shards.
 ///
-/// If you need mutable access, use [`ShardRefMutContext`](super::ShardRefMutContext) instead.
+/// If you need mutable access, use [`ShardRefMutContext`] instead, or upgrade an
+/// already-loaded context with [`into_mut`](Self::into_mut).
 /// If you only need a single shard, use [`AccountRef`] directly.
 pub struct ShardRefContext<'a, T: Loadable, F: Framework> {
     /// The low shard in the linked structure.
@@ -142,6 +188,36 @@ impl<'a, T: Loadable, F: Framework> ShardRefContext<'a, T, F> {
         Self { low, current, high }
     }
 
+    /// Upgrade to a writable context without re-validating the shard data.
+    ///
+    /// The discriminators aren't checked again, but each shard's writability
+    /// and ownership are, as in [`AccountRef::into_mut`]. Repeated accounts
+    /// are deduplicated as in [`ShardRefMutContext::new`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - A shard is not writable
+    /// * [`ProgramError::IllegalOwner`] - A shard is not owned by the program
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let shards = ShardRefContext::<OrderShard>::new(&accounts[0], &accounts[1], &accounts[2])?;
+    /// if shards.current().needs_rebalance() {
+    ///     let mut shards = shards.into_mut()?;
+    ///     shards.current_mut().rebalance();
+    /// }
+    /// ```
+    #[inline]
+    pub fn into_mut(self) -> Result<ShardRefMutContext<'a, T, F>, ProgramError> {
+        ShardRefMutContext::new_with(
+            self.low.info,
+            self.current.info,
+            self.high.info,
+            AccountRefMut::from_validated_writable,
+        )
+    }
+
     /// Get the address of the current shard.
     #[inline]
     pub fn current_address(&self) -> &Address {
@@ -217,4 +293,236 @@ impl<'a, T: Loadable, F: Framework> ShardRefContext<'a, T, F> {
             self.high.get(),
         )
     }
+
+    /// Reload all three shards after a CPI.
+    ///
+    /// See [`AccountRef::reload`].
+    #[inline]
+    pub fn reload_all(&mut self) {
+        self.low.reload();
+        self.current.reload();
+        self.high.reload();
+    }
+}
+
+impl<T: ShardLinks, F: Framework> ShardRefContext<'_, T, F> {
+    /// Check that the triplet is linked consistently: `low` and `high` point
+    /// at `current`, and `current` points back at both.
+    ///
+    /// Call this before trusting the passed neighbors, so a client can't
+    /// substitute an unrelated shard and corruption surfaces early.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if !shards.validate_links() {
+    ///     return Err(ProgramError::InvalidAccountData);
+    /// }
+    /// ```
+    #[inline]
+    pub fn validate_links(&self) -> bool {
+        links_consistent(self.all(), (self.low_address(), self.current_address(), self.high_address()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::{Pod, Zeroable};
+
+    use crate::test_utils::{AccountBuilder, TestAccount};
+
+    use super::*;
+
+    const PROGRAM_ID: Address = Address::new_from_array([7u8; 32]);
+
+    struct TestFramework;
+
+    impl Framework for TestFramework {
+        const PROGRAM_ID: Address = PROGRAM_ID;
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Pod, Zeroable)]
+    struct Shard {
+        discriminator: [u8; 8],
+        count: u64,
+    }
+
+    impl Loadable for Shard {
+        const DISCRIMINATOR: u8 = 3;
+    }
+
+    fn shard_account(seed: u8, is_writable: bool) -> TestAccount {
+        let mut data = [0u8; 16];
+        data[0] = Shard::DISCRIMINATOR;
+        AccountBuilder::new()
+            .address(Address::new_from_array([seed; 32]))
+            .owner(PROGRAM_ID)
+            .writable(is_writable)
+            .data(&data)
+            .build()
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Pod, Zeroable)]
+    struct LinkedShard {
+        discriminator: [u8; 8],
+        low_shard: Address,
+        high_shard: Address,
+    }
+
+    impl Loadable for LinkedShard {
+        const DISCRIMINATOR: u8 = 4;
+    }
+
+    impl ShardLinks for LinkedShard {
+        fn low_shard(&self) -> &Address {
+            &self.low_shard
+        }
+
+        fn high_shard(&self) -> &Address {
+            &self.high_shard
+        }
+
+        fn set_low_shard(&mut self, key: Address) {
+            self.low_shard = key;
+        }
+
+        fn set_high_shard(&mut self, key: Address) {
+            self.high_shard = key;
+        }
+    }
+
+    /// A shard at address `[seed; 32]` linked to `[low; 32]` and `[high; 32]`.
+    fn linked_shard(seed: u8, low: u8, high: u8) -> TestAccount {
+        let shard = LinkedShard {
+            discriminator: [LinkedShard::DISCRIMINATOR, 0, 0, 0, 0, 0, 0, 0],
+            low_shard: Address::new_from_array([low; 32]),
+            high_shard: Address::new_from_array([high; 32]),
+        };
+        AccountBuilder::new()
+            .address(Address::new_from_array([seed; 32]))
+            .owner(PROGRAM_ID)
+            .writable(true)
+            .data(bytemuck::bytes_of(&shard))
+            .build()
+    }
+
+    #[test]
+    fn test_link_after_and_unlink_current() {
+        // Ring 1 <-> 2 <-> 3 <-> 1, plus an unlinked shard 4
+        let a = linked_shard(1, 3, 2);
+        let b = linked_shard(2, 1, 3);
+        let c = linked_shard(3, 2, 1);
+        let d = linked_shard(4, 0, 0);
+        let shards = ShardRefContext::<LinkedShard, TestFramework>::new(a.info(), b.info(), c.info()).unwrap();
+        assert!(shards.validate_links());
+        // Neighbors passed in the wrong order
+        let shards = ShardRefContext::<LinkedShard, TestFramework>::new(c.info(), b.info(), a.info()).unwrap();
+        assert!(!shards.validate_links());
+
+        // Insert 4 between 2 and 3
+        let mut shards = ShardRefMutContext::<LinkedShard, TestFramework>::new(a.info(), b.info(), c.info()).unwrap();
+        let mut new_shard = AccountRefMut::load(d.info()).unwrap();
+        shards.link_after(&mut new_shard);
+        assert_eq!(new_shard.get().low_shard, *b.info().address());
+        assert_eq!(new_shard.get().high_shard, *c.info().address());
+        let shards = ShardRefMutContext::<LinkedShard, TestFramework>::new(b.info(), d.info(), c.info()).unwrap();
+        assert!(shards.validate_links());
+
+        // Remove 4 again
+        let mut shards = shards;
+        shards.unlink_current();
+        let shards = ShardRefContext::<LinkedShard, TestFramework>::new(a.info(), b.info(), c.info()).unwrap();
+        assert!(shards.validate_links());
+    }
+
+    #[test]
+    fn test_convert_between_contexts() {
+        let low = shard_account(1, true);
+        let current = shard_account(2, true);
+        let high = shard_account(3, true);
+
+        let shards = ShardRefContext::<Shard, TestFramework>::new(low.info(), current.info(), high.info()).unwrap();
+        let mut shards = shards.into_mut().unwrap();
+        shards.current_mut().count = 42;
+
+        let shards = shards.into_ref();
+        assert_eq!(shards.current().count, 42);
+        assert_eq!(shards.current_address(), current.info().address());
+        assert_eq!(shards.high_address(), high.info().address());
+    }
+
+    #[test]
+    fn test_into_mut_requires_writable() {
+        let low = shard_account(1, true);
+        let current = shard_account(2, false);
+        let high = shard_account(3, true);
+
+        let shards = ShardRefContext::<Shard, TestFramework>::new(low.info(), current.info(), high.info()).unwrap();
+        assert_eq!(shards.into_mut().err(), Some(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_into_mut_rechecks_owner() {
+        let low = shard_account(1, true);
+        let current = shard_account(2, true);
+        let high = shard_account(3, true);
+
+        let shards = ShardRefContext::<Shard, TestFramework>::new(low.info(), current.info(), high.info()).unwrap();
+        unsafe { (*current.raw_mut()).owner = Address::new_from_array([9u8; 32]) };
+        assert_eq!(shards.into_mut().err(), Some(ProgramError::IllegalOwner));
+    }
+
+    #[test]
+    fn test_into_ref_preserves_aliases() {
+        let shard = shard_account(1, true);
+
+        let mut shards = ShardRefMutContext::<Shard, TestFramework>::new(shard.info(), shard.info(), shard.info()).unwrap();
+        shards.low_mut().count = 7;
+
+        let shards = shards.into_ref();
+        assert_eq!(shards.all().0.count, 7);
+        assert_eq!(shards.high().count, 7);
+    }
+
+    #[test]
+    fn test_reload_all_sees_resized_data() {
+        let low = shard_account(1, true);
+        let current = shard_account(2, true);
+
+        // high == current; a CPI grows the shared account
+        let mut shards = ShardRefMutContext::<Shard, TestFramework>::new(low.info(), current.info(), current.info()).unwrap();
+        unsafe { (*current.raw_mut()).data_len = 24 };
+        shards.reload_all();
+        assert_eq!(shards.current_ref().data().len(), 24);
+        assert_eq!(shards.high_ref().data().len(), 24);
+        assert_eq!(shards.low_ref().data().len(), 16);
+
+        let mut shards = shards.into_ref();
+        assert_eq!(shards.high.get_tail::<u64>().len(), 1);
+        unsafe { (*current.raw_mut()).data_len = 16 };
+        shards.reload_all();
+        assert_eq!(shards.high.get_tail::<u64>().len(), 0);
+    }
+
+    #[test]
+    fn test_all_mut_aliases_match_dedup() {
+        let low = shard_account(1, true);
+        let current = shard_account(2, true);
+
+        // high == current, distinct from low
+        let mut shards = ShardRefMutContext::<Shard, TestFramework>::new(low.info(), current.info(), current.info()).unwrap();
+        let (_, current_data, high_data) = shards.all_mut();
+        current_data.count = 5;
+        assert_eq!(high_data.count, 5);
+        shards.all_data_mut();
+        shards.all_refs_mut();
+
+        // current == low, high aliases both
+        let mut shards = ShardRefMutContext::<Shard, TestFramework>::new(low.info(), low.info(), low.info()).unwrap();
+        let (low_data, _, high_data) = shards.all_mut();
+        low_data.count = 9;
+        assert_eq!(high_data.count, 9);
+    }
 }
@@ -10,7 +10,9 @@ use solana_address::Address;
 
 use crate::{Framework, Loadable};
 
+use super::account_ref::AccountRef;
 use super::account_ref_mut::AccountRefMut;
+use super::shard_ref_context::{links_consistent, ShardLinks, ShardRefContext};
 
 /// Context holding writable references to a triplet of shards.
 ///
@@ -122,16 +124,27 @@ impl<'a, T: Loadable, F: Framework> ShardRefMutContext<'a, T, F> {
         low_info: &'a AccountView,
         current_info: &'a AccountView,
         high_info: &'a AccountView,
+    ) -> Result<Self, ProgramError> {
+        Self::new_with(low_info, current_info, high_info, AccountRefMut::load)
+    }
+
+    /// Build a deduplicated context, loading each unique account with `load`.
+    #[inline]
+    pub(crate) fn new_with(
+        low_info: &'a AccountView,
+        current_info: &'a AccountView,
+        high_info: &'a AccountView,
+        load: fn(&'a AccountView) -> Result<AccountRefMut<'a, T, F>, ProgramError>,
     ) -> Result<Self, ProgramError> {
         // Always load low
-        let low = AccountRefMut::load(low_info)?;
+        let low = load(low_info)?;
 
         // Check if current is same as low (compare raw pointers to avoid loading twice)
         let low_current_same = ptr::eq(low_info, current_info);
         let current_ref = if low_current_same {
             CurrentRef::AliasLow
         } else {
-            CurrentRef::Owned(AccountRefMut::load(current_info)?)
+            CurrentRef::Owned(load(current_info)?)
         };
 
         // Check if high is same as low or current
@@ -142,7 +155,7 @@ impl<'a, T: Loadable, F: Framework> ShardRefMutContext<'a, T, F> {
         } else if current_high_same {
             HighRef::AliasCurrent
         } else {
-            HighRef::Owned(AccountRefMut::load(high_info)?)
+            HighRef::Owned(load(high_info)?)
         };
 
         Ok(Self {
@@ -226,6 +239,24 @@ impl<'a, T: Loadable, F: Framework> ShardRefMutContext<'a, T, F> {
         &mut self.low
     }
 
+    /// Check that the pointers handed out by the `all_*` accessors alias
+    /// exactly where [`CurrentRef`] and [`HighRef`] say they do.
+    ///
+    /// Catches contexts built with duplicate accounts via
+    /// [`from_loaded`](Self::from_loaded). Compiled out in release builds.
+    #[inline]
+    fn debug_assert_aliasing(&self, low: *const u8, current: *const u8, high: *const u8) {
+        let current_is_low = matches!(self.current_ref, CurrentRef::AliasLow);
+        let (high_is_low, high_is_current) = match self.high_ref {
+            HighRef::Owned(_) => (false, false),
+            HighRef::AliasLow => (true, current_is_low),
+            HighRef::AliasCurrent => (current_is_low, true),
+        };
+        debug_assert_eq!(ptr::eq(current, low), current_is_low, "current/low shard aliasing mismatch");
+        debug_assert_eq!(ptr::eq(high, low), high_is_low, "high/low shard aliasing mismatch");
+        debug_assert_eq!(ptr::eq(high, current), high_is_current, "high/current shard aliasing mismatch");
+    }
+
     // Private aliases for internal use
     #[inline]
     fn current_account(&self) -> &AccountRefMut<'a, T, F> {
@@ -311,6 +342,22 @@ impl<'a, T: Loadable, F: Framework> ShardRefMutContext<'a, T, F> {
         }
     }
 
+    /// Downgrade to a read-only context without re-validating the shards.
+    ///
+    /// Aliased positions (the same account passed more than once) become
+    /// separate read-only wrappers over the same account.
+    #[inline]
+    pub fn into_ref(self) -> ShardRefContext<'a, T, F> {
+        let low_info = self.low.info;
+        let current_info = self.current_ref().info;
+        let high_info = self.high_ref().info;
+        ShardRefContext::from_loaded(
+            AccountRef::from_validated(low_info),
+            AccountRef::from_validated(current_info),
+            AccountRef::from_validated(high_info),
+        )
+    }
+
     /// Get the address of the current shard.
     #[inline]
     pub fn current_address(&self) -> &Address {
@@ -420,6 +467,7 @@ impl<'a, T: Loadable, F: Framework> ShardRefMutContext<'a, T, F> {
         let low_ptr = self.low.get_mut() as *mut T;
         let current_ptr = self.current_account_mut().get_mut() as *mut T;
         let high_ptr = self.high_account_mut().get_mut() as *mut T;
+        self.debug_assert_aliasing(low_ptr as *const u8, current_ptr as *const u8, high_ptr as *const u8);
 
         unsafe {
             (&mut *low_ptr, &mut *current_ptr, &mut *high_ptr)
@@ -449,6 +497,7 @@ impl<'a, T: Loadable, F: Framework> ShardRefMutContext<'a, T, F> {
         let low_ptr = self.low.data_mut() as *mut [u8];
         let current_ptr = self.current_account_mut().data_mut() as *mut [u8];
         let high_ptr = self.high_account_mut().data_mut() as *mut [u8];
+        self.debug_assert_aliasing(low_ptr as *const u8, current_ptr as *const u8, high_ptr as *const u8);
 
         unsafe {
             (&mut *low_ptr, &mut *current_ptr, &mut *high_ptr)
@@ -474,9 +523,83 @@ impl<'a, T: Loadable, F: Framework> ShardRefMutContext<'a, T, F> {
         let low_ptr = &mut self.low as *mut AccountRefMut<'a, T, F>;
         let current_ptr = self.current_account_mut() as *mut AccountRefMut<'a, T, F>;
         let high_ptr = self.high_account_mut() as *mut AccountRefMut<'a, T, F>;
+        self.debug_assert_aliasing(low_ptr as *const u8, current_ptr as *const u8, high_ptr as *const u8);
 
         unsafe {
             (&mut *low_ptr, &mut *current_ptr, &mut *high_ptr)
         }
     }
+
+    /// Reload every shard after a CPI.
+    ///
+    /// Each unique account is reloaded once; aliased positions share the
+    /// reloaded reference. Prefer this over reloading shards one by one, so
+    /// none is left stale.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// invoke_as_pda(&instruction, &[shards.current_ref().info, market.info()], seeds)?;
+    /// shards.reload_all();
+    /// ```
+    #[inline]
+    pub fn reload_all(&mut self) {
+        self.low.reload();
+        if let CurrentRef::Owned(acct) = &mut self.current_ref {
+            acct.reload();
+        }
+        if let HighRef::Owned(acct) = &mut self.high_ref {
+            acct.reload();
+        }
+    }
+}
+
+impl<'a, T: ShardLinks, F: Framework> ShardRefMutContext<'a, T, F> {
+    /// Check that the triplet is linked consistently; see
+    /// [`ShardRefContext::validate_links`].
+    #[inline]
+    pub fn validate_links(&self) -> bool {
+        links_consistent(
+            (self.low(), self.current(), self.high()),
+            (self.low_address(), self.current_address(), self.high_address()),
+        )
+    }
+
+    /// Insert `new_shard` between `current` and `high`.
+    ///
+    /// Points `new_shard` at `current` and `high`, and repoints
+    /// `current.high` and `high.low` at `new_shard`. Afterwards
+    /// `(current, new_shard, high)` is a consistent triplet.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Split an overfull shard into a freshly created one
+    /// let mut new_shard = AccountRefMut::<OrderShard>::init_pda(...)?;
+    /// shards.link_after(&mut new_shard);
+    /// move_upper_half(shards.current_mut(), new_shard.get_mut());
+    /// ```
+    #[inline]
+    pub fn link_after(&mut self, new_shard: &mut AccountRefMut<'a, T, F>) {
+        let current = *self.current_address();
+        let high = *self.high_address();
+        let new_key = *new_shard.address();
+        new_shard.get_mut().set_low_shard(current);
+        new_shard.get_mut().set_high_shard(high);
+        self.current_mut().set_high_shard(new_key);
+        self.high_mut().set_low_shard(new_key);
+    }
+
+    /// Remove `current` from the structure by linking `low` and `high` to
+    /// each other.
+    ///
+    /// `current`'s own links are left as they are; close or reuse the
+    /// account afterwards.
+    #[inline]
+    pub fn unlink_current(&mut self) {
+        let low = *self.low_address();
+        let high = *self.high_address();
+        self.low_mut().set_high_shard(high);
+        self.high_mut().set_low_shard(low);
+    }
 }
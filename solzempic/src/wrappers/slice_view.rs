@@ -0,0 +1,328 @@
+//! Zero-copy view over a fixed header followed by a growable record array.
+//!
+//! This module provides [`SliceView`], a helper for accounts laid out as a
+//! [`Loadable`] header followed by a tail of fixed-size records, where the
+//! number of live records is tracked by a length field in the header.
+
+use bytemuck::Pod;
+use pinocchio::error::ProgramError;
+
+use crate::Loadable;
+
+/// Header type that tracks the number of records in a [`SliceView`] tail.
+///
+/// Implement this for the account header so the view knows how many of the
+/// trailing records are in use. The length is stored in whatever integer
+/// field the header already has; the view only reads and writes it through
+/// these two methods.
+///
+/// # Example
+///
+/// ```ignore
+/// #[account(discriminator = AccountType::OrderShard)]
+/// pub struct OrderShard {
+///     pub discriminator: [u8; 8],
+///     pub market: Address,
+///     pub order_count: u32,
+///     pub _padding: [u8; 4],
+/// }
+///
+/// impl SliceHeader for OrderShard {
+///     fn tail_len(&self) -> usize {
+///         self.order_count as usize
+///     }
+///
+///     fn set_tail_len(&mut self, len: usize) {
+///         self.order_count = len as u32;
+///     }
+/// }
+/// ```
+pub trait SliceHeader: Loadable {
+    /// Number of records currently stored after the header.
+    fn tail_len(&self) -> usize;
+
+    /// Update the number of records stored after the header.
+    fn set_tail_len(&mut self, len: usize);
+}
+
+/// Zero-copy view of a header `H` followed by a variable-length array of `R`.
+///
+/// `SliceView` splits account data into the header (first `H::LEN` bytes) and
+/// a tail reinterpreted as `[R]`. The capacity is however many whole records
+/// fit in the remaining account space; the live length comes from the header
+/// via [`SliceHeader`].
+///
+/// # Layout
+///
+/// ```text
+/// | H (H::LEN bytes) | R[0] | R[1] | ... | R[len-1] | unused ... |
+///                    |<------------ capacity * size_of::<R>() ---->|
+/// ```
+///
+/// # Example
+///
+/// ```ignore
+/// use solzempic::SliceView;
+///
+/// let mut shard: AccountRefMut<OrderShard> = AccountRefMut::load(&accounts[0])?;
+/// let mut orders = SliceView::<OrderShard, Order>::from_bytes(shard.data_mut())?;
+///
+/// orders.push(Order { price, size, .. })?;
+/// for order in orders.iter() {
+///     // ...
+/// }
+/// ```
+///
+/// # Alignment
+///
+/// The tail starts at offset `H::LEN`. Construction fails if that offset is
+/// not suitably aligned for `R`, so keep `H::LEN` a multiple of `R`'s alignment
+/// (padding the header if necessary).
+pub struct SliceView<'a, H: SliceHeader, R: Pod> {
+    header: &'a mut H,
+    records: &'a mut [R],
+}
+
+impl<'a, H: SliceHeader, R: Pod> SliceView<'a, H, R> {
+    /// Create a view over raw account data.
+    ///
+    /// # Validation
+    ///
+    /// 1. Data must be at least `H::LEN` bytes
+    /// 2. Discriminator must match `H::DISCRIMINATOR`
+    /// 3. Header and tail must be correctly aligned for `H` and `R`
+    /// 4. The header's length must not exceed the tail capacity
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidAccountData`] - Any of the checks above fails
+    #[inline]
+    pub fn from_bytes(data: &'a mut [u8]) -> Result<Self, ProgramError> {
+        H::validate(data)?;
+
+        let (head, tail) = data.split_at_mut(H::LEN);
+        let header: &mut H = bytemuck::try_from_bytes_mut(head)
+            .map_err(|_| crate::errors::invalid_account_data())?;
+
+        let record_size = core::mem::size_of::<R>();
+        let capacity = if record_size == 0 { 0 } else { tail.len() / record_size };
+        let records: &mut [R] = bytemuck::try_cast_slice_mut(&mut tail[..capacity * record_size])
+            .map_err(|_| crate::errors::invalid_account_data())?;
+
+        if header.tail_len() > records.len() {
+            return Err(crate::errors::invalid_account_data());
+        }
+
+        Ok(Self { header, records })
+    }
+
+    /// Get a reference to the header.
+    #[inline]
+    pub fn header(&self) -> &H {
+        self.header
+    }
+
+    /// Get a mutable reference to the header.
+    ///
+    /// Prefer [`push`](Self::push), [`pop`](Self::pop), or
+    /// [`truncate`](Self::truncate) to changing the length field directly. A
+    /// length set above [`capacity`](Self::capacity) here is clamped by
+    /// [`len`](Self::len) rather than trusted.
+    #[inline]
+    pub fn header_mut(&mut self) -> &mut H {
+        self.header
+    }
+
+    /// Number of records currently stored.
+    ///
+    /// The header's length, clamped to [`capacity`](Self::capacity) in case it
+    /// was raised through [`header_mut`](Self::header_mut).
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.header.tail_len().min(self.records.len())
+    }
+
+    /// Check if no records are stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of records that fit in the account.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Get the record at `index`, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&R> {
+        self.as_slice().get(index)
+    }
+
+    /// Get the record at `index` mutably, or `None` if out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut R> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    /// Append a record to the end of the tail.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::AccountDataTooSmall`] - The account is at capacity
+    #[inline]
+    pub fn push(&mut self, record: R) -> Result<(), ProgramError> {
+        let len = self.len();
+        if len >= self.capacity() {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        self.records[len] = record;
+        self.header.set_tail_len(len + 1);
+        Ok(())
+    }
+
+    /// Remove and return the last record, or `None` if empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<R> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        self.header.set_tail_len(len - 1);
+        Some(self.records[len - 1])
+    }
+
+    /// Shorten the tail to `len` records. Has no effect if `len` is not
+    /// smaller than the current length.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.header.set_tail_len(len);
+        }
+    }
+
+    /// Get the live records as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[R] {
+        &self.records[..self.len()]
+    }
+
+    /// Get the live records as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [R] {
+        let len = self.len();
+        &mut self.records[..len]
+    }
+
+    /// Iterate over the live records.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, R> {
+        self.as_slice().iter()
+    }
+
+    /// Iterate mutably over the live records.
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, R> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Pod, Zeroable)]
+    struct Shard {
+        discriminator: [u8; 8],
+        count: u64,
+    }
+
+    impl Loadable for Shard {
+        const DISCRIMINATOR: u8 = 3;
+    }
+
+    impl SliceHeader for Shard {
+        fn tail_len(&self) -> usize {
+            self.count as usize
+        }
+
+        fn set_tail_len(&mut self, len: usize) {
+            self.count = len as u64;
+        }
+    }
+
+    /// Header plus room for three `u64` records, aligned for both.
+    fn shard_data(count: u64) -> [u64; 5] {
+        let mut words = [0u64; 5];
+        bytemuck::cast_slice_mut::<u64, u8>(&mut words)[0] = Shard::DISCRIMINATOR;
+        words[1] = count;
+        words
+    }
+
+    fn load(words: &mut [u64; 5]) -> Result<SliceView<'_, Shard, u64>, ProgramError> {
+        SliceView::from_bytes(bytemuck::cast_slice_mut(words))
+    }
+
+    #[test]
+    fn test_push_until_capacity() {
+        let mut words = shard_data(0);
+        let mut view = load(&mut words).unwrap();
+        assert!(view.is_empty());
+        assert_eq!(view.capacity(), 3);
+
+        for record in 1..=3 {
+            view.push(record).unwrap();
+        }
+        assert_eq!(view.push(4), Err(ProgramError::AccountDataTooSmall));
+        assert_eq!(view.as_slice(), &[1, 2, 3]);
+        assert_eq!(view.header().count, 3);
+
+        assert_eq!(view.pop(), Some(3));
+        view.truncate(1);
+        assert_eq!(view.as_slice(), &[1]);
+        assert_eq!(words[1], 1);
+    }
+
+    #[test]
+    fn test_get_out_of_range() {
+        let mut words = shard_data(1);
+        words[2] = 9;
+        words[3] = 8;
+        let mut view = load(&mut words).unwrap();
+
+        assert_eq!(view.get(0), Some(&9));
+        // Index 1 is within capacity but past the live length
+        assert_eq!(view.get(1), None);
+        assert_eq!(view.get(3), None);
+        assert!(view.get_mut(1).is_none());
+    }
+
+    #[test]
+    fn test_len_clamped_after_header_mut() {
+        let mut words = shard_data(1);
+        let mut view = load(&mut words).unwrap();
+        view.header_mut().count = 10;
+
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.as_slice().len(), 3);
+        assert_eq!(view.iter_mut().count(), 3);
+        assert_eq!(view.push(1), Err(ProgramError::AccountDataTooSmall));
+        assert_eq!(view.pop(), Some(0));
+        assert_eq!(view.header().count, 2);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_length_past_capacity() {
+        let mut words = shard_data(4);
+        assert!(matches!(load(&mut words), Err(ProgramError::InvalidAccountData)));
+
+        // A length exactly at capacity is fine
+        let mut words = shard_data(3);
+        let view = load(&mut words).unwrap();
+        assert_eq!(view.len(), 3);
+    }
+}
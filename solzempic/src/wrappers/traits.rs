@@ -4,10 +4,10 @@
 //! interface for both read-only ([`AccountRef`](super::AccountRef)) and
 //! writable ([`AccountRefMut`](super::AccountRefMut)) account wrappers.
 
-use pinocchio::AccountView;
-use solana_address::Address;
+use pinocchio::{error::ProgramError, AccountView};
+use solana_address::{address_eq, Address};
 
-use crate::{Framework, Loadable};
+use crate::{rent_exempt_minimum, Framework, Loadable};
 
 /// Common interface for account wrappers (both read-only and writable).
 ///
@@ -33,7 +33,7 @@ use crate::{Framework, Loadable};
 ///     F: Framework,
 ///     A: AsAccountRef<'a, T, F>,
 /// {
-///     account.key() == expected
+///     account.key_eq(expected)
 /// }
 ///
 /// // Works with both AccountRef and AccountRefMut
@@ -73,12 +73,21 @@ pub trait AsAccountRef<'a, T: Loadable, F: Framework> {
     /// # Example
     ///
     /// ```ignore
-    /// if account.address() == expected_address {
+    /// if account.key_eq(expected_address) {
     ///     // Account is at expected address
     /// }
     /// ```
     fn address(&self) -> &Address;
 
+    /// Check whether the account's address equals `other`.
+    ///
+    /// Uses [`address_eq`] (four `u64` comparisons) rather than `==` on
+    /// `&Address`, which may compare byte by byte.
+    #[inline]
+    fn key_eq(&self, other: &Address) -> bool {
+        address_eq(self.address(), other)
+    }
+
     /// Get a reference to the parsed account data.
     ///
     /// Returns a typed reference to the account's data, parsed as type `T`.
@@ -132,4 +141,75 @@ pub trait AsAccountRef<'a, T: Loadable, F: Framework> {
     /// If you already know the bump, prefer storing it and validating with a
     /// simple key comparison instead.
     fn is_pda(&self, seeds: &[&[u8]]) -> (bool, u8);
+
+    /// Require this account to be the PDA derived from `seeds`.
+    ///
+    /// The assertion form of [`is_pda`](Self::is_pda), returning the
+    /// canonical bump on success.
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::InvalidSeeds`] - Address doesn't match the derived PDA
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let bump = account.assert_pda(&[b"user", owner.address().as_ref()])?;
+    /// ```
+    #[inline]
+    fn assert_pda(&self, seeds: &[&[u8]]) -> Result<u8, ProgramError> {
+        match self.is_pda(seeds) {
+            (true, bump) => Ok(bump),
+            (false, _) => Err(ProgramError::InvalidSeeds),
+        }
+    }
+
+    /// Get the account's lamport balance.
+    ///
+    /// Equivalent to `info().lamports()`.
+    #[inline]
+    fn lamports(&self) -> u64 {
+        self.info().lamports()
+    }
+
+    /// Get the length of the account's data in bytes.
+    ///
+    /// Equivalent to `info().data_len()`.
+    #[inline]
+    fn data_len(&self) -> usize {
+        self.info().data_len()
+    }
+
+    /// Check if the account holds at least the rent-exempt minimum for its
+    /// current data length.
+    ///
+    /// Uses [`rent_exempt_minimum`], so no Rent sysvar is needed.
+    #[inline]
+    fn is_rent_exempt(&self) -> bool {
+        self.lamports() >= rent_exempt_minimum(self.data_len())
+    }
+
+    /// Require the account to be rent-exempt.
+    ///
+    /// The assertion form of [`is_rent_exempt`](Self::is_rent_exempt).
+    ///
+    /// # Errors
+    ///
+    /// * [`ProgramError::AccountNotRentExempt`] - Balance is below the minimum
+    #[inline]
+    fn assert_rent_exempt(&self) -> Result<(), ProgramError> {
+        if self.is_rent_exempt() {
+            Ok(())
+        } else {
+            Err(ProgramError::AccountNotRentExempt)
+        }
+    }
+
+    /// Check if the account signed the transaction.
+    ///
+    /// Equivalent to `info().is_signer()`.
+    #[inline]
+    fn is_signer(&self) -> bool {
+        self.info().is_signer()
+    }
 }
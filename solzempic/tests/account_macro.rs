@@ -0,0 +1,139 @@
+//! Tests for the code generated by `#[account]`.
+
+use solzempic::{account, AccountDerive, AccountIdlMeta, Discriminator, Loadable};
+
+#[account(discriminator = 4)]
+pub struct Vault {
+    pub discriminator: [u8; 8],
+    /// Fees collected, in lamports.
+    #[le]
+    fees: [u8; 8],
+    #[le(i64)]
+    pnl: [u8; 8],
+    #[le]
+    bps: [u8; 2],
+    pub _padding: [u8; 6],
+}
+
+#[test]
+fn test_le_accessors_round_trip() {
+    let mut vault: Vault = bytemuck::Zeroable::zeroed();
+    vault.set_fees(0x0102_0304);
+    vault.set_pnl(-5);
+    vault.set_bps(250);
+
+    assert_eq!(vault.fees(), 0x0102_0304);
+    assert_eq!(vault.fees, [4, 3, 2, 1, 0, 0, 0, 0]);
+    assert_eq!(vault.pnl(), -5);
+    assert_eq!(vault.bps(), 250);
+}
+
+#[test]
+fn test_le_fields_described_as_integers() {
+    let types: Vec<_> = Vault::FIELDS.iter().map(|field| (field.name, field.type_name)).collect();
+    assert_eq!(
+        types,
+        [
+            ("discriminator", "[u8; 8]"),
+            ("fees", "u64"),
+            ("pnl", "i64"),
+            ("bps", "u16"),
+            ("_padding", "[u8; 6]"),
+        ]
+    );
+}
+
+#[account(discriminator = 5)]
+pub struct Ring<const N: usize> {
+    pub discriminator: [u8; 8],
+    pub slots: [u64; N],
+}
+
+#[test]
+fn test_generic_account_impls() {
+    assert_eq!(<Ring<4> as Loadable>::LEN, 40);
+    assert_eq!(<Ring<4> as AccountIdlMeta>::META.size, 40);
+    assert_eq!(<Ring<16> as AccountIdlMeta>::META.size, 136);
+    assert_eq!(<Ring<4> as AccountIdlMeta>::NAME, "Ring");
+
+    let ring: Ring<2> = bytemuck::Zeroable::zeroed();
+    assert_eq!(bytemuck::bytes_of(&ring).len(), 24);
+}
+
+/// Legacy layout whose type tag sits after the authority.
+#[repr(C)]
+#[derive(AccountDerive)]
+#[account(discriminator = 3, field = "tag")]
+pub struct LegacyPool {
+    pub authority: [u8; 32],
+    pub tag: u8,
+    pub _padding: [u8; 7],
+    pub liquidity: u64,
+}
+
+#[test]
+fn test_derive_account_with_custom_discriminator_field() {
+    assert_eq!(LegacyPool::DISCRIMINATOR_OFFSET, 32);
+    assert_eq!(LegacyPool::DISCRIMINATOR_BYTES, [3]);
+    assert_eq!(<LegacyPool as Loadable>::LEN, 48);
+
+    let mut pool: LegacyPool = bytemuck::Zeroable::zeroed();
+    pool.authority = [3; 32];
+    assert!(!LegacyPool::check_discriminator(bytemuck::bytes_of(&pool)));
+    assert!(LegacyPool::validate(bytemuck::bytes_of(&pool)).is_err());
+
+    pool.tag = 3;
+    assert!(LegacyPool::check_discriminator(bytemuck::bytes_of(&pool)));
+    assert!(LegacyPool::validate(bytemuck::bytes_of(&pool)).is_ok());
+}
+
+/// Tuple-struct account: field `0` is the discriminator.
+#[repr(C)]
+#[derive(AccountDerive)]
+#[account(discriminator = 6, size = 16)]
+pub struct Nonce(pub [u8; 8], pub u64);
+
+#[test]
+fn test_derive_account_on_tuple_struct() {
+    assert_eq!(Nonce::DISCRIMINATOR_BYTES, [6, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(<Nonce as Loadable>::LEN, 16);
+
+    let mut nonce = Nonce(Nonce::DISCRIMINATOR_BYTES, 0);
+    nonce.1 += 1;
+    assert!(Nonce::validate(bytemuck::bytes_of(&nonce)).is_ok());
+
+    nonce.0[0] = 7;
+    assert!(Nonce::validate(bytemuck::bytes_of(&nonce)).is_err());
+}
+
+#[test]
+fn test_derive_discriminator_bytes_agree() {
+    assert_eq!(<Nonce as Discriminator>::BYTES, &Nonce::DISCRIMINATOR_BYTES);
+    assert_eq!(<LegacyPool as Discriminator>::BYTES, &LegacyPool::DISCRIMINATOR_BYTES);
+    assert_eq!(<LegacyPool as Discriminator>::OFFSET, LegacyPool::DISCRIMINATOR_OFFSET);
+    assert_eq!(<Vault as Discriminator>::BYTES, &[4]);
+}
+
+/// Sum of the name's bytes, as a stand-in for a program's hashing scheme.
+const fn name_disc(name: &str) -> u8 {
+    let bytes = name.as_bytes();
+    let mut sum = 0u8;
+    let mut i = 0;
+    while i < bytes.len() {
+        sum = sum.wrapping_add(bytes[i]);
+        i += 1;
+    }
+    sum
+}
+
+#[account(discriminator_fn = name_disc)]
+pub struct Hashed {
+    pub discriminator: [u8; 8],
+    pub value: u64,
+}
+
+#[test]
+fn test_discriminator_fn_uses_struct_name() {
+    assert_eq!(<Hashed as Loadable>::DISCRIMINATOR, name_disc("Hashed"));
+    assert_eq!(<Hashed as AccountIdlMeta>::NAME, "Hashed");
+}
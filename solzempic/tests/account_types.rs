@@ -0,0 +1,32 @@
+//! Tests for `define_account_types!`.
+
+use bytemuck::{Pod, Zeroable};
+use solzempic::Loadable;
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct CounterAccount {
+    discriminator: [u8; 8],
+    count: u64,
+}
+
+impl Loadable for CounterAccount {
+    const DISCRIMINATOR: u8 = 1;
+}
+
+solzempic::define_account_types! {
+    Counter = 1 => CounterAccount,
+    User = 3,
+}
+
+#[test]
+fn test_account_type_registry() {
+    assert_eq!(AccountType::ALL, &[AccountType::Counter, AccountType::User]);
+    assert_eq!(AccountType::from_data(&[3, 0]), Some(AccountType::User));
+    assert_eq!(AccountType::from_data(&[2]), None);
+    assert_eq!(AccountType::from_data(&[]), None);
+
+    assert_eq!(AccountType::Counter.name(), "Counter");
+    assert_eq!(AccountType::Counter.len(), Some(16));
+    assert_eq!(AccountType::User.len(), None);
+}
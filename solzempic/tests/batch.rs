@@ -0,0 +1,67 @@
+//! Tests for `#[SolzempicEntrypoint(..., batch)]`, mirroring the
+//! batched-dispatch example in the macro docs.
+
+use pinocchio::{error::ProgramError, AccountView, ProgramResult};
+use solana_address::Address;
+use solzempic::{instruction, params, SolzempicEntrypoint};
+
+#[SolzempicEntrypoint("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", batch)]
+pub enum MyInstruction {
+    Deposit = 0,
+    Swap = 1,
+    Batch = 2,
+}
+
+#[params]
+pub struct NoParams;
+
+/// Define an instruction that accepts any accounts and does nothing.
+macro_rules! noop_instruction {
+    ($name:ident) => {
+        pub struct $name<'a> {
+            _accounts: &'a [AccountView],
+        }
+
+        #[instruction(NoParams)]
+        impl<'a> $name<'a> {
+            fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+                Ok(Self { _accounts: accounts })
+            }
+        }
+    };
+}
+
+noop_instruction!(Deposit);
+noop_instruction!(Swap);
+
+#[params]
+pub struct BatchParams;
+
+pub struct Batch<'a> {
+    accounts: &'a [AccountView],
+    frames: &'a [u8],
+}
+
+#[instruction(BatchParams)]
+impl<'a> Batch<'a> {
+    fn build_with_rest(accounts: &'a [AccountView], _params: &BatchParams, rest: &'a [u8]) -> Result<Self, ProgramError> {
+        Ok(Self { accounts, frames: rest })
+    }
+
+    fn execute(&self, program_id: &Address, _params: &BatchParams) -> ProgramResult {
+        MyInstruction::process_many(program_id, self.accounts, self.frames)
+    }
+}
+
+#[test]
+fn test_batch_handler_runs_frames() {
+    // Batch of two frames: Deposit, then Swap
+    let data = [2, 2, 1, 0, 0, 1, 0, 1];
+    assert_eq!(dispatch_bytes(&ID, &[], &data), Ok(()));
+
+    // A bad frame fails the outer instruction
+    assert_eq!(
+        dispatch_bytes(&ID, &[], &[2, 1, 1, 0, 9]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+}
@@ -0,0 +1,621 @@
+//! Tests for the code generated by `#[SolzempicEntrypoint]`.
+
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{error::ProgramError, AccountView, ProgramResult};
+use solana_address::Address;
+use solzempic::test_utils::{AccountBuilder, TestAccount};
+use solzempic::{
+    instruction, params, ContextInstruction, Framework, Instruction, InstructionParams, Loadable, MutSigner, Params, ParamsMeta, ReadOnly, SeedMeta, Signer,
+    SolzempicEntrypoint, ValidatedAccount, Writable,
+};
+
+#[SolzempicEntrypoint("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", batch)]
+pub enum TestInstruction {
+    Initialize = 0,
+    Transfer = 1,
+    Close = 7,
+}
+
+#[params]
+pub struct NoParams;
+
+/// Define an instruction that accepts any accounts and does nothing.
+///
+/// Relies on the macro's default `validate` and `execute`.
+macro_rules! noop_instruction {
+    ($name:ident) => {
+        #[instruction]
+        pub struct $name<'a> {
+            _accounts: &'a [AccountView],
+        }
+
+        #[instruction(NoParams)]
+        impl<'a> $name<'a> {
+            fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+                Ok(Self { _accounts: accounts })
+            }
+        }
+    };
+}
+
+noop_instruction!(Initialize);
+noop_instruction!(Transfer);
+noop_instruction!(Close);
+
+#[test]
+fn test_variants_lists_every_instruction() {
+    assert_eq!(
+        TestInstruction::VARIANTS,
+        &[("Initialize", 0), ("Transfer", 1), ("Close", 7)]
+    );
+
+    for &(_, discriminator) in TestInstruction::VARIANTS {
+        assert!(TestInstruction::try_from(discriminator).is_ok());
+        assert_eq!(dispatch_bytes(&ID, &[], &[discriminator]), Ok(()));
+    }
+}
+
+#[test]
+fn test_process_many_dispatches_each_frame() {
+    let data = [3, 1, 0, 0, 1, 0, 1, 1, 0, 7];
+    assert_eq!(TestInstruction::process_many(&ID, &[], &data), Ok(()));
+    assert_eq!(TestInstruction::process_many(&ID, &[], &[0]), Ok(()));
+
+    // Unknown discriminator in the second frame
+    let data = [2, 1, 0, 0, 1, 0, 9];
+    assert_eq!(
+        TestInstruction::process_many(&ID, &[], &data),
+        Err(ProgramError::InvalidInstructionData)
+    );
+
+    // Frame length runs past the end of the data
+    assert_eq!(
+        TestInstruction::process_many(&ID, &[], &[1, 2, 0, 0]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+
+    // Trailing bytes after the last frame
+    assert_eq!(
+        TestInstruction::process_many(&ID, &[], &[1, 1, 0, 0, 0]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+}
+
+solzempic::define_errors! {
+    pub enum TestError {
+        Rejected = 6000,
+    }
+}
+
+/// Instruction whose `validate` fails with a program-specific error.
+pub struct Reject<'a> {
+    _accounts: &'a [AccountView],
+}
+
+#[instruction(NoParams, error = TestError)]
+impl<'a> Reject<'a> {
+    fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+        Ok(Self { _accounts: accounts })
+    }
+
+    fn validate(&self, _program_id: &Address, _params: &NoParams) -> Result<(), TestError> {
+        Err(TestError::Rejected)
+    }
+}
+
+#[test]
+fn test_custom_error_converts_to_program_error() {
+    assert_eq!(ProgramError::from(TestError::Rejected), ProgramError::Custom(6000));
+    assert_eq!(Reject::process(&ID, &[], &[]), Err(ProgramError::Custom(6000)));
+}
+
+/// Instruction used only for its generated account metadata.
+#[instruction]
+pub struct Deposit<'a> {
+    pub depositor: MutSigner<'a>,
+    pub vault: Writable<'a>,
+    pub config: ReadOnly<'a>,
+}
+
+#[test]
+fn test_account_order_follows_field_order() {
+    let order: Vec<_> = Deposit::account_order()
+        .iter()
+        .map(|meta| (meta.name, meta.is_signer, meta.is_writable))
+        .collect();
+    assert_eq!(
+        order,
+        [("depositor", true, true), ("vault", false, true), ("config", false, false)]
+    );
+}
+
+/// Instruction with a fixed-size account array.
+#[instruction]
+pub struct ClaimRewards<'a> {
+    pub user: Signer<'a>,
+    pub reward_vaults: [Writable<'a>; 3],
+}
+
+#[test]
+fn test_account_array_expands_to_numbered_accounts() {
+    assert_eq!(ClaimRewards::NUM_ACCOUNTS, 4);
+    let order: Vec<_> = ClaimRewards::account_order()
+        .iter()
+        .map(|meta| (meta.index, meta.name, meta.is_writable))
+        .collect();
+    assert_eq!(
+        order,
+        [
+            (0, "user", false),
+            (1, "reward_vaults_0", true),
+            (2, "reward_vaults_1", true),
+            (3, "reward_vaults_2", true),
+        ]
+    );
+}
+
+/// Instruction with a PDA declared through `#[seeds]`.
+#[instruction]
+pub struct OpenMarket<'a> {
+    #[seeds(b"market", authority)]
+    pub market: Writable<'a>,
+    pub authority: Signer<'a>,
+}
+
+#[test]
+fn test_seeds_recorded_in_account_metadata() {
+    let accounts = OpenMarket::account_order();
+    assert_eq!(accounts[0].seeds, &[SeedMeta::Const(b"market"), SeedMeta::Account("authority")]);
+    assert!(accounts[1].seeds.is_empty());
+
+    let _check: fn(&OpenMarket<'_>, &Address) -> ProgramResult = OpenMarket::check_seeds;
+}
+
+#[params]
+pub struct CountParams {
+    pub count: u8,
+}
+
+/// Params declared with the derive, keeping the struct as written.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Params)]
+pub struct LimitParams {
+    /// Limit price.
+    pub price: u64,
+    pub side: u8,
+    pub _padding: [u8; 7],
+}
+
+#[test]
+fn test_derive_params_generates_metadata_and_parsing() {
+    let fields: Vec<_> = LimitParams::FIELDS.iter().map(|field| (field.name, field.type_name)).collect();
+    assert_eq!(fields, [("price", "u64"), ("side", "u8"), ("_padding", "[u8; 7]")]);
+    assert_eq!(LimitParams::FIELDS[0].docs, &["Limit price."]);
+
+    let params = LimitParams { price: 5, side: 1, _padding: [0; 7] };
+    let bytes = bytemuck::bytes_of(&params);
+    assert_eq!(LimitParams::try_from(bytes), Ok(params));
+    assert_eq!(LimitParams::try_from(&bytes[..15]), Err(ProgramError::InvalidInstructionData));
+}
+
+/// Instruction that rejects a zero count before loading its account.
+pub struct Guarded<'a> {
+    _payer: &'a AccountView,
+}
+
+#[instruction(CountParams)]
+impl<'a> Guarded<'a> {
+    fn precheck(params: &CountParams) -> ProgramResult {
+        if params.count == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    fn build(accounts: &'a [AccountView], _params: &CountParams) -> Result<Self, ProgramError> {
+        Ok(Self { _payer: solzempic::next_account(accounts, 0)? })
+    }
+}
+
+#[test]
+fn test_precheck_runs_before_build() {
+    assert_eq!(Guarded::process(&ID, &[], &[0]), Err(ProgramError::InvalidArgument));
+    assert_eq!(Guarded::process(&ID, &[], &[1]), Err(ProgramError::NotEnoughAccountKeys));
+}
+
+#[test]
+fn test_instruction_name_matches_idl_name() {
+    assert_eq!(<Guarded as InstructionParams>::NAME, "Guarded");
+    assert_eq!(<Doubled as InstructionParams>::NAME, Doubled::IDL_NAME);
+}
+
+/// Instruction whose accounts are loaded by the generated `auto_build`.
+#[instruction(auto_build)]
+pub struct AutoLoaded<'a> {
+    pub payer: MutSigner<'a>,
+    pub target: Writable<'a>,
+    #[manual]
+    pub raw: &'a AccountView,
+    #[present_if(params.count != 0)]
+    pub tip: Option<ReadOnly<'a>>,
+}
+
+#[instruction(CountParams, auto_build)]
+impl<'a> AutoLoaded<'a> {
+    fn build_raw(accounts: &'a [AccountView], _params: &CountParams) -> Result<&'a AccountView, ProgramError> {
+        solzempic::next_account(accounts, 2)
+    }
+}
+
+#[test]
+fn test_auto_build_loads_fields_by_type() {
+    let payer = AccountBuilder::new().signer(true).writable(true).build();
+    let target = AccountBuilder::new().writable(true).build();
+    let accounts = [*payer.info(), *target.info(), *target.info()];
+
+    let ix = AutoLoaded::build(&accounts, &CountParams { count: 0 }).unwrap();
+    assert!(ix.tip.is_none());
+    assert_eq!(ix.raw.address(), target.info().address());
+    assert_eq!(
+        AutoLoaded::build(&accounts, &CountParams { count: 1 }).err(),
+        Some(ProgramError::NotEnoughAccountKeys)
+    );
+
+    let accounts = [*target.info(), *target.info(), *target.info()];
+    assert_eq!(
+        AutoLoaded::build(&accounts, &CountParams { count: 0 }).err(),
+        Some(ProgramError::MissingRequiredSignature)
+    );
+}
+
+/// Second framework next to the entrypoint's `Solzempic`.
+#[derive(Framework)]
+#[framework(id = Address::new_from_array([9; 32]))]
+pub struct OtherProgram;
+
+#[test]
+fn test_derive_framework_sets_program_id() {
+    assert_eq!(OtherProgram::PROGRAM_ID, Address::new_from_array([9; 32]));
+    assert_eq!(Solzempic::PROGRAM_ID, ID);
+}
+
+/// Instruction whose tip recipient is only passed for a nonzero count.
+#[instruction]
+pub struct Tip<'a> {
+    pub payer: MutSigner<'a>,
+    #[present_if(params.count != 0)]
+    pub recipient: Option<Writable<'a>>,
+}
+
+#[instruction(CountParams)]
+impl<'a> Tip<'a> {
+    fn build(accounts: &'a [AccountView], params: &CountParams) -> Result<Self, ProgramError> {
+        let recipient = match params.count {
+            0 => None,
+            _ => Some(Writable::wrap(&accounts[1])?),
+        };
+        Ok(Self { payer: MutSigner::wrap(&accounts[0])?, recipient })
+    }
+}
+
+#[test]
+fn test_present_if_accounts_are_optional() {
+    assert_eq!(Tip::NUM_ACCOUNTS, 2);
+    assert_eq!(Tip::MIN_ACCOUNTS, 1);
+    assert_eq!(Tip::expected_account_count(&CountParams { count: 0 }), 1);
+    assert_eq!(Tip::expected_account_count(&CountParams { count: 3 }), 2);
+
+    let order: Vec<_> = Tip::account_order()
+        .iter()
+        .map(|meta| (meta.name, meta.is_writable, meta.is_optional))
+        .collect();
+    assert_eq!(order, [("payer", true, false), ("recipient", true, true)]);
+}
+
+/// Instruction that leaves two accounts after the payer undescribed.
+#[instruction]
+pub struct Gapped<'a> {
+    pub payer: MutSigner<'a>,
+    #[skip(2)]
+    pub target: Writable<'a>,
+}
+
+#[instruction(NoParams)]
+impl<'a> Gapped<'a> {
+    fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+        Ok(Self { payer: MutSigner::wrap(&accounts[0])?, target: Writable::wrap(&accounts[3])? })
+    }
+}
+
+#[test]
+fn test_skip_shifts_account_indices() {
+    assert_eq!(Gapped::NUM_ACCOUNTS, 2);
+    assert_eq!(Gapped::MIN_ACCOUNTS, 4);
+
+    let order: Vec<_> = Gapped::account_order().iter().map(|meta| (meta.name, meta.index)).collect();
+    assert_eq!(order, [("payer", 0), ("target", 3)]);
+}
+
+/// Instruction whose fixed params are followed by `count` payload bytes.
+pub struct Payload<'a> {
+    bytes: &'a [u8],
+}
+
+#[instruction(CountParams)]
+impl<'a> Payload<'a> {
+    /// Smallest payload byte `validate` accepts.
+    const MIN_BYTE: u8 = 1;
+
+    fn build_with_rest(_accounts: &'a [AccountView], params: &CountParams, rest: &'a [u8]) -> Result<Self, ProgramError> {
+        let bytes = rest.get(..params.count as usize).ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(Self { bytes })
+    }
+
+    fn validate(&self, _program_id: &Address, _params: &CountParams) -> ProgramResult {
+        if self.bytes.iter().any(|&byte| byte < Self::MIN_BYTE) {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_build_with_rest_receives_trailing_data() {
+    assert_eq!(Payload::process(&ID, &[], &[2, 10, 20]), Ok(()));
+    assert_eq!(Payload::process(&ID, &[], &[2, 10, 0]), Err(ProgramError::InvalidArgument));
+    assert_eq!(Payload::process(&ID, &[], &[3, 10, 20]), Err(ProgramError::InvalidInstructionData));
+}
+
+/// Instruction with a hand-written `InstructionParams` impl that relies on
+/// the default `decode_params` and `decode_params_with_rest`.
+pub struct Manual<'a> {
+    _accounts: &'a [AccountView],
+}
+
+impl InstructionParams for Manual<'_> {
+    type Params = CountParams;
+}
+
+impl<'a> Instruction<'a> for Manual<'a> {
+    fn build(accounts: &'a [AccountView], _params: &CountParams) -> Result<Self, ProgramError> {
+        Ok(Self { _accounts: accounts })
+    }
+
+    fn validate(&self, _program_id: &Address, params: &CountParams) -> ProgramResult {
+        if params.count == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    fn execute(&mut self, _program_id: &Address, _params: &CountParams) -> ProgramResult {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_default_decode_params_is_zero_copy() {
+    assert_eq!(Manual::decode_params(&[7]).unwrap().count, 7);
+    assert_eq!(Manual::decode_params(&[]).err(), Some(ProgramError::InvalidInstructionData));
+    let (params, rest) = Manual::decode_params_with_rest(&[7, 1, 2]).unwrap();
+    assert_eq!((params.count, rest), (7, &[1u8, 2][..]));
+    assert_eq!(Manual::process(&ID, &[], &[1]), Ok(()));
+    assert_eq!(Manual::process(&ID, &[], &[0]), Err(ProgramError::InvalidArgument));
+}
+
+#[test]
+fn test_params_try_from_bytes() {
+    let params = CountParams::try_from(&[7u8, 1][..]).unwrap();
+    assert_eq!(params.count, 7);
+    assert_eq!(CountParams::try_from(&[][..]).err(), Some(ProgramError::InvalidInstructionData));
+}
+
+/// Instruction that derives a value once in `build` and checks it in `validate`.
+pub struct Doubled<'a> {
+    _accounts: &'a [AccountView],
+}
+
+#[instruction(CountParams)]
+impl<'a> Doubled<'a> {
+    type Ctx = u16;
+
+    const LIMIT: u16 = 100;
+
+    fn build(accounts: &'a [AccountView], params: &CountParams) -> Result<(Self, u16), ProgramError> {
+        Ok((Self { _accounts: accounts }, params.count as u16 * 2))
+    }
+
+    fn validate(&self, doubled: &u16, _program_id: &Address, _params: &CountParams) -> ProgramResult {
+        if *doubled > Self::LIMIT {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_context_threads_through_phases() {
+    assert_eq!(Doubled::process_with_context(&ID, &[], &[50]), Ok(()));
+    assert_eq!(Doubled::process_with_context(&ID, &[], &[51]), Err(ProgramError::InvalidArgument));
+
+    let params = CountParams { count: 7 };
+    let (mut ix, doubled) = Doubled::build_with_context(&[], &params, &[]).unwrap();
+    assert_eq!(doubled, 14);
+    assert_eq!(ix.validate_with_context(&doubled, &ID, &params), Ok(()));
+    assert_eq!(ix.validate_with_context(&(Doubled::LIMIT + 1), &ID, &params), Err(ProgramError::InvalidArgument));
+    assert_eq!(ix.execute_with_context(&doubled, &ID, &params), Ok(()));
+}
+
+#[test]
+fn test_plain_instruction_is_a_unit_context_instruction() {
+    assert_eq!(<Payload as ContextInstruction>::process_with_context(&ID, &[], &[2, 10, 20]), Ok(()));
+    assert_eq!(
+        <Payload as ContextInstruction>::process_with_context(&ID, &[], &[2, 10, 0]),
+        Err(ProgramError::InvalidArgument)
+    );
+}
+
+/// Program account holding the authority that `UpdateMarket` checks.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct Market {
+    discriminator: [u8; 8],
+    pub authority: Address,
+}
+
+impl Loadable for Market {
+    const DISCRIMINATOR: u8 = 2;
+}
+
+fn market_account(authority: [u8; 32]) -> TestAccount {
+    let market = Market {
+        discriminator: [Market::DISCRIMINATOR, 0, 0, 0, 0, 0, 0, 0],
+        authority: Address::new_from_array(authority),
+    };
+    AccountBuilder::new().owner(ID).data(bytemuck::bytes_of(&market)).build()
+}
+
+/// Instruction whose only check is a declared `#[has_one]`.
+#[instruction]
+pub struct UpdateMarket<'a> {
+    #[has_one(authority = admin)]
+    pub market: AccountRef<'a, Market>,
+    pub admin: Signer<'a>,
+}
+
+#[instruction(NoParams)]
+impl<'a> UpdateMarket<'a> {
+    fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+        Ok(Self {
+            market: AccountRef::load(&accounts[0])?,
+            admin: Signer::wrap(&accounts[1])?,
+        })
+    }
+}
+
+#[test]
+fn test_process_runs_has_one() {
+    let admin = AccountBuilder::new().address(Address::new_from_array([4; 32])).signer(true).build();
+
+    let market = market_account([4; 32]);
+    let accounts = [*market.info(), *admin.info()];
+    assert_eq!(UpdateMarket::process(&ID, &accounts, &[]), Ok(()));
+    let ix = UpdateMarket::build(&accounts, &NoParams).unwrap();
+    assert_eq!(ix.check_has_one(), Ok(()));
+    assert_eq!(Instruction::check_constraints(&ix), Ok(()));
+
+    let other = market_account([5; 32]);
+    let accounts = [*other.info(), *admin.info()];
+    assert_eq!(UpdateMarket::process(&ID, &accounts, &[]), Err(ProgramError::InvalidAccountOwner));
+    let ix = UpdateMarket::build(&accounts, &NoParams).unwrap();
+    assert_eq!(Instruction::check_constraints(&ix), Err(ProgramError::InvalidAccountOwner));
+}
+
+#[test]
+fn test_check_constraints_defaults_to_ok_without_declared_checks() {
+    let payer = AccountBuilder::new().signer(true).writable(true).build();
+    let accounts = [*payer.info()];
+    let ix = Tip::build(&accounts, &CountParams { count: 0 }).unwrap();
+    assert_eq!(Instruction::check_constraints(&ix), Ok(()));
+}
+
+/// The only admin allowed to sign `SetAuthority`.
+const ADMIN: Address = Address::new_from_array([4; 32]);
+
+/// Instruction with both a declared `#[address]` and `#[has_one]`.
+#[instruction]
+pub struct SetAuthority<'a> {
+    #[has_one(authority = admin)]
+    pub market: AccountRef<'a, Market>,
+    #[address(ADMIN)]
+    pub admin: Signer<'a>,
+}
+
+#[instruction(NoParams)]
+impl<'a> SetAuthority<'a> {
+    fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+        Ok(Self {
+            market: AccountRef::load(&accounts[0])?,
+            admin: Signer::wrap(&accounts[1])?,
+        })
+    }
+}
+
+#[test]
+fn test_process_runs_address_checks() {
+    let admin = AccountBuilder::new().address(ADMIN).signer(true).build();
+    let market = market_account([4; 32]);
+    let accounts = [*market.info(), *admin.info()];
+    assert_eq!(SetAuthority::process(&ID, &accounts, &[]), Ok(()));
+    let ix = SetAuthority::build(&accounts, &NoParams).unwrap();
+    assert_eq!(ix.check_addresses(), Ok(()));
+
+    // The market agrees with the signer, but the signer isn't ADMIN
+    let impostor = AccountBuilder::new().address(Address::new_from_array([6; 32])).signer(true).build();
+    let impostor_market = market_account([6; 32]);
+    let accounts = [*impostor_market.info(), *impostor.info()];
+    assert_eq!(SetAuthority::process(&ID, &accounts, &[]), Err(ProgramError::InvalidArgument));
+    let ix = SetAuthority::build(&accounts, &NoParams).unwrap();
+    assert_eq!(ix.check_addresses(), Err(ProgramError::InvalidArgument));
+    assert_eq!(ix.check_has_one(), Ok(()));
+
+    // ADMIN signs, but the market names another authority
+    let other_market = market_account([5; 32]);
+    let accounts = [*other_market.info(), *admin.info()];
+    assert_eq!(SetAuthority::process(&ID, &accounts, &[]), Err(ProgramError::InvalidAccountOwner));
+}
+
+/// Instruction whose constrained accounts are only passed for a nonzero count.
+#[instruction]
+pub struct Rebate<'a> {
+    pub admin: Signer<'a>,
+    #[present_if(params.count != 0)]
+    #[has_one(authority = admin)]
+    pub market: Option<AccountRef<'a, Market>>,
+    #[present_if(params.count != 0)]
+    #[address(ADMIN)]
+    pub treasury: Option<ReadOnly<'a>>,
+    #[present_if(params.count != 0)]
+    #[seeds(b"rebate", admin)]
+    pub vault: Option<Writable<'a>>,
+}
+
+#[instruction(CountParams)]
+impl<'a> Rebate<'a> {
+    fn build(accounts: &'a [AccountView], params: &CountParams) -> Result<Self, ProgramError> {
+        let admin = Signer::wrap(&accounts[0])?;
+        if params.count == 0 {
+            return Ok(Self { admin, market: None, treasury: None, vault: None });
+        }
+        Ok(Self {
+            admin,
+            market: Some(AccountRef::load(&accounts[1])?),
+            treasury: Some(ReadOnly::wrap(&accounts[2])?),
+            vault: Some(Writable::wrap(&accounts[3])?),
+        })
+    }
+}
+
+#[test]
+fn test_constraints_on_present_if_accounts() {
+    let admin = AccountBuilder::new().address(ADMIN).signer(true).build();
+
+    // Absent accounts skip their checks
+    let accounts = [*admin.info()];
+    assert_eq!(Rebate::process(&ID, &accounts, &[0]), Ok(()));
+    let ix = Rebate::build(&accounts, &CountParams { count: 0 }).unwrap();
+    assert_eq!(ix.check_seeds(&ID), Ok(()));
+
+    let market = market_account([4; 32]);
+    let vault = AccountBuilder::new().writable(true).build();
+    let accounts = [*admin.info(), *market.info(), *admin.info(), *vault.info()];
+    assert_eq!(Rebate::process(&ID, &accounts, &[1]), Ok(()));
+
+    // Present accounts are checked
+    let other_market = market_account([5; 32]);
+    let accounts = [*admin.info(), *other_market.info(), *admin.info(), *vault.info()];
+    assert_eq!(Rebate::process(&ID, &accounts, &[1]), Err(ProgramError::InvalidAccountOwner));
+
+    let accounts = [*admin.info(), *market.info(), *vault.info(), *vault.info()];
+    assert_eq!(Rebate::process(&ID, &accounts, &[1]), Err(ProgramError::InvalidArgument));
+}
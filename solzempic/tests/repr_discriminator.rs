@@ -0,0 +1,47 @@
+//! Tests for `#[SolzempicEntrypoint]` on a `#[repr(u16)]` enum.
+
+use pinocchio::{error::ProgramError, AccountView};
+use solzempic::{instruction, params, SolzempicEntrypoint};
+
+#[SolzempicEntrypoint("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")]
+#[repr(u16)]
+pub enum ReprInstruction {
+    Initialize = 0,
+    Settle = 300,
+}
+
+#[params]
+pub struct NoParams;
+
+/// Define an instruction that accepts any accounts and does nothing.
+macro_rules! noop_instruction {
+    ($name:ident) => {
+        #[instruction]
+        pub struct $name<'a> {
+            _accounts: &'a [AccountView],
+        }
+
+        #[instruction(NoParams)]
+        impl<'a> $name<'a> {
+            fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+                Ok(Self { _accounts: accounts })
+            }
+        }
+    };
+}
+
+noop_instruction!(Initialize);
+noop_instruction!(Settle);
+
+#[test]
+fn test_repr_sets_discriminator_width() {
+    assert_eq!(core::mem::size_of::<ReprInstruction>(), 2);
+    assert_eq!(ReprInstruction::VARIANTS, &[("Initialize", 0u16), ("Settle", 300)]);
+    assert!(ReprInstruction::try_from(300u16).is_ok());
+
+    assert_eq!(ReprInstruction::process(&ID, &[], &300u16.to_le_bytes()), Ok(()));
+    assert_eq!(
+        ReprInstruction::process(&ID, &[], &[0]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+}
@@ -0,0 +1,57 @@
+//! Tests for `#[SolzempicEntrypoint(..., disc_len = N)]`.
+
+use pinocchio::{error::ProgramError, AccountView};
+use solzempic::{instruction, params, SolzempicEntrypoint};
+
+#[SolzempicEntrypoint("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", disc_len = 4)]
+pub enum WideInstruction {
+    Initialize = 1,
+    Transfer = 0x0001_0000,
+}
+
+#[params]
+pub struct NoParams;
+
+/// Define an instruction that accepts any accounts and does nothing.
+macro_rules! noop_instruction {
+    ($name:ident) => {
+        #[instruction]
+        pub struct $name<'a> {
+            _accounts: &'a [AccountView],
+        }
+
+        #[instruction(NoParams)]
+        impl<'a> $name<'a> {
+            fn build(accounts: &'a [AccountView], _params: &NoParams) -> Result<Self, ProgramError> {
+                Ok(Self { _accounts: accounts })
+            }
+        }
+    };
+}
+
+noop_instruction!(Initialize);
+noop_instruction!(Transfer);
+
+#[test]
+fn test_wide_discriminator_dispatch() {
+    assert_eq!(
+        WideInstruction::VARIANTS,
+        &[("Initialize", 1u32), ("Transfer", 0x0001_0000)]
+    );
+    assert!(WideInstruction::try_from(0x0001_0000u32).is_ok());
+
+    assert_eq!(WideInstruction::process(&ID, &[], &[1, 0, 0, 0]), Ok(()));
+    assert_eq!(WideInstruction::process(&ID, &[], &[0, 0, 1, 0]), Ok(()));
+
+    // Only the low byte matches Initialize
+    assert_eq!(
+        WideInstruction::process(&ID, &[], &[1, 0, 0, 1]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+
+    // Prefix shorter than disc_len
+    assert_eq!(
+        WideInstruction::process(&ID, &[], &[1, 0, 0]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+}